@@ -48,6 +48,7 @@ where
         EntityDetails,
         AuthorDetails,
         AuthorityDetails,
+        AccountId,
     >,
     P: TransactionPool + Sync + Send + 'static,
 {
@@ -7,11 +7,14 @@ use scale_codec::Codec;
 
 use polkadot_sdk::*;
 
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi, RuntimeApiInfo};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 
-pub use pallet_ip_onchain_runtime_api::ApiIpOnchainRuntime;
+pub use pallet_ip_onchain_runtime_api::{
+    AccountOverview, ApiIpOnchainRuntime, IndustryIdentifier, Ipi, LayoutV1, LicenseKind,
+    MetadataStandard, RelationKind,
+};
 
 /// Error type of this RPC api.
 pub enum Error {
@@ -39,6 +42,7 @@ pub trait IpOnchainRpcApi<
     EntityDetails,
     AuthorDetails,
     AuthorityDetails,
+    AccountId,
 >
 {
     /// Retrieves the details of an entity by its `entity_id`.
@@ -53,6 +57,8 @@ pub trait IpOnchainRpcApi<
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<(EntityId, EntityDetails)>>;
 
+    /// Retrieves the author `author_id`, its `status` tells whether the owner claimed it and
+    /// whether it is verified.
     #[method(name = "ipOnchain_author")]
     fn author(&self, author_id: AuthorId, at: Option<BlockHash>) -> RpcResult<AuthorDetails>;
 
@@ -78,6 +84,143 @@ pub trait IpOnchainRpcApi<
         to: AuthorityId,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<(AuthorityId, AuthorityDetails)>>;
+
+    /// Retrieves the IDs of the entities owned by the authority `authority_id`.
+    #[method(name = "ipOnchain_entitiesOfAuthority")]
+    fn entities_of_authority(
+        &self,
+        authority_id: AuthorityId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<EntityId>>;
+
+    /// Retrieves the IDs of the entities the author `author_id` is credited on.
+    #[method(name = "ipOnchain_entitiesOfAuthor")]
+    fn entities_of_author(
+        &self,
+        author_id: AuthorId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<EntityId>>;
+
+    /// Retrieves the authors owned by `account_id` and the authorities it has access to.
+    #[method(name = "ipOnchain_accountOverview")]
+    fn account_overview(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<AccountOverview<AuthorId, AuthorityId>>;
+
+    /// Retrieves the entities deriving from the entity `entity_id` with the kind of their relation.
+    #[method(name = "ipOnchain_derivatives")]
+    fn derivatives(
+        &self,
+        entity_id: EntityId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(EntityId, RelationKind)>>;
+
+    /// Checks whether `account_id` holds an active license of the given `kind` for an entity.
+    #[method(name = "ipOnchain_isLicensed")]
+    fn is_licensed(
+        &self,
+        account_id: AccountId,
+        entity_id: EntityId,
+        kind: LicenseKind,
+        at: Option<BlockHash>,
+    ) -> RpcResult<bool>;
+
+    /// Retrieves the metadata standards, with their versions, an entity can declare.
+    #[method(name = "ipOnchain_metadataStandards")]
+    fn metadata_standards(&self, at: Option<BlockHash>) -> RpcResult<Vec<MetadataStandard>>;
+
+    /// Retrieves the entity that anchored the multihash `content_hash`.
+    #[method(name = "ipOnchain_entityByContentHash")]
+    fn entity_by_content_hash(
+        &self,
+        content_hash: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<EntityId>>;
+
+    /// Retrieves the entity that registered an ISRC, ISWC or UPC.
+    #[method(name = "ipOnchain_entityByIdentifier")]
+    fn entity_by_identifier(
+        &self,
+        identifier: IndustryIdentifier,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<EntityId>>;
+
+    /// Retrieves the author that registered an IPI name number.
+    #[method(name = "ipOnchain_authorByIpi")]
+    fn author_by_ipi(&self, ipi: Ipi, at: Option<BlockHash>) -> RpcResult<Option<AuthorId>>;
+
+    /// Retrieves revision `revision` of an entity with the account that made it.
+    #[method(name = "ipOnchain_entityRevision")]
+    fn entity_revision(
+        &self,
+        entity_id: EntityId,
+        revision: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<(AccountId, EntityDetails)>>;
+
+    /// Retrieves a release with its tracks ordered by track number.
+    #[method(name = "ipOnchain_release")]
+    fn release(
+        &self,
+        entity_id: EntityId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<(EntityDetails, Vec<(u32, EntityId, EntityDetails)>)>;
+
+    /// Retrieves the current holder of the NFT of an entity, `None` if the entity has no NFT or it was burned.
+    #[method(name = "ipOnchain_entityNftHolder")]
+    fn entity_nft_holder(
+        &self,
+        entity_id: EntityId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<AccountId>>;
+}
+
+/// The runtime API the handler queries.
+type RuntimeApi<
+    Block,
+    EntityId,
+    AuthorId,
+    AuthorityId,
+    EntityDetails,
+    AuthorDetails,
+    AuthorityDetails,
+    AccountId,
+> = dyn ApiIpOnchainRuntime<
+    Block,
+    EntityId,
+    AuthorId,
+    AuthorityId,
+    EntityDetails,
+    AuthorDetails,
+    AuthorityDetails,
+    AccountId,
+>;
+
+/// Retrieves the version of the runtime API at the block `at`.
+///
+/// The runtime API returns the details of entities, authors and authorities in the layouts of
+/// `LayoutV1` before version 2, the handler converts them to the current layouts.
+fn api_version<Block: BlockT, Api: ApiExt<Block>, Info: RuntimeApiInfo + ?Sized>(
+    api: &Api,
+    at: Block::Hash,
+) -> RpcResult<u32> {
+    let version = api.api_version::<Info>(at).map_err(|e| {
+        ErrorObject::owned(
+            Error::RuntimeError.into(),
+            "Unable to query the runtime API version.",
+            Some(e.to_string()),
+        )
+    })?;
+
+    version.ok_or_else(|| {
+        ErrorObject::owned(
+            Error::RuntimeError.into(),
+            "The runtime API is not available at this block.",
+            None::<()>,
+        )
+    })
 }
 
 pub struct IpOnchainRpcHandler<C, B> {
@@ -94,7 +237,17 @@ impl<C, B> IpOnchainRpcHandler<C, B> {
     }
 }
 
-impl<C, Block, EntityId, AuthorId, AuthorityId, EntityDetails, AuthorDetails, AuthorityDetails>
+impl<
+        C,
+        Block,
+        EntityId,
+        AuthorId,
+        AuthorityId,
+        EntityDetails,
+        AuthorDetails,
+        AuthorityDetails,
+        AccountId,
+    >
     IpOnchainRpcApiServer<
         <Block as BlockT>::Hash,
         EntityId,
@@ -103,6 +256,7 @@ impl<C, Block, EntityId, AuthorId, AuthorityId, EntityDetails, AuthorDetails, Au
         EntityDetails,
         AuthorDetails,
         AuthorityDetails,
+        AccountId,
     > for IpOnchainRpcHandler<C, Block>
 where
     C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
@@ -114,19 +268,42 @@ where
         EntityDetails,
         AuthorDetails,
         AuthorityDetails,
+        AccountId,
     >,
     Block: BlockT,
-    EntityId: Codec + Send + Sync + 'static,
+    EntityId: Codec + Clone + Send + Sync + 'static,
     AuthorId: Codec + Send + Sync + 'static,
     AuthorityId: Codec + Send + Sync + 'static,
-    EntityDetails: Codec + Send + Sync + 'static,
-    AuthorDetails: Codec + Send + Sync + 'static,
-    AuthorityDetails: Codec + Send + Sync + 'static,
+    EntityDetails: Codec + LayoutV1<Id = EntityId> + Send + Sync + 'static,
+    AuthorDetails: Codec + LayoutV1<Id = ()> + Send + Sync + 'static,
+    AuthorityDetails: Codec + LayoutV1<Id = ()> + Send + Sync + 'static,
+    AccountId: Codec + Send + Sync + 'static,
 {
+    #[allow(deprecated)]
     fn entity(&self, entity_id: EntityId, at: Option<Block::Hash>) -> RpcResult<EntityDetails> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
-        let result = api.entity(at, entity_id).map_err(|e| {
+        let version = api_version::<
+            Block,
+            _,
+            RuntimeApi<
+                Block,
+                EntityId,
+                AuthorId,
+                AuthorityId,
+                EntityDetails,
+                AuthorDetails,
+                AuthorityDetails,
+                AccountId,
+            >,
+        >(&*api, at)?;
+        let result = if version < 2 {
+            api.entity_before_version_2(at, entity_id.clone())
+                .map(|result| result.map(|details| EntityDetails::from_v1(&entity_id, details)))
+        } else {
+            api.entity(at, entity_id)
+        }
+        .map_err(|e| {
             ErrorObject::owned(
                 Error::RuntimeError.into(),
                 "Unable to query details.",
@@ -143,6 +320,7 @@ where
         })?)
     }
 
+    #[allow(deprecated)]
     fn entities(
         &self,
         from: EntityId,
@@ -151,7 +329,36 @@ where
     ) -> RpcResult<Vec<(EntityId, EntityDetails)>> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
-        let result = api.entities(at, from, to).map_err(|e| {
+        let version = api_version::<
+            Block,
+            _,
+            RuntimeApi<
+                Block,
+                EntityId,
+                AuthorId,
+                AuthorityId,
+                EntityDetails,
+                AuthorDetails,
+                AuthorityDetails,
+                AccountId,
+            >,
+        >(&*api, at)?;
+        let result = if version < 2 {
+            api.entities_before_version_2(at, from, to).map(|result| {
+                result.map(|records| {
+                    records
+                        .into_iter()
+                        .map(|(id, details)| {
+                            let details = EntityDetails::from_v1(&id, details);
+                            (id, details)
+                        })
+                        .collect()
+                })
+            })
+        } else {
+            api.entities(at, from, to)
+        }
+        .map_err(|e| {
             ErrorObject::owned(
                 Error::RuntimeError.into(),
                 "Unable to query details.",
@@ -168,10 +375,31 @@ where
         })?)
     }
 
+    #[allow(deprecated)]
     fn author(&self, author_id: AuthorId, at: Option<Block::Hash>) -> RpcResult<AuthorDetails> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
-        let result = api.author(at, author_id).map_err(|e| {
+        let version = api_version::<
+            Block,
+            _,
+            RuntimeApi<
+                Block,
+                EntityId,
+                AuthorId,
+                AuthorityId,
+                EntityDetails,
+                AuthorDetails,
+                AuthorityDetails,
+                AccountId,
+            >,
+        >(&*api, at)?;
+        let result = if version < 2 {
+            api.author_before_version_2(at, author_id)
+                .map(|result| result.map(|details| AuthorDetails::from_v1(&(), details)))
+        } else {
+            api.author(at, author_id)
+        }
+        .map_err(|e| {
             ErrorObject::owned(
                 Error::RuntimeError.into(),
                 "Unable to query details.",
@@ -187,6 +415,7 @@ where
             )
         })?)
     }
+    #[allow(deprecated)]
     fn authors(
         &self,
         from: AuthorId,
@@ -195,7 +424,36 @@ where
     ) -> RpcResult<Vec<(AuthorId, AuthorDetails)>> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
-        let result = api.authors(at, from, to).map_err(|e| {
+        let version = api_version::<
+            Block,
+            _,
+            RuntimeApi<
+                Block,
+                EntityId,
+                AuthorId,
+                AuthorityId,
+                EntityDetails,
+                AuthorDetails,
+                AuthorityDetails,
+                AccountId,
+            >,
+        >(&*api, at)?;
+        let result = if version < 2 {
+            api.authors_before_version_2(at, from, to).map(|result| {
+                result.map(|records| {
+                    records
+                        .into_iter()
+                        .map(|(id, details)| {
+                            let details = AuthorDetails::from_v1(&(), details);
+                            (id, details)
+                        })
+                        .collect()
+                })
+            })
+        } else {
+            api.authors(at, from, to)
+        }
+        .map_err(|e| {
             ErrorObject::owned(
                 Error::RuntimeError.into(),
                 "Unable to query details.",
@@ -212,6 +470,7 @@ where
         })?)
     }
 
+    #[allow(deprecated)]
     fn authority(
         &self,
         authority_id: AuthorityId,
@@ -219,7 +478,27 @@ where
     ) -> RpcResult<AuthorityDetails> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
-        let result = api.authority(at, authority_id).map_err(|e| {
+        let version = api_version::<
+            Block,
+            _,
+            RuntimeApi<
+                Block,
+                EntityId,
+                AuthorId,
+                AuthorityId,
+                EntityDetails,
+                AuthorDetails,
+                AuthorityDetails,
+                AccountId,
+            >,
+        >(&*api, at)?;
+        let result = if version < 2 {
+            api.authority_before_version_2(at, authority_id)
+                .map(|result| result.map(|details| AuthorityDetails::from_v1(&(), details)))
+        } else {
+            api.authority(at, authority_id)
+        }
+        .map_err(|e| {
             ErrorObject::owned(
                 Error::RuntimeError.into(),
                 "Unable to query details.",
@@ -235,6 +514,7 @@ where
             )
         })?)
     }
+    #[allow(deprecated)]
     fn authorities(
         &self,
         from: AuthorityId,
@@ -243,7 +523,262 @@ where
     ) -> RpcResult<Vec<(AuthorityId, AuthorityDetails)>> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
-        let result = api.authorities(at, from, to).map_err(|e| {
+        let version = api_version::<
+            Block,
+            _,
+            RuntimeApi<
+                Block,
+                EntityId,
+                AuthorId,
+                AuthorityId,
+                EntityDetails,
+                AuthorDetails,
+                AuthorityDetails,
+                AccountId,
+            >,
+        >(&*api, at)?;
+        let result = if version < 2 {
+            api.authorities_before_version_2(at, from, to)
+                .map(|result| {
+                    result.map(|records| {
+                        records
+                            .into_iter()
+                            .map(|(id, details)| {
+                                let details = AuthorityDetails::from_v1(&(), details);
+                                (id, details)
+                            })
+                            .collect()
+                    })
+                })
+        } else {
+            api.authorities(at, from, to)
+        }
+        .map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e.to_string()),
+            )
+        })?;
+
+        Ok(result.map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e),
+            )
+        })?)
+    }
+
+    fn entities_of_authority(
+        &self,
+        authority_id: AuthorityId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<EntityId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.entities_of_authority(at, authority_id).map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e.to_string()),
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    fn entities_of_author(
+        &self,
+        author_id: AuthorId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<EntityId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.entities_of_author(at, author_id).map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e.to_string()),
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    fn account_overview(
+        &self,
+        account_id: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<AccountOverview<AuthorId, AuthorityId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.account_overview(at, account_id).map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e.to_string()),
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    fn derivatives(
+        &self,
+        entity_id: EntityId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(EntityId, RelationKind)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.derivatives(at, entity_id).map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e.to_string()),
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    fn is_licensed(
+        &self,
+        account_id: AccountId,
+        entity_id: EntityId,
+        kind: LicenseKind,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api
+            .is_licensed(at, account_id, entity_id, kind)
+            .map_err(|e| {
+                ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to query details.",
+                    Some(e.to_string()),
+                )
+            })?;
+
+        Ok(result)
+    }
+
+    fn metadata_standards(&self, at: Option<Block::Hash>) -> RpcResult<Vec<MetadataStandard>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.metadata_standards(at).map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e.to_string()),
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    fn entity_by_content_hash(
+        &self,
+        content_hash: Vec<u8>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<EntityId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.entity_by_content_hash(at, content_hash).map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e.to_string()),
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    fn entity_by_identifier(
+        &self,
+        identifier: IndustryIdentifier,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<EntityId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.entity_by_identifier(at, identifier).map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e.to_string()),
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    fn author_by_ipi(&self, ipi: Ipi, at: Option<Block::Hash>) -> RpcResult<Option<AuthorId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.author_by_ipi(at, ipi).map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e.to_string()),
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    fn entity_revision(
+        &self,
+        entity_id: EntityId,
+        revision: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<(AccountId, EntityDetails)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.entity_revision(at, entity_id, revision).map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e.to_string()),
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    fn release(
+        &self,
+        entity_id: EntityId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<(EntityDetails, Vec<(u32, EntityId, EntityDetails)>)> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.release(at, entity_id).map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e.to_string()),
+            )
+        })?;
+
+        Ok(result.map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query details.",
+                Some(e),
+            )
+        })?)
+    }
+
+    fn entity_nft_holder(
+        &self,
+        entity_id: EntityId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.entity_nft_holder(at, entity_id).map_err(|e| {
             ErrorObject::owned(
                 Error::RuntimeError.into(),
                 "Unable to query details.",
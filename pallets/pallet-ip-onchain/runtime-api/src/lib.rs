@@ -7,23 +7,68 @@ use scale_codec::Codec;
 extern crate alloc;
 use alloc::vec::Vec;
 
+pub use pallet_ip_onchain::{
+    AccountOverview, AuthorStatus, IndustryIdentifier, Ipi, Isrc, Iswc, LayoutV1, LicenseKind,
+    MetadataStandard, MetadataVersion, RelationKind, Upc,
+};
+
 sp_api::decl_runtime_apis! {
-    pub trait ApiIpOnchainRuntime<EntityId, AuthorId, AuthorityId, EntityDetails, AuthorDetails, AuthorityDetails>
+    /// Queries of the IP registry.
+    ///
+    /// Version 2 added all queries after `authorities` and changed the layouts of the entity, author and
+    /// authority details. The details returned by version 1 have the layouts of storage version 1, see
+    /// `LayoutV1`.
+    #[api_version(2)]
+    pub trait ApiIpOnchainRuntime<EntityId, AuthorId, AuthorityId, EntityDetails, AuthorDetails, AuthorityDetails, AccountId>
     where
         EntityId: Codec,
         AuthorId: Codec,
         AuthorityId: Codec,
-        EntityDetails: Codec,
-        AuthorDetails: Codec,
-        AuthorityDetails: Codec,
+        EntityDetails: Codec + LayoutV1,
+        AuthorDetails: Codec + LayoutV1,
+        AuthorityDetails: Codec + LayoutV1,
+        AccountId: Codec,
     {
+        #[changed_in(2)]
+        fn entity(entity_id: EntityId) -> Result<<EntityDetails as LayoutV1>::V1, sp_runtime::DispatchError>;
         fn entity(entity_id: EntityId) -> Result<EntityDetails, sp_runtime::DispatchError>;
+        #[changed_in(2)]
+        fn entities(from: EntityId, to: EntityId) -> Result<Vec<(EntityId, <EntityDetails as LayoutV1>::V1)>, sp_runtime::DispatchError>;
         fn entities(from: EntityId, to: EntityId) -> Result<Vec<(EntityId, EntityDetails)>, sp_runtime::DispatchError>;
 
+        #[changed_in(2)]
+        fn author(author_id: AuthorId) -> Result<<AuthorDetails as LayoutV1>::V1, sp_runtime::DispatchError>;
         fn author(author_id: AuthorId) -> Result<AuthorDetails, sp_runtime::DispatchError>;
+        #[changed_in(2)]
+        fn authors(from: AuthorId, to: AuthorId) -> Result<Vec<(AuthorId, <AuthorDetails as LayoutV1>::V1)>, sp_runtime::DispatchError>;
         fn authors(from: AuthorId, to: AuthorId) -> Result<Vec<(AuthorId, AuthorDetails)>, sp_runtime::DispatchError>;
 
+        #[changed_in(2)]
+        fn authority(authority_id: AuthorityId) -> Result<<AuthorityDetails as LayoutV1>::V1, sp_runtime::DispatchError>;
         fn authority(authority_id: AuthorityId) -> Result<AuthorityDetails, sp_runtime::DispatchError>;
+        #[changed_in(2)]
+        fn authorities(from: AuthorityId, to: AuthorityId) -> Result<Vec<(AuthorityId, <AuthorityDetails as LayoutV1>::V1)>, sp_runtime::DispatchError>;
         fn authorities(from: AuthorityId, to: AuthorityId) -> Result<Vec<(AuthorityId, AuthorityDetails)>, sp_runtime::DispatchError>;
+
+        fn entities_of_authority(authority_id: AuthorityId) -> Vec<EntityId>;
+        fn entities_of_author(author_id: AuthorId) -> Vec<EntityId>;
+        fn account_overview(account_id: AccountId) -> AccountOverview<AuthorId, AuthorityId>;
+
+        fn derivatives(entity_id: EntityId) -> Vec<(EntityId, RelationKind)>;
+
+        fn is_licensed(account_id: AccountId, entity_id: EntityId, kind: LicenseKind) -> bool;
+
+        fn metadata_standards() -> Vec<MetadataStandard>;
+
+        fn entity_by_content_hash(content_hash: Vec<u8>) -> Option<EntityId>;
+
+        fn entity_by_identifier(identifier: IndustryIdentifier) -> Option<EntityId>;
+        fn author_by_ipi(ipi: Ipi) -> Option<AuthorId>;
+
+        fn entity_revision(entity_id: EntityId, revision: u32) -> Option<(AccountId, EntityDetails)>;
+
+        fn release(entity_id: EntityId) -> Result<(EntityDetails, Vec<(u32, EntityId, EntityDetails)>), sp_runtime::DispatchError>;
+
+        fn entity_nft_holder(entity_id: EntityId) -> Result<Option<AccountId>, sp_runtime::DispatchError>;
     }
 }
@@ -3,10 +3,14 @@
 use super::*;
 
 extern crate alloc;
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 
 use polkadot_sdk::*;
 
+use frame::arithmetic::Perbill;
+use frame::traits::fungible::{Inspect as _, Mutate as _};
+use frame::traits::fungibles::{Create as _, Inspect as _, Mutate as _};
+use frame::traits::tokens::nonfungibles_v2::Inspect as _;
 use frame_benchmarking::v2::*;
 
 #[benchmarks]
@@ -20,6 +24,7 @@ mod benchmarks {
     fn create_author() {
         let author_id: T::AuthorId = T::AuthorId::initial_value().unwrap();
         let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
         let nickname: BoundedVec<u8, T::MaxShortStringLength> = vec![1, 2, 3].try_into().unwrap();
         let real_name: Option<BoundedVec<u8, T::MaxLongStringLength>> =
             Some(vec![4, 5, 6].try_into().unwrap());
@@ -33,6 +38,7 @@ mod benchmarks {
     #[benchmark]
     fn edit_author() {
         let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
         let author_id: T::AuthorId = T::AuthorId::initial_value().unwrap();
 
         Authors::<T>::insert(
@@ -41,6 +47,10 @@ mod benchmarks {
                 nickname: vec![0].try_into().unwrap(),
                 real_name: Some(vec![1].try_into().unwrap()),
                 owner: caller.clone(),
+                status: AuthorStatus::Claimed,
+                ipi: None,
+                created_at: Default::default(),
+                updated_at: Default::default(),
             },
         );
 
@@ -62,6 +72,7 @@ mod benchmarks {
     #[benchmark]
     fn create_authority() {
         let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
         let name: BoundedVec<u8, T::MaxShortStringLength> = vec![1, 2, 3].try_into().unwrap();
         let collection_cfg: T::CollectionConfig = Default::default();
 
@@ -80,6 +91,7 @@ mod benchmarks {
     #[benchmark]
     fn edit_authority() {
         let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
         let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
         let collection_cfg: T::CollectionConfig = Default::default();
 
@@ -89,6 +101,8 @@ mod benchmarks {
                 authority_kind: AuthorityKind::Label,
                 name: vec![0].try_into().unwrap(),
                 collection_id: None,
+                created_at: Default::default(),
+                updated_at: Default::default(),
             },
         );
 
@@ -117,7 +131,7 @@ mod benchmarks {
     #[benchmark]
     fn create_entity() {
         let caller: T::AccountId = whitelisted_caller();
-        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
 
         let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
         let url: BoundedVec<u8, T::MaxLongStringLength> = vec![4, 5, 6].try_into().unwrap();
@@ -131,6 +145,8 @@ mod benchmarks {
                 authority_kind: AuthorityKind::Label,
                 name: vec![0].try_into().unwrap(),
                 collection_id: Some(collection_id),
+                created_at: Default::default(),
+                updated_at: Default::default(),
             },
         );
 
@@ -165,7 +181,7 @@ mod benchmarks {
     #[benchmark]
     fn edit_entity() {
         let caller: T::AccountId = whitelisted_caller();
-        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
 
         let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
         let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
@@ -194,6 +210,11 @@ mod benchmarks {
                 },
                 collection_id: None,
                 item_id: None,
+                content: None,
+                identifiers: Default::default(),
+                created_at: Default::default(),
+                updated_at: Default::default(),
+                revision: 0,
             },
         );
 
@@ -203,6 +224,8 @@ mod benchmarks {
                 authority_kind: AuthorityKind::Label,
                 name: vec![0].try_into().unwrap(),
                 collection_id: Some(collection_id),
+                created_at: Default::default(),
+                updated_at: Default::default(),
             },
         );
 
@@ -234,6 +257,7 @@ mod benchmarks {
     #[benchmark]
     fn create_account_access() {
         let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
         let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
         let account_id: T::AccountId = account("other", 1, 1);
         let access: AuthorityAccessSettings = AuthorityAccessSettings::all();
@@ -244,6 +268,8 @@ mod benchmarks {
                 authority_kind: AuthorityKind::Label,
                 name: vec![0].try_into().unwrap(),
                 collection_id: None,
+                created_at: Default::default(),
+                updated_at: Default::default(),
             },
         );
 
@@ -259,6 +285,7 @@ mod benchmarks {
             authority_id,
             account_id.clone(),
             access,
+            None,
         );
 
         assert!(AuthoritiesAccess::<T>::contains_key(
@@ -271,7 +298,7 @@ mod benchmarks {
     fn edit_account_access() {
         let caller: T::AccountId = whitelisted_caller();
         let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
-        let account_id: T::AccountId = whitelisted_caller();
+        let account_id: T::AccountId = account("member", 0, 1);
         let new_access: AuthorityAccessSettings = AuthorityAccessSettings::none();
 
         Authorities::<T>::insert(
@@ -280,14 +307,20 @@ mod benchmarks {
                 authority_kind: AuthorityKind::Label,
                 name: vec![0].try_into().unwrap(),
                 collection_id: None,
+                created_at: Default::default(),
+                updated_at: Default::default(),
             },
         );
 
-        AuthoritiesAccess::<T>::insert(
+        // Worst case: the account loses the `EditAccess` right, which is still held by the caller.
+        Pallet::<T>::write_access(authority_id, &caller, Some(AuthorityAccessSettings::all()))
+            .unwrap();
+        Pallet::<T>::write_access(
             authority_id,
-            account_id.clone(),
-            AuthorityAccessSettings::all(),
-        );
+            &account_id,
+            Some(AuthorityAccessSettings::all()),
+        )
+        .unwrap();
 
         #[extrinsic_call]
         edit_account_access(
@@ -295,6 +328,7 @@ mod benchmarks {
             authority_id,
             account_id.clone(),
             new_access,
+            None,
         );
 
         let updated_access = AuthoritiesAccess::<T>::get(authority_id, account_id).unwrap();
@@ -310,5 +344,1638 @@ mod benchmarks {
         assert_eq!(enabled, true);
     }
 
-    impl_benchmark_test_suite!(IpOnchain, mock::new_test_ext(), mock::Test);
+    #[benchmark]
+    fn pay_royalties(p: Linear<1, { T::MaxRoyaltyParts::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        insert_entity::<T>(entity_id, authority_id, Some(royalty_parts::<T>(p)));
+
+        let amount = T::Currency::minimum_balance() * 1000u32.into() * p.into();
+
+        #[extrinsic_call]
+        pay_royalties(
+            RawOrigin::Signed(caller),
+            entity_id,
+            amount,
+            PaymentCurrency::Native,
+        );
+
+        let recipient: T::AccountId = account("recipient", 0, 1);
+        assert!(!T::Currency::balance(&recipient).is_zero());
+    }
+
+    #[benchmark]
+    fn add_license_offer() {
+        let caller: T::AccountId = whitelisted_caller();
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+        insert_entity::<T>(entity_id, authority_id, Some(royalty_parts::<T>(1)));
+
+        #[extrinsic_call]
+        add_license_offer(RawOrigin::Signed(caller), entity_id, license_offer::<T>());
+
+        assert_eq!(LicenseOffers::<T>::get(entity_id).len(), 1);
+    }
+
+    #[benchmark]
+    fn remove_license_offer() {
+        let caller: T::AccountId = whitelisted_caller();
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+        insert_entity::<T>(entity_id, authority_id, None);
+
+        let offers: BoundedVec<(u32, LicenseOfferFor<T>), T::MaxLicenseOffers> = (0
+            ..T::MaxLicenseOffers::get())
+            .map(|offer_id| (offer_id, license_offer::<T>()))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        LicenseOffers::<T>::insert(entity_id, offers);
+
+        #[extrinsic_call]
+        remove_license_offer(
+            RawOrigin::Signed(caller),
+            entity_id,
+            T::MaxLicenseOffers::get() - 1,
+        );
+
+        assert_eq!(
+            LicenseOffers::<T>::get(entity_id).len() as u32,
+            T::MaxLicenseOffers::get() - 1
+        );
+    }
+
+    #[benchmark]
+    fn purchase_license(p: Linear<1, { T::MaxRoyaltyParts::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        insert_entity::<T>(entity_id, authority_id, Some(royalty_parts::<T>(p)));
+
+        let mut offer = license_offer::<T>();
+        offer.price = T::Currency::minimum_balance() * 1000u32.into() * p.into();
+        let price = offer.price;
+        LicenseOffers::<T>::insert(
+            entity_id,
+            BoundedVec::<_, T::MaxLicenseOffers>::try_from(vec![(0, offer)]).unwrap(),
+        );
+
+        #[extrinsic_call]
+        purchase_license(
+            RawOrigin::Signed(caller.clone()),
+            entity_id,
+            0,
+            price,
+            PaymentCurrency::Native,
+        );
+
+        assert!(Pallet::<T>::is_licensed(
+            caller,
+            entity_id,
+            LicenseKind::Streaming
+        ));
+    }
+
+    #[benchmark]
+    fn remove_author() {
+        let caller: T::AccountId = whitelisted_caller();
+        let author_id: T::AuthorId = T::AuthorId::initial_value().unwrap();
+
+        Authors::<T>::insert(
+            author_id,
+            AuthorDetails {
+                nickname: vec![0].try_into().unwrap(),
+                real_name: None,
+                owner: caller.clone(),
+                status: AuthorStatus::Claimed,
+                ipi: None,
+                created_at: Default::default(),
+                updated_at: Default::default(),
+            },
+        );
+        AuthorsByOwner::<T>::insert(&caller, author_id, ());
+
+        #[extrinsic_call]
+        remove_author(RawOrigin::Signed(caller), author_id);
+
+        assert!(!Authors::<T>::contains_key(author_id));
+    }
+
+    #[benchmark]
+    fn remove_authority(a: Linear<1, 100>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        Authorities::<T>::insert(
+            authority_id,
+            AuthorityDetails {
+                authority_kind: AuthorityKind::Label,
+                name: vec![0].try_into().unwrap(),
+                collection_id: None,
+                created_at: Default::default(),
+                updated_at: Default::default(),
+            },
+        );
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+        for i in 1..a {
+            AuthoritiesAccess::<T>::insert(
+                authority_id,
+                account::<T::AccountId>("member", i, 1),
+                AuthorityAccessSettings::none(),
+            );
+        }
+
+        #[extrinsic_call]
+        remove_authority(RawOrigin::Signed(caller), authority_id, a);
+
+        assert!(!Authorities::<T>::contains_key(authority_id));
+    }
+
+    #[benchmark]
+    fn remove_entity(
+        a: Linear<0, { T::MaxEntityAuthors::get() }>,
+        r: Linear<0, { T::MaxRelatedEntities::get() }>,
+    ) {
+        let caller: T::AccountId = whitelisted_caller();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+
+        let mut author_id: T::AuthorId = T::AuthorId::initial_value().unwrap();
+        let mut authors = Vec::new();
+        for _ in 0..a {
+            authors.push(author_id);
+            author_id = author_id.increment().unwrap();
+        }
+
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let mut source_id = entity_id;
+        let mut related_to = Vec::new();
+        for _ in 0..r {
+            source_id = source_id.increment().unwrap();
+            related_to.push(Relation {
+                kind: RelationKind::RemixOf,
+                entity_id: source_id,
+            });
+        }
+
+        insert_entity::<T>(entity_id, authority_id, None);
+        Entities::<T>::mutate(entity_id, |entity| {
+            let entity = entity.as_mut().unwrap();
+            entity.authors = Some(authors.try_into().unwrap());
+            entity.related_to = Some(related_to.try_into().unwrap());
+        });
+        let entity = Entities::<T>::get(entity_id).unwrap();
+        Pallet::<T>::index_entity(entity_id, None, Some(&entity));
+        Pallet::<T>::index_relations(entity_id, &None, &entity.related_to);
+
+        #[extrinsic_call]
+        remove_entity(RawOrigin::Signed(caller), entity_id, 0);
+
+        assert!(!Entities::<T>::contains_key(entity_id));
+    }
+
+    #[benchmark]
+    fn remove_account_access() {
+        let caller: T::AccountId = whitelisted_caller();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let account_id: T::AccountId = account("member", 0, 1);
+
+        Authorities::<T>::insert(
+            authority_id,
+            AuthorityDetails {
+                authority_kind: AuthorityKind::Label,
+                name: vec![0].try_into().unwrap(),
+                collection_id: None,
+                created_at: Default::default(),
+                updated_at: Default::default(),
+            },
+        );
+
+        Pallet::<T>::write_access(authority_id, &caller, Some(AuthorityAccessSettings::all()))
+            .unwrap();
+        Pallet::<T>::write_access(
+            authority_id,
+            &account_id,
+            Some(AuthorityAccessSettings::all()),
+        )
+        .unwrap();
+
+        #[extrinsic_call]
+        remove_account_access(RawOrigin::Signed(caller), authority_id, account_id.clone());
+
+        assert!(!AuthoritiesAccess::<T>::contains_key(
+            authority_id,
+            account_id
+        ));
+        assert_eq!(EditAccessHolders::<T>::get(authority_id), 1);
+    }
+
+    #[benchmark]
+    fn offer_entity_transfer() {
+        let caller: T::AccountId = whitelisted_caller();
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let to_authority: T::AuthorityId = authority_id.increment().unwrap();
+
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+        insert_authority::<T>(to_authority, None);
+        insert_entity::<T>(entity_id, authority_id, None);
+
+        let price = T::Currency::minimum_balance();
+
+        #[extrinsic_call]
+        offer_entity_transfer(
+            RawOrigin::Signed(caller),
+            entity_id,
+            to_authority,
+            price,
+            PaymentCurrency::Native,
+        );
+
+        assert!(EntityTransfers::<T>::contains_key(entity_id));
+    }
+
+    #[benchmark]
+    fn cancel_entity_transfer() {
+        let caller: T::AccountId = whitelisted_caller();
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let to_authority: T::AuthorityId = authority_id.increment().unwrap();
+
+        // Worst case: the caller has no access to the receiving authority, so both sides are checked.
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+        insert_entity::<T>(entity_id, authority_id, None);
+        EntityTransfers::<T>::insert(
+            entity_id,
+            EntityTransfer {
+                to_authority,
+                price: T::Currency::minimum_balance(),
+                beneficiary: caller.clone(),
+                currency: PaymentCurrency::Native,
+            },
+        );
+
+        #[extrinsic_call]
+        cancel_entity_transfer(RawOrigin::Signed(caller), entity_id);
+
+        assert!(!EntityTransfers::<T>::contains_key(entity_id));
+    }
+
+    #[benchmark]
+    fn accept_entity_transfer() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let to_authority: T::AuthorityId = authority_id.increment().unwrap();
+        let item_id: T::ItemId = T::BenchmarkHelper::item_id(1);
+
+        let collection_cfg: T::CollectionConfig = Default::default();
+        let collection_id = T::Nfts::create_collection(&caller, &caller, &collection_cfg).unwrap();
+        let to_collection_id =
+            T::Nfts::create_collection(&caller, &caller, &collection_cfg).unwrap();
+        T::Nfts::mint_into(
+            &collection_id,
+            &item_id,
+            &caller,
+            &ItemConfig::default(),
+            true,
+        )
+        .unwrap();
+
+        insert_authority::<T>(authority_id, Some(collection_id));
+        insert_authority::<T>(to_authority, Some(to_collection_id));
+        AuthoritiesAccess::<T>::insert(
+            to_authority,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+        insert_entity::<T>(entity_id, authority_id, None);
+        Entities::<T>::mutate(entity_id, |entity| {
+            let entity = entity.as_mut().unwrap();
+            entity.collection_id = Some(collection_id);
+            entity.item_id = Some(item_id);
+        });
+
+        let beneficiary: T::AccountId = account("beneficiary", 0, 1);
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            beneficiary.clone(),
+            AuthorityAccessSettings::all(),
+        );
+
+        let price = T::Currency::minimum_balance() * 1000u32.into();
+        EntityTransfers::<T>::insert(
+            entity_id,
+            EntityTransfer {
+                to_authority,
+                price,
+                beneficiary,
+                currency: PaymentCurrency::Native,
+            },
+        );
+
+        #[extrinsic_call]
+        accept_entity_transfer(
+            RawOrigin::Signed(caller),
+            entity_id,
+            price,
+            PaymentCurrency::Native,
+        );
+
+        let entity = Entities::<T>::get(entity_id).unwrap();
+        assert_eq!(entity.owner, to_authority);
+        assert_eq!(entity.collection_id, Some(to_collection_id));
+    }
+
+    #[benchmark]
+    fn claim_author() {
+        let caller: T::AccountId = whitelisted_caller();
+        let author_id: T::AuthorId = T::AuthorId::initial_value().unwrap();
+
+        Authors::<T>::insert(
+            author_id,
+            AuthorDetails {
+                nickname: vec![0].try_into().unwrap(),
+                real_name: None,
+                owner: caller.clone(),
+                status: AuthorStatus::Unclaimed,
+                ipi: None,
+                created_at: Default::default(),
+                updated_at: Default::default(),
+            },
+        );
+
+        #[extrinsic_call]
+        claim_author(RawOrigin::Signed(caller), author_id);
+
+        assert_eq!(
+            Authors::<T>::get(author_id).unwrap().status,
+            AuthorStatus::Claimed
+        );
+    }
+
+    #[benchmark]
+    fn verify_author() -> Result<(), BenchmarkError> {
+        let origin =
+            T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let author_id: T::AuthorId = T::AuthorId::initial_value().unwrap();
+
+        Authors::<T>::insert(
+            author_id,
+            AuthorDetails {
+                nickname: vec![0].try_into().unwrap(),
+                real_name: None,
+                owner: whitelisted_caller(),
+                status: AuthorStatus::Claimed,
+                ipi: None,
+                created_at: Default::default(),
+                updated_at: Default::default(),
+            },
+        );
+
+        #[extrinsic_call]
+        verify_author(origin as T::RuntimeOrigin, author_id, true);
+
+        assert_eq!(
+            Authors::<T>::get(author_id).unwrap().status,
+            AuthorStatus::Verified
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_permissionless_mode() {
+        #[extrinsic_call]
+        set_permissionless_mode(RawOrigin::Root, true);
+
+        assert!(PermissionlessMode::<T>::get());
+    }
+
+    #[benchmark]
+    fn set_entity_content() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+        insert_entity::<T>(entity_id, authority_id, None);
+
+        // Worst case: the entity already anchored a content, which is replaced.
+        Pallet::<T>::set_content(caller.clone(), entity_id, Some(content_commitment::<T>(1)))
+            .unwrap();
+        let content = content_commitment::<T>(2);
+
+        #[extrinsic_call]
+        set_entity_content(RawOrigin::Signed(caller), entity_id, Some(content.clone()));
+
+        assert_eq!(
+            Entities::<T>::get(entity_id).unwrap().content,
+            Some(content)
+        );
+    }
+
+    #[benchmark]
+    fn set_entity_identifiers() {
+        let caller: T::AccountId = whitelisted_caller();
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+        insert_entity::<T>(entity_id, authority_id, None);
+
+        // Worst case: all identifiers of the entity are replaced.
+        Pallet::<T>::set_identifiers(caller.clone(), entity_id, entity_identifiers(b'1')).unwrap();
+        let identifiers = entity_identifiers(b'2');
+
+        #[extrinsic_call]
+        set_entity_identifiers(RawOrigin::Signed(caller), entity_id, identifiers.clone());
+
+        assert_eq!(
+            Entities::<T>::get(entity_id).unwrap().identifiers,
+            identifiers
+        );
+    }
+
+    #[benchmark]
+    fn set_author_ipi() {
+        let caller: T::AccountId = whitelisted_caller();
+        let author_id: T::AuthorId = T::AuthorId::initial_value().unwrap();
+
+        Authors::<T>::insert(
+            author_id,
+            AuthorDetails {
+                nickname: vec![0].try_into().unwrap(),
+                real_name: None,
+                owner: caller.clone(),
+                status: AuthorStatus::Verified,
+                ipi: None,
+                created_at: Default::default(),
+                updated_at: Default::default(),
+            },
+        );
+
+        // Worst case: the IPI name number of the author is replaced.
+        Pallet::<T>::set_ipi(caller.clone(), author_id, Some(Ipi(*b"00000000001"))).unwrap();
+
+        #[extrinsic_call]
+        set_author_ipi(
+            RawOrigin::Signed(caller),
+            author_id,
+            Some(Ipi(*b"00000000002")),
+        );
+
+        assert_eq!(
+            Authors::<T>::get(author_id).unwrap().ipi,
+            Some(Ipi(*b"00000000002"))
+        );
+    }
+
+    #[benchmark]
+    fn open_dispute() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        insert_entity::<T>(entity_id, authority_id, None);
+        let evidence_url: BoundedVec<u8, T::MaxLongStringLength> =
+            vec![0; T::MaxLongStringLength::get() as usize]
+                .try_into()
+                .unwrap();
+
+        #[extrinsic_call]
+        open_dispute(RawOrigin::Signed(caller), entity_id, evidence_url);
+
+        assert!(Disputes::<T>::contains_key(entity_id));
+    }
+
+    #[benchmark]
+    fn resolve_dispute() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ArbiterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let to_authority: T::AuthorityId = authority_id.increment().unwrap();
+        let item_id: T::ItemId = T::BenchmarkHelper::item_id(1);
+
+        // Worst case: the entity and its NFT move to another authority.
+        let collection_cfg: T::CollectionConfig = Default::default();
+        let collection_id = T::Nfts::create_collection(&caller, &caller, &collection_cfg).unwrap();
+        let to_collection_id =
+            T::Nfts::create_collection(&caller, &caller, &collection_cfg).unwrap();
+        T::Nfts::mint_into(
+            &collection_id,
+            &item_id,
+            &caller,
+            &ItemConfig::default(),
+            true,
+        )
+        .unwrap();
+
+        insert_authority::<T>(authority_id, Some(collection_id));
+        insert_authority::<T>(to_authority, Some(to_collection_id));
+        insert_entity::<T>(entity_id, authority_id, None);
+        Entities::<T>::mutate(entity_id, |entity| {
+            let entity = entity.as_mut().unwrap();
+            entity.collection_id = Some(collection_id);
+            entity.item_id = Some(item_id);
+        });
+        Pallet::<T>::add_dispute(caller, entity_id, vec![0].try_into().unwrap()).unwrap();
+
+        #[extrinsic_call]
+        resolve_dispute(
+            origin as T::RuntimeOrigin,
+            entity_id,
+            DisputeResolution::TransferOwnership(to_authority),
+        );
+
+        let entity = Entities::<T>::get(entity_id).unwrap();
+        assert_eq!(entity.owner, to_authority);
+        assert!(!Disputes::<T>::contains_key(entity_id));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_authority_policy() {
+        let caller: T::AccountId = whitelisted_caller();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let policy = AuthorityPolicy {
+            threshold: 2,
+            actions: GovernedActions::all(),
+        };
+
+        insert_authority::<T>(authority_id, None);
+        insert_edit_access_holders::<T>(authority_id, &caller, 2);
+
+        #[extrinsic_call]
+        set_authority_policy(RawOrigin::Signed(caller), authority_id, policy);
+
+        assert_eq!(AuthorityPolicies::<T>::get(authority_id), Some(policy));
+    }
+
+    #[benchmark]
+    fn propose_authority_action() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let account_id: T::AccountId = account("member", 0, 1);
+
+        insert_authority::<T>(authority_id, None);
+        insert_edit_access_holders::<T>(authority_id, &caller, 2);
+        AuthorityPolicies::<T>::insert(
+            authority_id,
+            AuthorityPolicy {
+                threshold: 2,
+                actions: GovernedActions::all(),
+            },
+        );
+
+        #[extrinsic_call]
+        propose_authority_action(
+            RawOrigin::Signed(caller),
+            authority_id,
+            AuthorityAction::SetAccess {
+                account_id,
+                access: Some(AuthorityAccessSettings::all()),
+                expires_at: None,
+            },
+        );
+
+        assert!(Proposals::<T>::contains_key(authority_id, 0));
+    }
+
+    #[benchmark]
+    fn approve_authority_proposal() {
+        let caller: T::AccountId = whitelisted_caller();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let account_id: T::AccountId = account("member", 0, 1);
+        let threshold = T::MaxPolicyThreshold::get();
+
+        insert_authority::<T>(authority_id, None);
+        insert_edit_access_holders::<T>(authority_id, &caller, threshold);
+        AuthorityPolicies::<T>::insert(
+            authority_id,
+            AuthorityPolicy {
+                threshold,
+                actions: GovernedActions::all(),
+            },
+        );
+
+        // Worst case: every approval but the missing one is checked, the action is weighed separately.
+        let approvals: Vec<T::AccountId> = (1..threshold.saturating_sub(1))
+            .map(|i| account("holder", i, 1))
+            .collect();
+        Proposals::<T>::insert(
+            authority_id,
+            0,
+            AuthorityProposal {
+                proposer: account("holder", 1, 1),
+                action: AuthorityAction::SetAccess {
+                    account_id,
+                    access: Some(AuthorityAccessSettings::all()),
+                    expires_at: None,
+                },
+                approvals: approvals.try_into().unwrap(),
+            },
+        );
+
+        #[extrinsic_call]
+        approve_authority_proposal(RawOrigin::Signed(caller.clone()), authority_id, 0);
+
+        let proposal = Proposals::<T>::get(authority_id, 0).unwrap();
+        assert!(proposal.approvals.contains(&caller));
+    }
+
+    #[benchmark]
+    fn cancel_authority_proposal() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        Proposals::<T>::insert(
+            authority_id,
+            0,
+            AuthorityProposal {
+                proposer: caller.clone(),
+                action: AuthorityAction::SetPolicy(None),
+                approvals: vec![caller.clone()].try_into().unwrap(),
+            },
+        );
+        PendingProposals::<T>::insert(authority_id, 1);
+        let deposit =
+            Pallet::<T>::update_deposit(None, &caller, T::ProposalDeposit::get()).unwrap();
+        ProposalDeposits::<T>::set(authority_id, 0, deposit);
+
+        #[extrinsic_call]
+        cancel_authority_proposal(RawOrigin::Signed(caller), authority_id, 0);
+
+        assert!(!Proposals::<T>::contains_key(authority_id, 0));
+    }
+
+    #[benchmark]
+    fn invite_account_access() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let account_id: T::AccountId = account("member", 0, 1);
+        let access = AuthorityAccessSettings(AuthorityAccessSetting::EditEntity.into());
+
+        insert_authority::<T>(authority_id, None);
+        Pallet::<T>::write_access(authority_id, &caller, Some(AuthorityAccessSettings::all()))
+            .unwrap();
+
+        #[extrinsic_call]
+        invite_account_access(
+            RawOrigin::Signed(caller),
+            authority_id,
+            account_id.clone(),
+            access,
+            Some(1000u32.into()),
+        );
+
+        assert!(AccessInvitations::<T>::contains_key(
+            authority_id,
+            account_id
+        ));
+    }
+
+    #[benchmark]
+    fn accept_account_access() {
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let account_id: T::AccountId = account("member", 0, 1);
+
+        AccessInvitations::<T>::insert(
+            authority_id,
+            &account_id,
+            AccessInvitation {
+                access: AuthorityAccessSettings::all(),
+                expires_at: None,
+            },
+        );
+
+        #[extrinsic_call]
+        accept_account_access(RawOrigin::Signed(account_id.clone()), authority_id);
+
+        assert!(AuthoritiesAccess::<T>::contains_key(
+            authority_id,
+            account_id
+        ));
+    }
+
+    #[benchmark]
+    fn cancel_account_access_invitation() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let account_id: T::AccountId = account("member", 0, 1);
+
+        insert_authority::<T>(authority_id, None);
+        Pallet::<T>::write_access(authority_id, &caller, Some(AuthorityAccessSettings::all()))
+            .unwrap();
+        Pallet::<T>::add_invitation(
+            caller.clone(),
+            authority_id,
+            account_id.clone(),
+            AuthorityAccessSettings::all(),
+            None,
+        )
+        .unwrap();
+
+        #[extrinsic_call]
+        cancel_account_access_invitation(
+            RawOrigin::Signed(caller),
+            authority_id,
+            account_id.clone(),
+        );
+
+        assert!(!AccessInvitations::<T>::contains_key(
+            authority_id,
+            account_id
+        ));
+    }
+
+    #[benchmark]
+    fn prune_expired_account_access() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let account_id: T::AccountId = account("member", 0, 1);
+        let access = AuthorityAccessSettings(AuthorityAccessSetting::EditEntity.into());
+
+        insert_authority::<T>(authority_id, None);
+        Pallet::<T>::write_access(authority_id, &caller, Some(AuthorityAccessSettings::all()))
+            .unwrap();
+        Pallet::<T>::add_access(
+            caller.clone(),
+            authority_id,
+            account_id.clone(),
+            access,
+            Some(frame_system::Pallet::<T>::block_number() + 1u32.into()),
+        )
+        .unwrap();
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + 1u32.into(),
+        );
+
+        #[extrinsic_call]
+        prune_expired_account_access(RawOrigin::Signed(caller), authority_id, account_id.clone());
+
+        assert!(!AuthoritiesAccess::<T>::contains_key(
+            authority_id,
+            account_id
+        ));
+    }
+
+    #[benchmark]
+    fn delegate_account_access() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let account_id: T::AccountId = account("member", 0, 1);
+        let access = AuthorityAccessSettings(AuthorityAccessSetting::EditEntity.into());
+
+        insert_authority::<T>(authority_id, None);
+        Pallet::<T>::write_access(authority_id, &caller, Some(AuthorityAccessSettings::all()))
+            .unwrap();
+
+        #[extrinsic_call]
+        delegate_account_access(
+            RawOrigin::Signed(caller.clone()),
+            authority_id,
+            account_id.clone(),
+            access,
+        );
+
+        assert_eq!(
+            AccessDelegators::<T>::get(authority_id, account_id),
+            Some(caller)
+        );
+    }
+
+    #[benchmark]
+    fn revoke_delegated_access() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let account_id: T::AccountId = account("member", 0, 1);
+        let access = AuthorityAccessSettings(AuthorityAccessSetting::EditEntity.into());
+
+        insert_authority::<T>(authority_id, None);
+        Pallet::<T>::write_access(authority_id, &caller, Some(AuthorityAccessSettings::all()))
+            .unwrap();
+        Pallet::<T>::add_delegation(caller.clone(), authority_id, account_id.clone(), access)
+            .unwrap();
+
+        #[extrinsic_call]
+        revoke_delegated_access(RawOrigin::Signed(caller), authority_id, account_id.clone());
+
+        assert!(!AuthoritiesAccess::<T>::contains_key(
+            authority_id,
+            account_id
+        ));
+    }
+
+    #[benchmark]
+    fn create_release(t: Linear<1, { T::MaxReleaseTracks::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let url: BoundedVec<u8, T::MaxLongStringLength> = vec![4, 5, 6].try_into().unwrap();
+
+        let collection_cfg: T::CollectionConfig = Default::default();
+        let collection_id = T::Nfts::create_collection(&caller, &caller, &collection_cfg).unwrap();
+
+        insert_authority::<T>(authority_id, Some(collection_id));
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+
+        let tracks: Vec<ReleaseTrackFor<T>> = (0..t)
+            .map(|i| ReleaseTrack {
+                entity_kind: IPEntityKind::Track,
+                url: url.clone(),
+                metadata_standard: MetadataStandard::M25,
+                metadata_features: MetadataFeatures::default(),
+                authors: None,
+                royalty_parts: None,
+                related_to: None,
+                nft_item_id: Some(T::BenchmarkHelper::item_id(i + 2)),
+            })
+            .collect();
+
+        #[extrinsic_call]
+        create_release(
+            RawOrigin::Signed(caller.clone()),
+            IPEntityKind::Release,
+            authority_id,
+            url,
+            MetadataStandard::M25,
+            MetadataFeatures::default(),
+            None,
+            None,
+            tracks.try_into().unwrap(),
+            Some(T::BenchmarkHelper::item_id(1)),
+            Some(caller.clone()),
+            None,
+        );
+
+        let release_id = T::EntityId::initial_value().unwrap();
+        assert_eq!(ReleaseTracks::<T>::get(release_id).len() as u32, t);
+    }
+
+    #[benchmark]
+    fn set_release_tracks(t: Linear<0, { T::MaxReleaseTracks::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let track_authority: T::AuthorityId = authority_id.increment().unwrap();
+        let release_id: T::EntityId = T::EntityId::initial_value().unwrap();
+
+        insert_authority::<T>(authority_id, None);
+        insert_authority::<T>(track_authority, None);
+        for authority in [authority_id, track_authority] {
+            AuthoritiesAccess::<T>::insert(
+                authority,
+                caller.clone(),
+                AuthorityAccessSettings::all(),
+            );
+        }
+        insert_entity::<T>(release_id, authority_id, None);
+        Entities::<T>::mutate(release_id, |entity| {
+            entity.as_mut().unwrap().entity_kind = IPEntityKind::Compilation
+        });
+
+        // The current tracks are all replaced by tracks of another authority.
+        let mut entity_id = release_id;
+        let mut old_tracks = Vec::new();
+        for track_number in 1..=T::MaxReleaseTracks::get() {
+            entity_id = entity_id.increment().unwrap();
+            insert_entity::<T>(entity_id, authority_id, None);
+            TrackReleases::<T>::insert(entity_id, release_id, ());
+            old_tracks.push(ReleaseMember {
+                entity_id,
+                track_number,
+            });
+        }
+        ReleaseTracks::<T>::insert(
+            release_id,
+            BoundedVec::<_, T::MaxReleaseTracks>::try_from(old_tracks).unwrap(),
+        );
+        let mut tracks = Vec::new();
+        for track_number in 1..=t {
+            entity_id = entity_id.increment().unwrap();
+            insert_entity::<T>(entity_id, track_authority, None);
+            tracks.push(ReleaseMember {
+                entity_id,
+                track_number,
+            });
+        }
+
+        #[extrinsic_call]
+        set_release_tracks(
+            RawOrigin::Signed(caller),
+            release_id,
+            tracks.try_into().unwrap(),
+        );
+
+        assert_eq!(ReleaseTracks::<T>::get(release_id).len() as u32, t);
+    }
+
+    #[benchmark]
+    fn withdraw_release_track() {
+        let caller: T::AccountId = whitelisted_caller();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let track_authority: T::AuthorityId = authority_id.increment().unwrap();
+        let release_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let track_id: T::EntityId = release_id.increment().unwrap();
+
+        insert_authority::<T>(authority_id, None);
+        insert_authority::<T>(track_authority, None);
+        AuthoritiesAccess::<T>::insert(
+            track_authority,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+        insert_entity::<T>(release_id, authority_id, None);
+        Entities::<T>::mutate(release_id, |entity| {
+            entity.as_mut().unwrap().entity_kind = IPEntityKind::Compilation
+        });
+        insert_entity::<T>(track_id, track_authority, None);
+        TrackReleases::<T>::insert(track_id, release_id, ());
+        ReleaseTracks::<T>::insert(
+            release_id,
+            BoundedVec::<_, T::MaxReleaseTracks>::try_from(vec![ReleaseMember {
+                entity_id: track_id,
+                track_number: 1,
+            }])
+            .unwrap(),
+        );
+
+        #[extrinsic_call]
+        withdraw_release_track(RawOrigin::Signed(caller), release_id, track_id);
+
+        assert!(!TrackReleases::<T>::contains_key(track_id, release_id));
+    }
+
+    #[benchmark]
+    fn detach_entity_nft() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let item_id: T::ItemId = T::BenchmarkHelper::item_id(1);
+        let collection_id = insert_entity_nft::<T>(entity_id, authority_id, item_id, &caller);
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+
+        #[extrinsic_call]
+        detach_entity_nft(RawOrigin::Signed(caller), entity_id);
+
+        assert_eq!(Entities::<T>::get(entity_id).unwrap().item_id, None);
+        assert!(!EntitiesByNft::<T>::contains_key(collection_id, item_id));
+    }
+
+    #[benchmark]
+    fn burn_entity_nft() {
+        let caller: T::AccountId = whitelisted_caller();
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let item_id: T::ItemId = T::BenchmarkHelper::item_id(1);
+        let collection_id = insert_entity_nft::<T>(entity_id, authority_id, item_id, &caller);
+
+        #[extrinsic_call]
+        burn_entity_nft(RawOrigin::Signed(caller), entity_id);
+
+        assert_eq!(Entities::<T>::get(entity_id).unwrap().item_id, None);
+        assert!(T::Nfts::owner(&collection_id, &item_id).is_none());
+    }
+
+    #[benchmark]
+    fn set_resale_royalty() {
+        let caller: T::AccountId = whitelisted_caller();
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+        insert_entity::<T>(entity_id, authority_id, None);
+
+        #[extrinsic_call]
+        set_resale_royalty(
+            RawOrigin::Signed(caller),
+            authority_id,
+            Some(entity_id),
+            Some(T::MaxResaleRoyalty::get()),
+        );
+
+        assert_eq!(
+            EntityResaleRoyalties::<T>::get(entity_id),
+            Some(T::MaxResaleRoyalty::get())
+        );
+    }
+
+    #[benchmark]
+    fn list_entity_nft() {
+        let caller: T::AccountId = whitelisted_caller();
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let item_id: T::ItemId = T::BenchmarkHelper::item_id(1);
+        insert_entity_nft::<T>(entity_id, authority_id, item_id, &caller);
+        let price = T::Currency::minimum_balance() * 1000u32.into();
+
+        #[extrinsic_call]
+        list_entity_nft(
+            RawOrigin::Signed(caller),
+            entity_id,
+            price,
+            PaymentCurrency::Native,
+            None,
+        );
+
+        assert!(NftListings::<T>::contains_key(entity_id));
+    }
+
+    #[benchmark]
+    fn delist_entity_nft() {
+        let caller: T::AccountId = whitelisted_caller();
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let item_id: T::ItemId = T::BenchmarkHelper::item_id(1);
+        insert_entity_nft::<T>(entity_id, authority_id, item_id, &caller);
+        NftListings::<T>::insert(
+            entity_id,
+            NftListing {
+                seller: caller.clone(),
+                price: T::Currency::minimum_balance(),
+                currency: PaymentCurrency::Native,
+                buyer: None,
+            },
+        );
+
+        #[extrinsic_call]
+        delist_entity_nft(RawOrigin::Signed(caller), entity_id);
+
+        assert!(!NftListings::<T>::contains_key(entity_id));
+    }
+
+    #[benchmark]
+    fn buy_entity_nft(p: Linear<1, { T::MaxRoyaltyParts::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let seller: T::AccountId = account("seller", 0, 1);
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let item_id: T::ItemId = T::BenchmarkHelper::item_id(1);
+        let collection_id = insert_entity_nft::<T>(entity_id, authority_id, item_id, &seller);
+        Entities::<T>::mutate(entity_id, |entity| {
+            entity.as_mut().unwrap().royalty_parts = Some(royalty_parts::<T>(p));
+        });
+        AuthorityResaleRoyalties::<T>::insert(authority_id, T::MaxResaleRoyalty::get());
+
+        let price = T::Currency::minimum_balance() * 1000u32.into() * p.into();
+        NftListings::<T>::insert(
+            entity_id,
+            NftListing {
+                seller: seller.clone(),
+                price,
+                currency: PaymentCurrency::Native,
+                buyer: None,
+            },
+        );
+
+        #[extrinsic_call]
+        buy_entity_nft(
+            RawOrigin::Signed(caller.clone()),
+            entity_id,
+            price,
+            PaymentCurrency::Native,
+        );
+
+        assert_eq!(T::Nfts::owner(&collection_id, &item_id), Some(caller));
+        assert!(!T::Currency::balance(&seller).is_zero());
+    }
+
+    #[benchmark]
+    fn issue_royalty_shares() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+        insert_entity::<T>(entity_id, authority_id, None);
+
+        let min_balance: AssetBalanceOf<T> = 1000u32.into();
+        let supply = min_balance * T::MaxRoyaltyShareHolders::get().into();
+
+        #[extrinsic_call]
+        issue_royalty_shares(
+            RawOrigin::Signed(caller.clone()),
+            entity_id,
+            supply,
+            min_balance,
+        );
+
+        let asset_id = T::RoyaltySharesAssetIdStart::get();
+        assert_eq!(
+            EntityRoyaltyShares::<T>::get(entity_id).unwrap().asset_id,
+            asset_id
+        );
+        assert_eq!(T::Assets::balance(asset_id, &caller), supply);
+        assert!(RoyaltySharesDeposits::<T>::contains_key(entity_id));
+    }
+
+    #[benchmark]
+    fn register_royalty_share_holder(h: Linear<1, { T::MaxRoyaltyShareHolders::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        insert_entity::<T>(entity_id, authority_id, None);
+        let asset_id = insert_royalty_shares::<T>(entity_id, h - 1);
+        let min_balance = T::Assets::minimum_balance(asset_id.clone());
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        T::Assets::mint_into(asset_id, &caller, min_balance).unwrap();
+
+        #[extrinsic_call]
+        register_royalty_share_holder(RawOrigin::Signed(caller.clone()), entity_id);
+
+        let shares = EntityRoyaltyShares::<T>::get(entity_id).unwrap();
+        assert_eq!(shares.holders.len() as u32, h);
+        assert!(shares.holders.contains(&caller));
+    }
+
+    #[benchmark]
+    fn gift_entity_nft() {
+        let caller: T::AccountId = whitelisted_caller();
+        let to: T::AccountId = account("to", 0, 1);
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        let item_id: T::ItemId = T::BenchmarkHelper::item_id(1);
+        let collection_id = insert_entity_nft::<T>(entity_id, authority_id, item_id, &caller);
+        AuthoritiesAccess::<T>::insert(authority_id, to.clone(), AuthorityAccessSettings::all());
+        NftListings::<T>::insert(
+            entity_id,
+            NftListing {
+                seller: caller.clone(),
+                price: T::Currency::minimum_balance(),
+                currency: PaymentCurrency::Native,
+                buyer: None,
+            },
+        );
+
+        #[extrinsic_call]
+        gift_entity_nft(RawOrigin::Signed(caller), entity_id, to.clone());
+
+        assert_eq!(T::Nfts::owner(&collection_id, &item_id), Some(to));
+        assert!(!NftListings::<T>::contains_key(entity_id));
+    }
+
+    #[benchmark]
+    fn prune_licenses() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 100u8.into());
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+
+        let offer = license_offer::<T>();
+        let licenses = (0..T::MaxHolderLicenses::get())
+            .map(|_| License {
+                kind: offer.kind,
+                price: offer.price,
+                currency: offer.currency.clone(),
+                territory: offer.territory.clone(),
+                purchased_at: Zero::zero(),
+                expires_at: Some(Zero::zero()),
+            })
+            .collect::<Vec<_>>();
+        let deposit = T::LicenseDeposit::get().saturating_mul(T::MaxHolderLicenses::get().into());
+        let deposit = Pallet::<T>::update_deposit(None, &caller, deposit).unwrap();
+        Licenses::<T>::insert(&caller, entity_id, BoundedVec::try_from(licenses).unwrap());
+        if let Some(deposit) = deposit {
+            LicenseDeposits::<T>::insert(&caller, entity_id, deposit);
+        }
+
+        #[extrinsic_call]
+        prune_licenses(RawOrigin::Signed(caller.clone()), entity_id);
+
+        assert!(Licenses::<T>::get(&caller, entity_id).is_empty());
+        assert!(!LicenseDeposits::<T>::contains_key(&caller, entity_id));
+    }
+
+    #[benchmark]
+    fn reinstate_entity() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ArbiterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+
+        insert_entity::<T>(entity_id, authority_id, None);
+        TakenDownEntities::<T>::insert(entity_id, ());
+
+        #[extrinsic_call]
+        reinstate_entity(origin as T::RuntimeOrigin, entity_id);
+
+        assert!(!Pallet::<T>::is_taken_down(entity_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn migrate_v2_authority() {
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        StorageVersion::new(1).put::<Pallet<T>>();
+        migrations::v2::v1::Authorities::<T, ()>::insert(
+            authority_id,
+            migrations::v2::v1::AuthorityDetails {
+                authority_kind: AuthorityKind::Label,
+                name: vec![0; T::MaxShortStringLength::get() as usize]
+                    .try_into()
+                    .unwrap(),
+                collection_id: None,
+            },
+        );
+
+        #[block]
+        {
+            migrations::v2::MigrateV1ToV2::<T>::migrate_next(
+                migrations::v2::MigrationCursor::Authorities(None),
+            );
+        }
+
+        assert!(Authorities::<T>::contains_key(authority_id));
+    }
+
+    #[benchmark]
+    fn migrate_v2_author() {
+        let author_id: T::AuthorId = T::AuthorId::initial_value().unwrap();
+        StorageVersion::new(1).put::<Pallet<T>>();
+        migrations::v2::v1::Authors::<T, ()>::insert(
+            author_id,
+            migrations::v2::v1::AuthorDetails {
+                nickname: vec![0; T::MaxShortStringLength::get() as usize]
+                    .try_into()
+                    .unwrap(),
+                real_name: Some(
+                    vec![0; T::MaxLongStringLength::get() as usize]
+                        .try_into()
+                        .unwrap(),
+                ),
+                owner: whitelisted_caller(),
+            },
+        );
+
+        #[block]
+        {
+            migrations::v2::MigrateV1ToV2::<T>::migrate_next(
+                migrations::v2::MigrationCursor::Authors(None),
+            );
+        }
+
+        assert!(Authors::<T>::contains_key(author_id));
+    }
+
+    #[benchmark]
+    fn migrate_v2_entity(
+        a: Linear<0, { T::MaxEntityAuthors::get() }>,
+        r: Linear<0, { T::MaxRelatedEntities::get() }>,
+    ) {
+        let caller: T::AccountId = whitelisted_caller();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        StorageVersion::new(1).put::<Pallet<T>>();
+
+        let mut author_id: T::AuthorId = T::AuthorId::initial_value().unwrap();
+        let mut authors = Vec::new();
+        for _ in 0..a {
+            authors.push(author_id);
+            author_id = author_id.increment().unwrap();
+        }
+
+        let entity_id: T::EntityId = T::EntityId::initial_value().unwrap();
+        let mut source_id = entity_id;
+        let mut related_to = Vec::new();
+        for _ in 0..r {
+            source_id = source_id.increment().unwrap();
+            related_to.push(source_id);
+        }
+
+        let item_id: T::ItemId = T::BenchmarkHelper::item_id(1);
+        let collection_cfg: T::CollectionConfig = Default::default();
+        let collection_id = T::Nfts::create_collection(&caller, &caller, &collection_cfg).unwrap();
+        T::Nfts::mint_into(
+            &collection_id,
+            &item_id,
+            &caller,
+            &ItemConfig::default(),
+            true,
+        )
+        .unwrap();
+
+        migrations::v2::v1::Entities::<T, ()>::insert(
+            entity_id,
+            migrations::v2::v1::EntityDetails {
+                entity_kind: IPEntityKind::Track,
+                owner: authority_id,
+                authors: Some(authors.try_into().unwrap()),
+                royalty_parts: Some(
+                    (0..T::MaxRoyaltyParts::get())
+                        .map(|i| migrations::v2::v1::Wallet {
+                            address_id: account("recipient", i, 1),
+                            weight: 1,
+                        })
+                        .collect::<Vec<_>>()
+                        .try_into()
+                        .unwrap(),
+                ),
+                related_to: Some(related_to.try_into().unwrap()),
+                metadata: Metadata {
+                    url: vec![4, 5, 6].try_into().unwrap(),
+                    standard: MetadataStandard::M25,
+                    features: Default::default(),
+                },
+                collection_id: Some(collection_id),
+                item_id: Some(item_id),
+            },
+        );
+
+        #[block]
+        {
+            migrations::v2::MigrateV1ToV2::<T>::migrate_next(
+                migrations::v2::MigrationCursor::Entities(None),
+            );
+        }
+
+        assert_eq!(
+            Entities::<T>::get(entity_id)
+                .unwrap()
+                .related_to
+                .map(|related_to| related_to.len()),
+            Some(r as usize)
+        );
+        assert!(Pallet::<T>::is_entity_nft_locked(&collection_id, &item_id));
+    }
+
+    #[benchmark]
+    fn migrate_v2_access() {
+        let caller: T::AccountId = whitelisted_caller();
+        let authority_id: T::AuthorityId = T::AuthorityId::initial_value().unwrap();
+        StorageVersion::new(1).put::<Pallet<T>>();
+        AuthoritiesAccess::<T>::insert(
+            authority_id,
+            caller.clone(),
+            AuthorityAccessSettings::all(),
+        );
+
+        #[block]
+        {
+            migrations::v2::MigrateV1ToV2::<T>::migrate_next(
+                migrations::v2::MigrationCursor::AuthoritiesAccess(None),
+            );
+        }
+
+        assert!(AccessByAccount::<T>::contains_key(caller, authority_id));
+        assert_eq!(EditAccessHolders::<T>::get(authority_id), 1);
+    }
+
+    impl_benchmark_test_suite!(IpOnchain, mock::new_test_ext(), mock::Test);
+}
+
+/// Gives the `EditAccess` right to the caller and `count - 1` other holders.
+fn insert_edit_access_holders<T: Config>(
+    authority_id: T::AuthorityId,
+    caller: &T::AccountId,
+    count: u32,
+) {
+    Pallet::<T>::write_access(authority_id, caller, Some(AuthorityAccessSettings::all())).unwrap();
+    for i in 1..count {
+        let holder: T::AccountId = account("holder", i, 1);
+        Pallet::<T>::write_access(authority_id, &holder, Some(AuthorityAccessSettings::all()))
+            .unwrap();
+    }
+}
+
+fn insert_entity<T: Config>(
+    entity_id: T::EntityId,
+    owner: T::AuthorityId,
+    royalty_parts: Option<BoundedVec<Wallet<T::AccountId>, T::MaxRoyaltyParts>>,
+) {
+    Entities::<T>::insert(
+        entity_id,
+        EntityDetails {
+            entity_kind: IPEntityKind::Track,
+            owner,
+            authors: None,
+            royalty_parts,
+            related_to: None,
+            metadata: Metadata {
+                url: vec![4, 5, 6].try_into().unwrap(),
+                standard: MetadataStandard::M25,
+                features: Default::default(),
+            },
+            collection_id: None,
+            item_id: None,
+            content: None,
+            identifiers: Default::default(),
+            created_at: Default::default(),
+            updated_at: Default::default(),
+            revision: 0,
+        },
+    );
+}
+
+/// Inserts an entity with an NFT held by `holder`, the entity is mirrored into the NFT.
+fn insert_entity_nft<T: Config>(
+    entity_id: T::EntityId,
+    owner: T::AuthorityId,
+    item_id: T::ItemId,
+    holder: &T::AccountId,
+) -> T::CollectionId {
+    let collection_cfg: T::CollectionConfig = Default::default();
+    let collection_id = T::Nfts::create_collection(holder, holder, &collection_cfg).unwrap();
+    T::Nfts::mint_into(
+        &collection_id,
+        &item_id,
+        holder,
+        &ItemConfig::default(),
+        true,
+    )
+    .unwrap();
+    Pallet::<T>::lock_entity_nft(&collection_id, &item_id).unwrap();
+
+    insert_authority::<T>(owner, Some(collection_id));
+    insert_entity::<T>(entity_id, owner, None);
+    Entities::<T>::mutate(entity_id, |entity| {
+        let entity = entity.as_mut().unwrap();
+        entity.collection_id = Some(collection_id);
+        entity.item_id = Some(item_id);
+        Pallet::<T>::index_entity(entity_id, None, Some(entity));
+        Pallet::<T>::mirror_entity_nft(entity_id, entity).unwrap();
+    });
+
+    collection_id
+}
+
+/// Issues royalty shares for an entity and registers `holders` holders of the minimum balance,
+/// the rest of the supply is held by the issuer.
+fn insert_royalty_shares<T: Config>(entity_id: T::EntityId, holders: u32) -> AssetIdOf<T> {
+    let issuer: T::AccountId = account("issuer", 0, 1);
+    let asset_id = T::BenchmarkHelper::asset_id(1);
+    let min_balance: AssetBalanceOf<T> = 1000u32.into();
+    T::Assets::create(
+        asset_id.clone(),
+        Pallet::<T>::pallet_account(),
+        false,
+        min_balance,
+    )
+    .unwrap();
+    T::Currency::set_balance(&issuer, BalanceOf::<T>::max_value() / 100u8.into());
+    T::Assets::mint_into(
+        asset_id.clone(),
+        &issuer,
+        min_balance * T::MaxRoyaltyShareHolders::get().into(),
+    )
+    .unwrap();
+
+    let holders = (0..holders)
+        .map(|i| {
+            let holder: T::AccountId = account("holder", i, 1);
+            T::Currency::set_balance(&holder, T::Currency::minimum_balance() * 1000u32.into());
+            T::Assets::mint_into(asset_id.clone(), &holder, min_balance).unwrap();
+            holder
+        })
+        .collect::<Vec<_>>();
+
+    EntityRoyaltyShares::<T>::insert(
+        entity_id,
+        RoyaltyShares {
+            asset_id: asset_id.clone(),
+            issuer,
+            holders: holders.try_into().unwrap(),
+        },
+    );
+
+    asset_id
+}
+
+fn insert_authority<T: Config>(
+    authority_id: T::AuthorityId,
+    collection_id: Option<T::CollectionId>,
+) {
+    Authorities::<T>::insert(
+        authority_id,
+        AuthorityDetails {
+            authority_kind: AuthorityKind::Label,
+            name: vec![0].try_into().unwrap(),
+            collection_id,
+            created_at: Default::default(),
+            updated_at: Default::default(),
+        },
+    );
+}
+
+fn content_commitment<T: Config>(seed: u8) -> ContentCommitmentFor<T> {
+    // A sha2-256 multihash.
+    let mut content_hash = vec![0x12, 0x20];
+    content_hash.extend([seed; 32]);
+
+    ContentCommitment {
+        content_hash: content_hash.try_into().unwrap(),
+        cid: vec![seed; 32].try_into().unwrap(),
+        fingerprint: Some(vec![seed; 32].try_into().unwrap()),
+    }
+}
+
+fn entity_identifiers(seed: u8) -> EntityIdentifiers {
+    let mut isrc = *b"USS1Z9900000";
+    isrc[11] = seed;
+
+    EntityIdentifiers {
+        isrc: Some(Isrc(isrc)),
+        iswc: Some(Iswc(*b"T0345246801")),
+        upc: Some(Upc(*b"0036000291452")),
+    }
+}
+
+fn royalty_parts<T: Config>(p: u32) -> BoundedVec<Wallet<T::AccountId>, T::MaxRoyaltyParts> {
+    (0..p)
+        .map(|i| Wallet {
+            address_id: account("recipient", i, 1),
+            share: Perbill::from_rational(1, p),
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+fn license_offer<T: Config>() -> LicenseOfferFor<T> {
+    LicenseOffer {
+        kind: LicenseKind::Streaming,
+        price: T::Currency::minimum_balance(),
+        currency: PaymentCurrency::Native,
+        duration: None,
+        territory: Territory::Worldwide,
+    }
 }
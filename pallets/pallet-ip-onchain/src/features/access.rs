@@ -7,6 +7,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     ///
     /// # It ensures
     /// - Ensures that the access settings are added only if they do not already exist.
+    /// - The access is indexed in the `AccessByAccount` and `EditAccessHolders` storages.
+    /// - The deposit of the access is held from the account.
     ///
     /// # Parameters
     /// - `authority_id`: The unique identifier of the authority.
@@ -14,6 +16,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     ///
     /// # Errors
     /// - Returns `Error::<T, I>::AuthoritiesAccessExist` if access settings already exist for the given authority and account.
+    /// - Returns an error if the account can not afford the deposit.
     ///
     /// # Events
     /// - Emits `Event::AuthoritiesAccessAdded` with the `authority_id` and `account_id`.
@@ -26,11 +29,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             Error::<T, I>::AuthoritiesAccessExist
         );
 
-        AuthoritiesAccess::<T, I>::insert(
+        Self::write_access(
             authority_id,
-            account_id.clone(),
-            AuthorityAccessSettings::all(),
-        );
+            &account_id,
+            Some(AuthorityAccessSettings::all()),
+        )?;
+        let deposit = Self::update_deposit(None, &account_id, T::AccessDeposit::get())?;
+        AccessDeposits::<T, I>::set(authority_id, &account_id, deposit);
 
         Self::deposit_event(Event::AuthoritiesAccessAdded {
             authority_id,
@@ -44,17 +49,24 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     ///
     /// # It ensures
     /// - Validates that the caller has the required access rights to edit access settings.
-    /// - Ensures that the access settings are added only if they do not already exist.
+    /// - Ensures that the access settings are added only if they do not already exist, an expired access is pruned first.
+    /// - The access is indexed in the `AccessByAccount` and `EditAccessHolders` storages.
+    /// - The deposit of the access is held from the caller.
     ///
     /// # Parameters
     /// - `origin`: The account ID of the caller attempting to add access.
     /// - `authority_id`: The unique identifier of the authority.
     /// - `account_id`: The unique identifier of the account to which access is being granted.
     /// - `access`: The access settings to be added.
+    /// - `expires_at`: The block from which the access is treated as absent, `None` for a permanent access.
     ///
     /// # Errors
     /// - Returns `Error::<T, I>::AuthoritiesAccessExist` if access settings already exist for the given authority and account.
+    /// - Returns `Error::<T, I>::AccessInvitationPending` if the account is invited to the authority.
+    /// - Returns an expiry error if the `expires_at` block is not valid, see `write_expiry`.
     /// - Returns `Error::<T, I>::NotAuthorized` if the caller does not have the required access rights.
+    /// - Returns `Error::<T, I>::ProposalRequired` if the authority governs access changes by its policy.
+    /// - Returns an error if the caller can not afford the deposit.
     ///
     /// # Events
     /// - Emits `Event::AuthoritiesAccessAdded` with the `authority_id` and `account_id`.
@@ -63,26 +75,22 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         authority_id: T::AuthorityId,
         account_id: T::AccountId,
         access: AuthorityAccessSettings,
+        expires_at: Option<BlockNumberFor<T>>,
     ) -> DispatchResult {
         Self::ensure_access_right(
             &origin,
             &authority_id,
             AuthorityAccessSetting::EditAccess.into(),
         )?;
+        Self::ensure_not_governed(&authority_id, GovernedAction::AccessChange)?;
 
+        Self::prune_expired_access(authority_id, &account_id)?;
         ensure!(
             !AuthoritiesAccess::<T, I>::contains_key(authority_id, &account_id),
             Error::<T, I>::AuthoritiesAccessExist
         );
 
-        AuthoritiesAccess::<T, I>::insert(authority_id, account_id.clone(), access);
-
-        Self::deposit_event(Event::AuthoritiesAccessAdded {
-            authority_id,
-            account_id,
-        });
-
-        Ok(())
+        Self::change_access(&origin, authority_id, account_id, Some(access), expires_at)
     }
 
     /// Updates access settings for an authority and account.
@@ -90,16 +98,21 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// # It ensures
     /// - Validates that the caller has the required access rights to edit access settings.
     /// - Ensures that the access settings exist before attempting to update them.
+    /// - At least one account keeps the `EditAccess` right for the authority.
     ///
     /// # Parameters
     /// - `origin`: The account ID of the caller attempting to update access.
     /// - `authority_id`: The unique identifier of the authority.
     /// - `account_id`: The unique identifier of the account whose access settings are being updated.
     /// - `new_access`: The new access settings to be applied.
+    /// - `expires_at`: The new expiry block of the access, `None` to make the access permanent.
     ///
     /// # Errors
     /// - Returns `Error::<T, I>::AuthoritiesAccessNotExist` if access settings do not exist for the given authority and account.
+    /// - Returns `Error::<T, I>::LastEditAccessHolder` if the update takes the `EditAccess` right from its last holder.
+    /// - Returns an expiry error if the `expires_at` block is not valid, see `write_expiry`.
     /// - Returns `Error::<T, I>::NotAuthorized` if the caller does not have the required access rights.
+    /// - Returns `Error::<T, I>::ProposalRequired` if the authority governs access changes by its policy.
     ///
     /// # Events
     /// - Emits `Event::AuthoritiesAccessChanged` with the `authority_id` and `account_id`.
@@ -108,37 +121,328 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         authority_id: T::AuthorityId,
         account_id: T::AccountId,
         new_access: AuthorityAccessSettings,
+        expires_at: Option<BlockNumberFor<T>>,
     ) -> DispatchResult {
         Self::ensure_access_right(
             &origin,
             &authority_id,
             AuthorityAccessSetting::EditAccess.into(),
         )?;
+        Self::ensure_not_governed(&authority_id, GovernedAction::AccessChange)?;
+
+        ensure!(
+            AuthoritiesAccess::<T, I>::contains_key(authority_id, &account_id),
+            Error::<T, I>::AuthoritiesAccessNotExist
+        );
 
-        AuthoritiesAccess::<T, I>::try_mutate(
+        Self::change_access(
+            &origin,
             authority_id,
-            account_id.clone(),
-            |maybe_author| -> DispatchResult {
+            account_id,
+            Some(new_access),
+            expires_at,
+        )
+    }
+
+    /// Removes the access settings of an account for an authority.
+    ///
+    /// # It ensures
+    /// - Validates that the caller has the required access rights to edit access settings.
+    /// - Ensures that the access settings exist before attempting to remove them.
+    /// - The deposit of the access is released to its depositor.
+    /// - At least one account keeps the `EditAccess` right for the authority.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller attempting to remove access.
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `account_id`: The unique identifier of the account whose access settings are being removed.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthoritiesAccessNotExist` if access settings do not exist for the given authority and account.
+    /// - Returns `Error::<T, I>::LastEditAccessHolder` if the account is the last one with the `EditAccess` right.
+    /// - Returns `Error::<T, I>::NotAuthorized` if the caller does not have the required access rights.
+    /// - Returns `Error::<T, I>::ProposalRequired` if the authority governs access changes by its policy.
+    ///
+    /// # Events
+    /// - Emits `Event::AuthoritiesAccessRemoved` with the `authority_id` and `account_id`.
+    pub(crate) fn remove_access(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+        account_id: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_access_right(
+            &origin,
+            &authority_id,
+            AuthorityAccessSetting::EditAccess.into(),
+        )?;
+        Self::ensure_not_governed(&authority_id, GovernedAction::AccessChange)?;
+
+        ensure!(
+            AuthoritiesAccess::<T, I>::contains_key(authority_id, &account_id),
+            Error::<T, I>::AuthoritiesAccessNotExist
+        );
+
+        Self::change_access(&origin, authority_id, account_id, None, None)
+    }
+
+    /// Adds, changes or removes the access settings of an account for an authority.
+    ///
+    /// # It ensures
+    /// - A new access is written with its deposit held from the `depositor`.
+    /// - A removed access releases its deposit to its depositor.
+    /// - The expiry of a written access is replaced by `expires_at`.
+    ///
+    /// # Parameters
+    /// - `depositor`: The account ID holding the deposit of a new access.
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `account_id`: The unique identifier of the account.
+    /// - `access`: The new access settings, `None` to remove the access.
+    /// - `expires_at`: The block from which the written access is treated as absent, `None` for a permanent access.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthoritiesAccessNotExist` if a missing access is removed.
+    /// - Returns `Error::<T, I>::AccessInvitationPending` if the account is invited to the authority.
+    /// - Returns `Error::<T, I>::AccessDelegated` if a delegated access is changed, it can only be removed.
+    /// - Returns an expiry error if the `expires_at` block is not valid, see `write_expiry`.
+    /// - Returns `Error::<T, I>::LastEditAccessHolder` or `Error::<T, I>::PolicyThresholdUnreachable`
+    ///   if the change takes the `EditAccess` right from too many holders, see `write_access`.
+    /// - Returns an error if the depositor can not afford the deposit.
+    ///
+    /// # Events
+    /// - Emits `Event::AuthoritiesAccessAdded`, `Event::AuthoritiesAccessChanged` or `Event::AuthoritiesAccessRemoved`
+    ///   with the `authority_id` and `account_id`.
+    pub(crate) fn change_access(
+        depositor: &T::AccountId,
+        authority_id: T::AuthorityId,
+        account_id: T::AccountId,
+        access: Option<AuthorityAccessSettings>,
+        expires_at: Option<BlockNumberFor<T>>,
+    ) -> DispatchResult {
+        let exists = AuthoritiesAccess::<T, I>::contains_key(authority_id, &account_id);
+
+        match (exists, access) {
+            (false, Some(access)) => {
+                ensure!(
+                    !AccessInvitations::<T, I>::contains_key(authority_id, &account_id),
+                    Error::<T, I>::AccessInvitationPending
+                );
+                Self::write_access(authority_id, &account_id, Some(access))?;
+                Self::write_expiry(authority_id, &account_id, access, expires_at)?;
+                let deposit = Self::update_deposit(None, depositor, T::AccessDeposit::get())?;
+                AccessDeposits::<T, I>::set(authority_id, &account_id, deposit);
+
+                Self::deposit_event(Event::AuthoritiesAccessAdded {
+                    authority_id,
+                    account_id,
+                });
+            }
+            (true, Some(access)) => {
                 ensure!(
-                    maybe_author.is_some(),
-                    Error::<T, I>::AuthoritiesAccessNotExist
+                    !AccessDelegators::<T, I>::contains_key(authority_id, &account_id),
+                    Error::<T, I>::AccessDelegated
                 );
-                *maybe_author = Some(new_access);
+                Self::write_access(authority_id, &account_id, Some(access))?;
+                Self::write_expiry(authority_id, &account_id, access, expires_at)?;
 
                 Self::deposit_event(Event::AuthoritiesAccessChanged {
                     authority_id,
                     account_id,
                 });
+            }
+            (true, None) => {
+                Self::write_access(authority_id, &account_id, None)?;
+                Self::release_deposit(AccessDeposits::<T, I>::take(authority_id, &account_id))?;
+
+                Self::deposit_event(Event::AuthoritiesAccessRemoved {
+                    authority_id,
+                    account_id,
+                });
+            }
+            (false, None) => return Err(Error::<T, I>::AuthoritiesAccessNotExist.into()),
+        }
+
+        Ok(())
+    }
+
+    /// Writes the access settings of an account for an authority.
+    ///
+    /// # It ensures
+    /// - The `AccessByAccount` and `EditAccessHolders` storages are kept in sync with the `AuthoritiesAccess` storage.
+    /// - The last account with the `EditAccess` right can not lose it, so the authority is never orphaned.
+    /// - The delegations of the account that are no longer a strict subset of its access are revoked,
+    ///   a removed delegated access leaves the `AccessDelegators` and `AccessDelegates` storages.
+    /// - The accounts with the `EditAccess` right can still reach the threshold of the authority policy.
+    ///
+    /// # Parameters
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `account_id`: The unique identifier of the account.
+    /// - `new_access`: The new access settings, `None` to remove the access.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::LastEditAccessHolder` if the account is the last one losing the `EditAccess` right.
+    /// - Returns `Error::<T, I>::PolicyThresholdUnreachable` if fewer holders than the policy threshold are left.
+    pub(crate) fn write_access(
+        authority_id: T::AuthorityId,
+        account_id: &T::AccountId,
+        new_access: Option<AuthorityAccessSettings>,
+    ) -> DispatchResult {
+        let can_edit_access = |access: Option<AuthorityAccessSettings>| {
+            access
+                .is_some_and(|access| access.has_access(AuthorityAccessSetting::EditAccess.into()))
+        };
 
+        let had_edit_access =
+            can_edit_access(AuthoritiesAccess::<T, I>::get(authority_id, account_id));
+        let has_edit_access = can_edit_access(new_access);
+
+        if had_edit_access && !has_edit_access {
+            EditAccessHolders::<T, I>::try_mutate(authority_id, |holders| -> DispatchResult {
+                ensure!(*holders > 1, Error::<T, I>::LastEditAccessHolder);
+                *holders -= 1;
+                if let Some(policy) = AuthorityPolicies::<T, I>::get(authority_id) {
+                    ensure!(
+                        *holders >= policy.threshold,
+                        Error::<T, I>::PolicyThresholdUnreachable
+                    );
+                }
                 Ok(())
-            },
-        )
+            })?;
+        } else if !had_edit_access && has_edit_access {
+            EditAccessHolders::<T, I>::mutate(authority_id, |holders| {
+                *holders = holders.saturating_add(1)
+            });
+        }
+
+        match new_access {
+            Some(access) => {
+                AuthoritiesAccess::<T, I>::insert(authority_id, account_id, access);
+                AccessByAccount::<T, I>::insert(account_id, authority_id, ());
+            }
+            None => {
+                AuthoritiesAccess::<T, I>::remove(authority_id, account_id);
+                AccessByAccount::<T, I>::remove(account_id, authority_id);
+                AccessExpiries::<T, I>::remove(authority_id, account_id);
+                if let Some(delegator) = AccessDelegators::<T, I>::take(authority_id, account_id) {
+                    AccessDelegates::<T, I>::mutate_exists(authority_id, &delegator, |delegates| {
+                        if let Some(list) = delegates {
+                            list.retain(|delegate| delegate != account_id);
+                            if list.is_empty() {
+                                *delegates = None;
+                            }
+                        }
+                    });
+                }
+            }
+        }
+
+        Self::revoke_lapsed_delegations(authority_id, account_id, new_access)
+    }
+
+    /// Writes the expiry of an access.
+    ///
+    /// # It ensures
+    /// - The `EditAccess` right never expires, so the authority and its policy keep their holders.
+    /// - The expiry block is in the future.
+    ///
+    /// # Parameters
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `account_id`: The unique identifier of the account.
+    /// - `access`: The access settings the expiry applies to.
+    /// - `expires_at`: The block from which the access is treated as absent, `None` for a permanent access.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EditAccessCannotExpire` if an access with the `EditAccess` right gets an expiry.
+    /// - Returns `Error::<T, I>::AccessExpiryInvalid` if the expiry block is not in the future.
+    pub(crate) fn write_expiry(
+        authority_id: T::AuthorityId,
+        account_id: &T::AccountId,
+        access: AuthorityAccessSettings,
+        expires_at: Option<BlockNumberFor<T>>,
+    ) -> DispatchResult {
+        match expires_at {
+            Some(expires_at) => {
+                Self::ensure_valid_expiry(access, expires_at)?;
+                AccessExpiries::<T, I>::insert(authority_id, account_id, expires_at);
+            }
+            None => AccessExpiries::<T, I>::remove(authority_id, account_id),
+        }
+        Ok(())
+    }
+
+    /// Validates the expiry block of an access, see `write_expiry`.
+    pub(crate) fn ensure_valid_expiry(
+        access: AuthorityAccessSettings,
+        expires_at: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        ensure!(
+            !access.has_access(AuthorityAccessSetting::EditAccess.into()),
+            Error::<T, I>::EditAccessCannotExpire
+        );
+        ensure!(
+            expires_at > frame_system::Pallet::<T>::block_number(),
+            Error::<T, I>::AccessExpiryInvalid
+        );
+        Ok(())
+    }
+
+    /// Removes the access of an account once it expired.
+    ///
+    /// # It ensures
+    /// - Only an access whose expiry block is reached is removed, its deposit is released to its depositor.
+    ///
+    /// # Parameters
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `account_id`: The unique identifier of the account.
+    ///
+    /// # Returns
+    /// - `true` if the access was expired and removed.
+    ///
+    /// # Events
+    /// - Emits `Event::AuthoritiesAccessExpired` with the `authority_id` and `account_id`.
+    pub(crate) fn prune_expired_access(
+        authority_id: T::AuthorityId,
+        account_id: &T::AccountId,
+    ) -> Result<bool, DispatchError> {
+        let expired = AccessExpiries::<T, I>::get(authority_id, account_id)
+            .is_some_and(|expires_at| expires_at <= frame_system::Pallet::<T>::block_number());
+        if !expired {
+            return Ok(false);
+        }
+
+        Self::write_access(authority_id, account_id, None)?;
+        Self::release_deposit(AccessDeposits::<T, I>::take(authority_id, account_id))?;
+
+        Self::deposit_event(Event::AuthoritiesAccessExpired {
+            authority_id,
+            account_id: account_id.clone(),
+        });
+
+        Ok(true)
+    }
+
+    /// Retrieves the access settings of an account for an authority, an expired access,
+    /// or an access delegated by an account whose access expired, is treated as absent.
+    pub fn get_access(
+        authority_id: T::AuthorityId,
+        account_id: &T::AccountId,
+    ) -> Option<AuthorityAccessSettings> {
+        let expired = AccessExpiries::<T, I>::get(authority_id, account_id)
+            .is_some_and(|expires_at| expires_at <= frame_system::Pallet::<T>::block_number());
+        if expired {
+            return None;
+        }
+        // A delegated access lapses with the access of its delegator.
+        if let Some(delegator) = AccessDelegators::<T, I>::get(authority_id, account_id) {
+            Self::get_access(authority_id, &delegator)?;
+        }
+        AuthoritiesAccess::<T, I>::get(authority_id, account_id)
     }
 
     /// Validates that an account has the required access rights for an authority.
     ///
     /// # It ensures
     /// - Ensures that the account has the specified access rights for the given authority.
+    /// - An expired access is treated as absent.
     ///
     /// # Parameters
     /// - `who`: The account ID of the entity whose access rights are being validated.
@@ -158,7 +462,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         access_flags: BitFlags<AuthorityAccessSetting, u64>,
     ) -> DispatchResult {
         ensure!(
-            AuthoritiesAccess::<T, I>::get(authority_id, who)
+            Self::get_access(*authority_id, who)
                 .ok_or(Error::<T, I>::AuthoritiesAccessNotFound)?
                 .has_access(access_flags),
             Error::<T, I>::NotAuthorized
@@ -6,6 +6,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// # It ensures
     /// - Ensures that the `NextAuthorId` is incremented and used as the unique identifier for the new author.
     /// - Validates that the author ID does not already exist in the storage.
+    /// - The author is indexed by its owner in the `AuthorsByOwner` storage.
+    /// - An author created on behalf of another account stays `Unclaimed` until its owner claims it.
+    /// - The deposit of the author is held from the caller.
+    /// - The author is stamped with the current block.
     ///
     /// # Parameters
     /// - `nickname`: A bounded vector representing the nickname of the author. This is a required field.
@@ -15,6 +19,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// # Errors
     /// - Returns `Error::<T, I>::AuthorAlreadyExists` if the author ID already exists in the storage.
     /// - Returns `Error::<T, I>::AuthorIdIncrementFailed` if the `NextAuthorId` cannot be incremented or initialized.
+    /// - Returns an error if the caller can not afford the deposit.
     ///
     /// # Events
     /// - Emits `Event::AuthorAdded` with the newly created author ID.
@@ -34,14 +39,26 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                 Error::<T, I>::AuthorAlreadyExists
             );
 
-            Authors::<T, I>::insert(
-                author_id,
-                AuthorDetails {
-                    nickname,
-                    real_name,
-                    owner: owner.unwrap_or(origin),
-                },
-            );
+            let owner = owner.unwrap_or(origin.clone());
+            let status = if owner == origin {
+                AuthorStatus::Claimed
+            } else {
+                AuthorStatus::Unclaimed
+            };
+            Self::index_author(author_id, None, Some(&owner));
+            let now = frame_system::Pallet::<T>::block_number();
+            let author = AuthorDetails {
+                nickname,
+                real_name,
+                owner,
+                status,
+                ipi: None,
+                created_at: now,
+                updated_at: now,
+            };
+            let deposit = Self::update_deposit(None, &origin, Self::author_deposit(&author))?;
+            AuthorDeposits::<T, I>::set(author_id, deposit);
+            Authors::<T, I>::insert(author_id, author);
             Self::deposit_event(Event::AuthorAdded { author_id });
 
             let new_author_id = author_id
@@ -58,9 +75,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     ///
     /// # It ensures
     /// - Ensures the author with the given `author_id` exists in the storage before making any changes.
-    /// - Updates the `real_name` field if a new value is provided.
-    /// - Updates the `owner` field if a new value is provided.
-    /// - Validates that the caller has the authority to modify the author's details.
+    /// - Updates the `real_name` field if a new value is provided, a verified author falls back to `Claimed`
+    ///   and the caller takes over the deposit of the author.
+    /// - Updates the `owner` field if a new value is provided, keeping the `AuthorsByOwner` storage in sync.
+    ///   The author stays `Unclaimed` until the new owner claims it.
+    /// - Validates that the caller is the owner of the author and has claimed it.
+    /// - The `updated_at` block of the author is the current block.
     ///
     /// # Parameters
     /// - `author_id`: The unique identifier of the author to be edited.
@@ -69,6 +89,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     ///
     /// # Errors
     /// - Returns `Error::<T, I>::AuthorNotFound` if the author with the given `author_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::NoPermission` if the caller is not the owner of the author.
+    /// - Returns `Error::<T, I>::AuthorNotClaimed` if the owner did not claim the author yet.
+    /// - Returns an error if the caller can not afford the deposit.
     ///
     /// # Events
     /// - Emits `Event::AuthorEdited` with the `author_id` of the edited author.
@@ -82,22 +105,167 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         Authors::<T, I>::try_mutate(author_id, |maybe_author| -> DispatchResult {
             let author = maybe_author.as_mut().ok_or(Error::<T, I>::AuthorNotFound)?;
 
-            Self::ensure_author_owner(&origin, &author.owner)?;
+            Self::ensure_author_owner(&origin, author)?;
 
             if let Some(new_real_name) = real_name {
                 author.real_name = Some(new_real_name);
+                if author.status == AuthorStatus::Verified {
+                    author.status = AuthorStatus::Claimed;
+                }
+                AuthorDeposits::<T, I>::try_mutate(author_id, |deposit| -> DispatchResult {
+                    *deposit = Self::update_deposit(
+                        deposit.take(),
+                        &origin,
+                        Self::author_deposit(author),
+                    )?;
+                    Ok(())
+                })?;
             }
 
-            if let Some(new_owner) = owner {
+            if let Some(new_owner) = owner.filter(|new_owner| *new_owner != author.owner) {
+                Self::index_author(author_id, Some(&author.owner), Some(&new_owner));
                 author.owner = new_owner;
+                author.status = AuthorStatus::Unclaimed;
             }
 
+            author.updated_at = frame_system::Pallet::<T>::block_number();
+
             Self::deposit_event(Event::AuthorEdited { author_id });
 
             Ok(())
         })
     }
 
+    /// Removes an author from the storage.
+    ///
+    /// # It ensures
+    /// - The author with the given `author_id` exists in the storage.
+    /// - Only the owner of the author can remove it, once it claimed the author.
+    /// - The author is not credited on any entity.
+    /// - The author is removed from the `AuthorsByOwner` and `AuthorsByIpi` storages and its deposit is released.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `author_id`: The unique identifier of the author to be removed.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthorNotFound` if the author with the given `author_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::NoPermission` if the caller is not the owner of the author.
+    /// - Returns `Error::<T, I>::AuthorNotClaimed` if the owner did not claim the author yet.
+    /// - Returns `Error::<T, I>::AuthorHasEntities` if the author is still credited on entities.
+    ///
+    /// # Events
+    /// - Emits `Event::AuthorRemoved` with the `author_id` of the removed author.
+    pub(crate) fn delete_author(origin: T::AccountId, author_id: T::AuthorId) -> DispatchResult {
+        let author = Self::get_author(author_id)?;
+
+        Self::ensure_author_owner(&origin, &author)?;
+
+        ensure!(
+            EntitiesByAuthor::<T, I>::iter_key_prefix(author_id)
+                .next()
+                .is_none(),
+            Error::<T, I>::AuthorHasEntities
+        );
+
+        Self::index_author(author_id, Some(&author.owner), None);
+        if let Some(ipi) = author.ipi {
+            AuthorsByIpi::<T, I>::remove(ipi);
+        }
+        Self::release_deposit(AuthorDeposits::<T, I>::take(author_id))?;
+        Authors::<T, I>::remove(author_id);
+
+        Self::deposit_event(Event::AuthorRemoved { author_id });
+
+        Ok(())
+    }
+
+    /// Claims an author created on behalf of the caller.
+    ///
+    /// # It ensures
+    /// - The author with the given `author_id` exists in the storage.
+    /// - Only the owner of the author can claim it.
+    /// - The author is `Unclaimed` and becomes `Claimed`.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `author_id`: The unique identifier of the author to be claimed.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthorNotFound` if the author with the given `author_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::NoPermission` if the caller is not the owner of the author.
+    /// - Returns `Error::<T, I>::AuthorAlreadyClaimed` if the author is already claimed.
+    ///
+    /// # Events
+    /// - Emits `Event::AuthorClaimed` with the `author_id` of the claimed author.
+    pub(crate) fn claim_author_owner(
+        origin: T::AccountId,
+        author_id: T::AuthorId,
+    ) -> DispatchResult {
+        Authors::<T, I>::try_mutate(author_id, |maybe_author| -> DispatchResult {
+            let author = maybe_author.as_mut().ok_or(Error::<T, I>::AuthorNotFound)?;
+
+            ensure!(origin == author.owner, Error::<T, I>::NoPermission);
+            ensure!(
+                author.status == AuthorStatus::Unclaimed,
+                Error::<T, I>::AuthorAlreadyClaimed
+            );
+
+            author.status = AuthorStatus::Claimed;
+            author.updated_at = frame_system::Pallet::<T>::block_number();
+
+            Self::deposit_event(Event::AuthorClaimed { author_id });
+
+            Ok(())
+        })
+    }
+
+    /// Attests or revokes the verification of an author.
+    ///
+    /// # It ensures
+    /// - The author with the given `author_id` exists in the storage.
+    /// - Only a claimed author can be verified, a revoked author falls back to `Claimed`.
+    ///
+    /// # Parameters
+    /// - `author_id`: The unique identifier of the author.
+    /// - `verified`: `true` to verify the author, `false` to revoke its verification.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthorNotFound` if the author with the given `author_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::AuthorNotClaimed` if the author to verify is not claimed by its owner.
+    /// - Returns `Error::<T, I>::AuthorNotVerified` if the author to revoke is not verified.
+    ///
+    /// # Events
+    /// - Emits `Event::AuthorVerified` or `Event::AuthorVerificationRevoked` with the `author_id`.
+    pub(crate) fn set_author_verification(
+        author_id: T::AuthorId,
+        verified: bool,
+    ) -> DispatchResult {
+        Authors::<T, I>::try_mutate(author_id, |maybe_author| -> DispatchResult {
+            let author = maybe_author.as_mut().ok_or(Error::<T, I>::AuthorNotFound)?;
+
+            if verified {
+                ensure!(
+                    author.status != AuthorStatus::Unclaimed,
+                    Error::<T, I>::AuthorNotClaimed
+                );
+                author.status = AuthorStatus::Verified;
+                Self::deposit_event(Event::AuthorVerified { author_id });
+            } else {
+                ensure!(
+                    author.status == AuthorStatus::Verified,
+                    Error::<T, I>::AuthorNotVerified
+                );
+                author.status = AuthorStatus::Claimed;
+                Self::deposit_event(Event::AuthorVerificationRevoked { author_id });
+            }
+
+            author.updated_at = frame_system::Pallet::<T>::block_number();
+
+            Ok(())
+        })
+    }
+
     /// Fetches a paginated list of authors from storage.
     ///
     /// # Parameters
@@ -148,8 +316,50 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         Ok(Authors::<T, I>::get(author_id).ok_or(Error::<T, I>::AuthorNotFound)?)
     }
 
-    fn ensure_author_owner(origin: &T::AccountId, owner: &T::AccountId) -> DispatchResult {
-        ensure!(origin.eq(owner), Error::<T, I>::NoPermission);
+    /// Validates that the caller owns an author and claimed it, an unclaimed author can only be claimed.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::NoPermission` if the caller is not the owner of the author.
+    /// - Returns `Error::<T, I>::AuthorNotClaimed` if the owner did not claim the author yet.
+    pub(crate) fn ensure_author_owner(
+        origin: &T::AccountId,
+        author: &AuthorFor<T, I>,
+    ) -> DispatchResult {
+        ensure!(origin.eq(&author.owner), Error::<T, I>::NoPermission);
+        ensure!(
+            author.status != AuthorStatus::Unclaimed,
+            Error::<T, I>::AuthorNotClaimed
+        );
+        Ok(())
+    }
+
+    /// Validates the authors credited on an entity.
+    ///
+    /// # It ensures
+    /// - Every author exists in the `Authors` storage.
+    /// - Every author that is not in `credited` yet is claimed by its owner, so an author created on behalf
+    ///   of an account is only credited once that account signed for it.
+    ///
+    /// # Parameters
+    /// - `authors`: The authors to credit.
+    /// - `credited`: The authors already credited on the entity, `None` for a new entity.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityAuthorNotFound` if an author does not exist in the storage.
+    /// - Returns `Error::<T, I>::AuthorNotClaimed` if a newly credited author is not claimed.
+    pub(crate) fn ensure_credited_authors<'a>(
+        authors: impl Iterator<Item = &'a T::AuthorId>,
+        credited: Option<&[T::AuthorId]>,
+    ) -> DispatchResult {
+        for author_id in authors {
+            let author =
+                Authors::<T, I>::get(author_id).ok_or(Error::<T, I>::EntityAuthorNotFound)?;
+            ensure!(
+                author.status != AuthorStatus::Unclaimed
+                    || credited.is_some_and(|credited| credited.contains(author_id)),
+                Error::<T, I>::AuthorNotClaimed
+            );
+        }
         Ok(())
     }
 }
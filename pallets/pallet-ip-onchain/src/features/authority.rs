@@ -1,3 +1,6 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
 use crate::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -7,6 +10,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// - The `NextAuthorityId` is incremented and used as the unique identifier for the new authority.
     /// - Ensures that the authority ID does not already exist in the storage.
     /// - The `add_first_access` function is called to initialize access rights for the new authority.
+    /// - The deposit of the authority and of its first access row is held from the caller.
+    /// - The authority is stamped with the current block.
     ///
     /// # Parameters
     /// - `origin`: The account ID of the caller.
@@ -17,6 +22,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// # Errors
     /// - Returns `Error::<T, I>::AuthorityAlreadyExists` if the authority ID already exists in the storage.
     /// - Returns `Error::<T, I>::AuthorityIdIncrementFailed` if the `NextAuthorityId` cannot be incremented or initialized.
+    /// - Returns an error if the caller can not afford the deposit.
     ///
     /// # Events
     /// - Emits `Event::AuthorityAdded` with the newly created authority ID.
@@ -39,14 +45,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             let collection_id =
                 Self::init_collection_id_checked(origin.clone(), collection_config)?;
 
-            Authorities::<T, I>::insert(
-                authority_id,
-                AuthorityDetails {
-                    authority_kind,
-                    name,
-                    collection_id,
-                },
-            );
+            let now = frame_system::Pallet::<T>::block_number();
+            let authority = AuthorityDetails {
+                authority_kind,
+                name,
+                collection_id,
+                created_at: now,
+                updated_at: now,
+            };
+            let deposit = Self::update_deposit(None, &origin, Self::authority_deposit(&authority))?;
+            AuthorityDeposits::<T, I>::set(authority_id, deposit);
+            Authorities::<T, I>::insert(authority_id, authority);
             Self::deposit_event(Event::AuthorityAdded { authority_id });
 
             Self::add_first_access(authority_id, origin)?;
@@ -66,9 +75,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// # It ensures
     /// - The authority with the given `authority_id` exists in the storage before making any changes.
     /// - The caller (`origin`) has the necessary access rights to edit the authority.
-    /// - Updates the `name` field if a new value is provided.
+    /// - Updates the `name` field if a new value is provided, the caller takes over the deposit of the authority.
     /// - Updates the `authority_kind` field if a new value is provided.
     /// - Initializes the NFT collection ID if provided and not already set.
+    /// - The `updated_at` block of the authority is the current block.
     ///
     /// # Parameters
     /// - `origin`: The account ID of the caller, which must have the required access rights for the authority.
@@ -80,6 +90,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// # Errors
     /// - Returns `Error::<T, I>::AuthorityNotFound` if the authority with the given `authority_id` does not exist in the storage.
     /// - Returns `Error::<T, I>::AuthorityNftCollectionIdAlreadyExist` if the collection ID is already initialized.
+    /// - Returns an error if the caller can not afford the deposit.
     ///
     /// # Events
     /// - Emits `Event::AuthorityEdited` with the `authority_id` of the edited authority.
@@ -113,19 +124,30 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                     AuthorityAccessSetting::CreateAuthorityCollection.into(),
                 )?;
 
-                let collection_id = Self::init_collection_id_checked(origin, collection_config)?;
+                let collection_id =
+                    Self::init_collection_id_checked(origin.clone(), collection_config)?;
 
                 authority.collection_id = collection_id;
             }
 
             if let Some(new_name) = name {
                 authority.name = new_name;
+                AuthorityDeposits::<T, I>::try_mutate(authority_id, |deposit| -> DispatchResult {
+                    *deposit = Self::update_deposit(
+                        deposit.take(),
+                        &origin,
+                        Self::authority_deposit(authority),
+                    )?;
+                    Ok(())
+                })?;
             }
 
             if let Some(new_authority_kind) = authority_kind {
                 authority.authority_kind = new_authority_kind;
             }
 
+            authority.updated_at = frame_system::Pallet::<T>::block_number();
+
             Ok(())
         })?;
 
@@ -134,6 +156,97 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         Ok(())
     }
 
+    /// Removes an authority from the storage.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditAuthority` access right for the authority.
+    /// - The authority does not own any entity or NFT collection and has no pending proposal or invitation.
+    /// - All `AuthoritiesAccess` rows of the authority and their `AccessByAccount`, `AccessExpiries`
+    ///   and delegation entries are removed.
+    /// - The threshold policy and the resale royalty of the authority are removed.
+    /// - The deposits of the authority and of its access rows are released.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `authority_id`: The unique identifier of the authority to be removed.
+    /// - `access_count`: The witness of the number of `AuthoritiesAccess` rows of the authority.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthorityNotFound` if the authority with the given `authority_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::AuthorityHasEntities` if the authority still owns entities.
+    /// - Returns `Error::<T, I>::AuthorityHasCollection` if the authority still has an NFT collection.
+    /// - Returns `Error::<T, I>::AuthorityHasProposals` if the authority still has pending proposals.
+    /// - Returns `Error::<T, I>::AuthorityHasInvitations` if the authority still has pending invitations.
+    /// - Returns `Error::<T, I>::BadWitness` if the authority has more access rows than `access_count`.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit the authority.
+    ///
+    /// # Events
+    /// - Emits `Event::AuthorityRemoved` with the `authority_id` of the removed authority.
+    pub(crate) fn delete_authority(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+        access_count: u32,
+    ) -> DispatchResult {
+        Self::ensure_access_right(
+            &origin,
+            &authority_id,
+            AuthorityAccessSetting::EditAuthority.into(),
+        )?;
+
+        let authority = Self::get_authority(authority_id)?;
+
+        ensure!(
+            EntitiesByAuthority::<T, I>::iter_key_prefix(authority_id)
+                .next()
+                .is_none(),
+            Error::<T, I>::AuthorityHasEntities
+        );
+        ensure!(
+            authority.collection_id.is_none(),
+            Error::<T, I>::AuthorityHasCollection
+        );
+        ensure!(
+            Proposals::<T, I>::iter_key_prefix(authority_id)
+                .next()
+                .is_none(),
+            Error::<T, I>::AuthorityHasProposals
+        );
+        ensure!(
+            AccessInvitations::<T, I>::iter_key_prefix(authority_id)
+                .next()
+                .is_none(),
+            Error::<T, I>::AuthorityHasInvitations
+        );
+
+        let accounts: Vec<T::AccountId> = AuthoritiesAccess::<T, I>::iter_key_prefix(authority_id)
+            .take(access_count.saturating_add(1) as usize)
+            .collect();
+        ensure!(
+            accounts.len() <= access_count as usize,
+            Error::<T, I>::BadWitness
+        );
+
+        for account_id in accounts {
+            AuthoritiesAccess::<T, I>::remove(authority_id, &account_id);
+            AccessByAccount::<T, I>::remove(&account_id, authority_id);
+            AccessExpiries::<T, I>::remove(authority_id, &account_id);
+            AccessDelegators::<T, I>::remove(authority_id, &account_id);
+            AccessDelegates::<T, I>::remove(authority_id, &account_id);
+            Self::release_deposit(AccessDeposits::<T, I>::take(authority_id, &account_id))?;
+        }
+        EditAccessHolders::<T, I>::remove(authority_id);
+        AuthorityPolicies::<T, I>::remove(authority_id);
+        AuthorityResaleRoyalties::<T, I>::remove(authority_id);
+        NextProposalId::<T, I>::remove(authority_id);
+        PendingProposals::<T, I>::remove(authority_id);
+        Self::release_deposit(AuthorityDeposits::<T, I>::take(authority_id))?;
+        Authorities::<T, I>::remove(authority_id);
+
+        Self::deposit_event(Event::AuthorityRemoved { authority_id });
+
+        Ok(())
+    }
+
     /// Fetches a paginated list of authorities from storage.
     ///
     /// # It ensures
@@ -0,0 +1,124 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Anchors the content of an entity or clears it.
+    ///
+    /// # It ensures
+    /// - The entity with the given `entity_id` exists in the storage and is not frozen by a dispute or a takedown.
+    /// - The caller has the `EditEntity` access right for the authority owning the entity.
+    /// - The content of an entity with the `Immutable` metadata feature can be anchored once and is never changed.
+    /// - The `content_hash` is a multihash and is not anchored by another entity.
+    /// - Keeps the `EntitiesByContentHash` storage in sync with the content of the entity.
+    /// - The caller takes over the deposit of the entity, which covers the bytes of the content.
+    /// - The new content is kept as the next revision of the entity with the caller as its editor.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `content`: The new content commitment, `None` to clear the content.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::EntityContentImmutable` if the content of an immutable entity is already anchored.
+    /// - Returns `Error::<T, I>::EntityContentHashInvalid` if the `content_hash` is not a multihash.
+    /// - Returns `Error::<T, I>::EntityContentAlreadyAnchored` if another entity anchored the `content_hash`.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit the entity.
+    /// - Returns an error if the caller can not afford the deposit.
+    /// - Returns `Error::<T, I>::Overflow` if the revision number can not be incremented.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityContentAnchored` with the `entity_id` and the `content_hash`, or `Event::EntityContentCleared`.
+    pub(crate) fn set_content(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+        content: Option<ContentCommitmentFor<T, I>>,
+    ) -> DispatchResult {
+        Entities::<T, I>::try_mutate(entity_id, |maybe_entity| -> DispatchResult {
+            let entity = maybe_entity.as_mut().ok_or(Error::<T, I>::EntityNotFound)?;
+            Self::ensure_not_frozen(entity_id)?;
+
+            Self::ensure_access_right(
+                &origin,
+                &entity.owner,
+                AuthorityAccessSetting::EditEntity.into(),
+            )?;
+
+            ensure!(
+                entity.content.is_none()
+                    || !entity
+                        .metadata
+                        .features
+                        .has_feature(MetadataFeature::Immutable),
+                Error::<T, I>::EntityContentImmutable
+            );
+
+            if let Some(new_content) = &content {
+                ensure!(
+                    Self::is_multihash(&new_content.content_hash),
+                    Error::<T, I>::EntityContentHashInvalid
+                );
+                ensure!(
+                    EntitiesByContentHash::<T, I>::get(&new_content.content_hash)
+                        .map_or(true, |anchored_by| anchored_by == entity_id),
+                    Error::<T, I>::EntityContentAlreadyAnchored
+                );
+            }
+
+            if let Some(old_content) = entity.content.take() {
+                EntitiesByContentHash::<T, I>::remove(old_content.content_hash);
+            }
+            if let Some(new_content) = &content {
+                EntitiesByContentHash::<T, I>::insert(&new_content.content_hash, entity_id);
+            }
+            entity.content = content;
+
+            EntityDeposits::<T, I>::try_mutate(entity_id, |deposit| -> DispatchResult {
+                *deposit =
+                    Self::update_deposit(deposit.take(), &origin, Self::entity_deposit(entity))?;
+                Ok(())
+            })?;
+            Self::bump_revision(entity_id, entity, &origin)?;
+
+            match &entity.content {
+                Some(content) => Self::deposit_event(Event::EntityContentAnchored {
+                    entity_id,
+                    content_hash: content.content_hash.clone(),
+                }),
+                None => Self::deposit_event(Event::EntityContentCleared { entity_id }),
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Checks that the bytes are a multihash: a varint hash function code, a varint digest
+    /// length and a non-empty digest of exactly that length.
+    fn is_multihash(bytes: &[u8]) -> bool {
+        let read_varint = |bytes: &mut &[u8]| -> Option<u64> {
+            let mut value: u64 = 0;
+            for (i, byte) in bytes.iter().take(9).enumerate() {
+                value |= u64::from(byte & 0x7f) << (7 * i);
+                if byte & 0x80 == 0 {
+                    *bytes = &bytes[i + 1..];
+                    return Some(value);
+                }
+            }
+            None
+        };
+
+        let mut rest = bytes;
+        read_varint(&mut rest).is_some()
+            && read_varint(&mut rest)
+                .is_some_and(|length| length != 0 && length == rest.len() as u64)
+    }
+
+    /// Retrieves the entity that anchored the `content_hash`.
+    pub fn get_entity_by_content_hash(content_hash: Vec<u8>) -> Option<T::EntityId> {
+        let content_hash = ContentHashFor::<T, I>::try_from(content_hash).ok()?;
+        EntitiesByContentHash::<T, I>::get(content_hash)
+    }
+}
@@ -0,0 +1,173 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Delegates a strict subset of the access of the caller to another account.
+    ///
+    /// # It ensures
+    /// - The caller has a direct access to the authority, a delegated access can not be delegated further.
+    /// - The delegated access is a non-empty strict subset of the access of the caller without the `EditAccess` right.
+    /// - The account has no access and no pending invitation for the authority, an expired access is pruned first.
+    /// - The delegation is traced in the `AccessDelegators` and `AccessDelegates` storages,
+    ///   and the deposit of the access is held from the caller.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the delegator.
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `account_id`: The unique identifier of the delegate.
+    /// - `access`: The delegated access settings.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthoritiesAccessNotFound` if the caller has no access to the authority.
+    /// - Returns `Error::<T, I>::DelegationInvalid` if the access can not be delegated by the caller.
+    /// - Returns `Error::<T, I>::AuthoritiesAccessExist` if the account already has access to the authority.
+    /// - Returns `Error::<T, I>::AccessInvitationPending` if the account is invited to the authority.
+    /// - Returns `Error::<T, I>::ProposalRequired` if the authority governs access changes by its policy.
+    /// - Returns `Error::<T, I>::LimitExceeded` if the caller already has `MaxAccessDelegates` delegates.
+    /// - Returns an error if the caller can not afford the deposit.
+    ///
+    /// # Events
+    /// - Emits `Event::AccessDelegated` with the `authority_id`, the delegator and the delegate.
+    pub(crate) fn add_delegation(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+        account_id: T::AccountId,
+        access: AuthorityAccessSettings,
+    ) -> DispatchResult {
+        let delegator_access = Self::get_access(authority_id, &origin)
+            .ok_or(Error::<T, I>::AuthoritiesAccessNotFound)?;
+        ensure!(
+            !AccessDelegators::<T, I>::contains_key(authority_id, &origin)
+                && !access.has_access(AuthorityAccessSetting::EditAccess.into())
+                && Self::is_strict_subset(access, delegator_access),
+            Error::<T, I>::DelegationInvalid
+        );
+        Self::ensure_not_governed(&authority_id, GovernedAction::AccessChange)?;
+
+        Self::prune_expired_access(authority_id, &account_id)?;
+        ensure!(
+            !AuthoritiesAccess::<T, I>::contains_key(authority_id, &account_id),
+            Error::<T, I>::AuthoritiesAccessExist
+        );
+        ensure!(
+            !AccessInvitations::<T, I>::contains_key(authority_id, &account_id),
+            Error::<T, I>::AccessInvitationPending
+        );
+
+        AccessDelegates::<T, I>::try_mutate(authority_id, &origin, |delegates| {
+            delegates
+                .try_push(account_id.clone())
+                .map_err(|_| Error::<T, I>::LimitExceeded)
+        })?;
+        AccessDelegators::<T, I>::insert(authority_id, &account_id, &origin);
+        Self::write_access(authority_id, &account_id, Some(access))?;
+
+        let deposit = Self::update_deposit(None, &origin, T::AccessDeposit::get())?;
+        AccessDeposits::<T, I>::set(authority_id, &account_id, deposit);
+
+        Self::deposit_event(Event::AccessDelegated {
+            authority_id,
+            delegator: origin,
+            account_id,
+        });
+
+        Ok(())
+    }
+
+    /// Revokes an access delegated by the caller.
+    ///
+    /// # It ensures
+    /// - Only the delegator revokes its delegations, the holders of the `EditAccess` right can remove
+    ///   any access with `remove_account_access`.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the delegator.
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `account_id`: The unique identifier of the delegate.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::DelegationNotFound` if the access of the account is not delegated.
+    /// - Returns `Error::<T, I>::NotAuthorized` if the caller is not the delegator.
+    ///
+    /// # Events
+    /// - Emits `Event::AccessDelegationRevoked` with the `authority_id` and the delegate.
+    pub(crate) fn remove_delegation(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+        account_id: T::AccountId,
+    ) -> DispatchResult {
+        let delegator = AccessDelegators::<T, I>::get(authority_id, &account_id)
+            .ok_or(Error::<T, I>::DelegationNotFound)?;
+        ensure!(delegator == origin, Error::<T, I>::NotAuthorized);
+
+        Self::revoke_delegation(authority_id, &account_id)
+    }
+
+    /// Revokes the delegations of an account that are no longer covered by its access.
+    ///
+    /// # It ensures
+    /// - A delegation is kept only while it is a strict subset of the new access of the delegator,
+    ///   all the delegations are revoked when the access of the delegator is removed.
+    ///
+    /// # Parameters
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `delegator`: The account ID of the delegator.
+    /// - `delegator_access`: The new access settings of the delegator, `None` if the access is removed.
+    ///
+    /// # Events
+    /// - Emits `Event::AccessDelegationRevoked` with the `authority_id` and the delegate for each revoked delegation.
+    pub(crate) fn revoke_lapsed_delegations(
+        authority_id: T::AuthorityId,
+        delegator: &T::AccountId,
+        delegator_access: Option<AuthorityAccessSettings>,
+    ) -> DispatchResult {
+        for delegate in AccessDelegates::<T, I>::get(authority_id, delegator) {
+            let covered = delegator_access
+                .zip(AuthoritiesAccess::<T, I>::get(authority_id, &delegate))
+                .is_some_and(|(delegator_access, access)| {
+                    Self::is_strict_subset(access, delegator_access)
+                });
+
+            if !covered {
+                Self::revoke_delegation(authority_id, &delegate)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes a delegated access and releases its deposit.
+    fn revoke_delegation(authority_id: T::AuthorityId, delegate: &T::AccountId) -> DispatchResult {
+        Self::write_access(authority_id, delegate, None)?;
+        Self::release_deposit(AccessDeposits::<T, I>::take(authority_id, delegate))?;
+
+        Self::deposit_event(Event::AccessDelegationRevoked {
+            authority_id,
+            account_id: delegate.clone(),
+        });
+
+        Ok(())
+    }
+
+    /// Checks whether the access is a non-empty strict subset of the other access.
+    fn is_strict_subset(access: AuthorityAccessSettings, of: AuthorityAccessSettings) -> bool {
+        !access.0.is_empty() && of.0.contains(access.0) && access.0 != of.0
+    }
+
+    /// Retrieves the delegator of a delegated access.
+    pub fn get_access_delegator(
+        authority_id: T::AuthorityId,
+        account_id: &T::AccountId,
+    ) -> Option<T::AccountId> {
+        AccessDelegators::<T, I>::get(authority_id, account_id)
+    }
+
+    /// Retrieves the delegates of an account.
+    pub fn get_access_delegates(
+        authority_id: T::AuthorityId,
+        account_id: &T::AccountId,
+    ) -> Vec<T::AccountId> {
+        AccessDelegates::<T, I>::get(authority_id, account_id).into_inner()
+    }
+}
@@ -0,0 +1,136 @@
+use crate::*;
+
+use frame::traits::{fungible::MutateHold, tokens::Precision};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Validates that an account may call the pallet.
+    ///
+    /// # It ensures
+    /// - The account is whitelisted, or the permissionless mode is enabled and the account
+    ///   only has to post the deposits of the records it creates.
+    ///
+    /// # Parameters
+    /// - `who`: The account ID of the caller.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::NotWhitelisted` if the account is not whitelisted and the permissionless mode is disabled.
+    pub(crate) fn ensure_whitelisted(who: &T::AccountId) -> DispatchResult {
+        ensure!(
+            PermissionlessMode::<T, I>::get() || T::WhiteListChecker::contains(who),
+            Error::<T, I>::NotWhitelisted
+        );
+        Ok(())
+    }
+
+    /// Holds the deposit of a record, replacing its previous deposit.
+    ///
+    /// # It ensures
+    /// - If the `depositor` already holds the previous deposit, only the difference is held or released.
+    /// - Otherwise the previous deposit is released to its depositor and the whole `amount` is held from the `depositor`.
+    ///
+    /// # Parameters
+    /// - `old_deposit`: The deposit currently held for the record, `None` if there is none.
+    /// - `depositor`: The account ID posting the deposit.
+    /// - `amount`: The deposit required for the record.
+    ///
+    /// # Returns
+    /// - The new deposit of the record, `None` if the required `amount` is zero.
+    ///
+    /// # Errors
+    /// - Returns an error if the `depositor` can not afford the deposit.
+    pub(crate) fn update_deposit(
+        old_deposit: Option<DepositFor<T, I>>,
+        depositor: &T::AccountId,
+        amount: BalanceOf<T, I>,
+    ) -> Result<Option<DepositFor<T, I>>, DispatchError> {
+        let reason = HoldReason::StorageDeposit.into();
+
+        match old_deposit {
+            Some(old_deposit) if old_deposit.depositor == *depositor => {
+                if amount > old_deposit.amount {
+                    T::Currency::hold(&reason, depositor, amount - old_deposit.amount)?;
+                } else if amount < old_deposit.amount {
+                    T::Currency::release(
+                        &reason,
+                        depositor,
+                        old_deposit.amount - amount,
+                        Precision::BestEffort,
+                    )?;
+                }
+            }
+            old_deposit => {
+                Self::release_deposit(old_deposit)?;
+                if !amount.is_zero() {
+                    T::Currency::hold(&reason, depositor, amount)?;
+                }
+            }
+        }
+
+        Ok((!amount.is_zero()).then(|| Deposit {
+            depositor: depositor.clone(),
+            amount,
+        }))
+    }
+
+    /// Releases the deposit of a removed record to its depositor.
+    ///
+    /// # Parameters
+    /// - `deposit`: The deposit held for the record, `None` if there is none.
+    ///
+    /// # Errors
+    /// - Returns an error if the release fails.
+    pub(crate) fn release_deposit(deposit: Option<DepositFor<T, I>>) -> DispatchResult {
+        if let Some(deposit) = deposit {
+            T::Currency::release(
+                &HoldReason::StorageDeposit.into(),
+                &deposit.depositor,
+                deposit.amount,
+                Precision::BestEffort,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Calculates the deposit of an authority.
+    pub fn authority_deposit(authority: &AuthorityDetailsFor<T, I>) -> BalanceOf<T, I> {
+        T::AuthorityDeposit::get().saturating_add(Self::bytes_deposit(authority.name.len()))
+    }
+
+    /// Calculates the deposit of an author.
+    pub fn author_deposit(author: &AuthorFor<T, I>) -> BalanceOf<T, I> {
+        let bytes = author.nickname.len()
+            + author
+                .real_name
+                .as_ref()
+                .map_or(0, |real_name| real_name.len());
+        T::AuthorDeposit::get().saturating_add(Self::bytes_deposit(bytes))
+    }
+
+    /// Calculates the deposit of an entity, including the bytes mirrored into its NFT
+    /// and the `MaxEntityRevisions` revisions kept in its history.
+    pub fn entity_deposit(entity: &EntityDetailsFor<T, I>) -> BalanceOf<T, I> {
+        let bytes = entity.metadata.url.len()
+            + entity
+                .content
+                .as_ref()
+                .map_or(0, |content| content.byte_len())
+            + Self::entity_nft_bytes(entity);
+        let revisions_deposit =
+            T::RevisionDeposit::get().saturating_mul(T::MaxEntityRevisions::get().into());
+        T::EntityDeposit::get()
+            .saturating_add(Self::bytes_deposit(bytes))
+            .saturating_add(revisions_deposit)
+    }
+
+    fn bytes_deposit(bytes: usize) -> BalanceOf<T, I> {
+        T::ByteDeposit::get().saturating_mul((bytes as u32).into())
+    }
+
+    /// Enables or disables the permissionless mode.
+    ///
+    /// # It ensures
+    /// - While enabled, accounts that are not whitelisted can call the pallet and post deposits for their records.
+    pub fn set_permissionless(enabled: bool) {
+        PermissionlessMode::<T, I>::put(enabled);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::*;
+
+use frame::traits::{
+    fungible::MutateHold,
+    tokens::{Fortitude, Precision},
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Opens a dispute against the registration of an entity.
+    ///
+    /// # It ensures
+    /// - The entity with the given `entity_id` exists in the storage and is not frozen by another dispute or a takedown.
+    /// - The `DisputeBond` is held from the caller until the dispute is resolved.
+    /// - The entity is frozen while the dispute is open.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the claimant.
+    /// - `entity_id`: The unique identifier of the disputed entity.
+    /// - `evidence_url`: The URL of the evidence supporting the claim.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::DisputeAlreadyOpen` if the entity is already disputed.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is taken down.
+    /// - Returns an error if the caller can not afford the bond.
+    ///
+    /// # Events
+    /// - Emits `Event::DisputeOpened` with the `entity_id` and the claimant.
+    pub(crate) fn add_dispute(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+        evidence_url: BoundedVec<u8, T::MaxLongStringLength>,
+    ) -> DispatchResult {
+        Self::get_entity(entity_id)?;
+        ensure!(
+            !Disputes::<T, I>::contains_key(entity_id),
+            Error::<T, I>::DisputeAlreadyOpen
+        );
+        Self::ensure_not_frozen(entity_id)?;
+
+        let bond = T::DisputeBond::get();
+        if !bond.is_zero() {
+            T::Currency::hold(&HoldReason::DisputeBond.into(), &origin, bond)?;
+        }
+
+        Disputes::<T, I>::insert(
+            entity_id,
+            Dispute {
+                claimant: origin.clone(),
+                bond,
+                evidence_url,
+                opened_at: frame_system::Pallet::<T>::block_number(),
+            },
+        );
+
+        Self::deposit_event(Event::DisputeOpened {
+            entity_id,
+            claimant: origin,
+        });
+
+        Ok(())
+    }
+
+    /// Resolves the open dispute of an entity.
+    ///
+    /// # It ensures
+    /// - `Uphold` refunds the bond and takes the entity down, it stays frozen in the `TakenDownEntities` storage.
+    /// - `Reject` slashes the bond and unfreezes the entity.
+    /// - `TransferOwnership` refunds the bond and moves the entity with its NFT to the given authority,
+    ///   the new owner is mirrored into the NFT and the move is kept as the next revision of the entity
+    ///   with the claimant as its editor.
+    /// - A pending transfer offer of the entity is cancelled unless the dispute is rejected.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the disputed entity.
+    /// - `resolution`: The decision of the arbiter.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::DisputeNotFound` if the entity is not disputed.
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::AuthorityNotFound` if the authority receiving the entity does not exist in the storage.
+    /// - Returns an error if the bond can not be released or slashed, or the NFT move fails.
+    ///
+    /// # Events
+    /// - Emits `Event::DisputeResolved` with the `entity_id` and the `resolution`.
+    pub(crate) fn settle_dispute(
+        entity_id: T::EntityId,
+        resolution: DisputeResolution<T::AuthorityId>,
+    ) -> DispatchResult {
+        let dispute = Disputes::<T, I>::take(entity_id).ok_or(Error::<T, I>::DisputeNotFound)?;
+        let reason = HoldReason::DisputeBond.into();
+
+        match &resolution {
+            DisputeResolution::Reject => {
+                T::Currency::burn_held(
+                    &reason,
+                    &dispute.claimant,
+                    dispute.bond,
+                    Precision::BestEffort,
+                    Fortitude::Force,
+                )?;
+            }
+            DisputeResolution::Uphold => {
+                T::Currency::release(
+                    &reason,
+                    &dispute.claimant,
+                    dispute.bond,
+                    Precision::BestEffort,
+                )?;
+                EntityTransfers::<T, I>::remove(entity_id);
+                TakenDownEntities::<T, I>::insert(entity_id, ());
+            }
+            DisputeResolution::TransferOwnership(to_authority) => {
+                T::Currency::release(
+                    &reason,
+                    &dispute.claimant,
+                    dispute.bond,
+                    Precision::BestEffort,
+                )?;
+                EntityTransfers::<T, I>::remove(entity_id);
+
+                Entities::<T, I>::try_mutate(entity_id, |maybe_entity| -> DispatchResult {
+                    let entity = maybe_entity.as_mut().ok_or(Error::<T, I>::EntityNotFound)?;
+                    if entity.owner == *to_authority {
+                        return Ok(());
+                    }
+                    Self::get_authority(*to_authority)?;
+
+                    let old_entity = entity.clone();
+                    Self::move_entity_nft(entity_id, entity, to_authority)?;
+                    entity.owner = *to_authority;
+                    Self::mirror_entity_nft(entity_id, entity)?;
+
+                    Self::index_entity(entity_id, Some(&old_entity), Some(entity));
+                    Self::bump_revision(entity_id, entity, &dispute.claimant)
+                })?;
+            }
+        }
+
+        Self::deposit_event(Event::DisputeResolved {
+            entity_id,
+            resolution,
+        });
+
+        Ok(())
+    }
+
+    /// Reinstates an entity taken down by an upheld dispute.
+    ///
+    /// # It ensures
+    /// - The entity leaves the `TakenDownEntities` storage and can be changed, disputed and paid again.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the taken down entity.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotTakenDown` if the entity is not taken down.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityReinstated` with the `entity_id`.
+    pub(crate) fn reinstate_taken_down_entity(entity_id: T::EntityId) -> DispatchResult {
+        ensure!(
+            TakenDownEntities::<T, I>::take(entity_id).is_some(),
+            Error::<T, I>::EntityNotTakenDown
+        );
+
+        Self::deposit_event(Event::EntityReinstated { entity_id });
+
+        Ok(())
+    }
+
+    /// Validates that an entity can be changed.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    pub(crate) fn ensure_not_frozen(entity_id: T::EntityId) -> DispatchResult {
+        ensure!(
+            !Disputes::<T, I>::contains_key(entity_id)
+                && !TakenDownEntities::<T, I>::contains_key(entity_id),
+            Error::<T, I>::EntityFrozen
+        );
+        Ok(())
+    }
+
+    /// Retrieves the open dispute of an entity.
+    pub fn get_dispute(entity_id: T::EntityId) -> Option<DisputeFor<T, I>> {
+        Disputes::<T, I>::get(entity_id)
+    }
+
+    /// Checks whether an entity was taken down by an upheld dispute.
+    pub fn is_taken_down(entity_id: T::EntityId) -> bool {
+        TakenDownEntities::<T, I>::contains_key(entity_id)
+    }
+}
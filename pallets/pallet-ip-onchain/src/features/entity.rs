@@ -1,3 +1,6 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
 use crate::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -6,9 +9,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// # It ensures
     /// - The `NextEntityId` is incremented and used as the unique identifier for the new entity.
     /// - Ensures that the entity ID does not already exist in the storage.
-    /// - Validates that all provided authors exist in the `Authors` storage if the `authors` parameter is provided.
-    /// - Validates that all related entities exist in the `Entities` storage if the `related_entities` parameter is provided.
+    /// - Validates that all provided authors exist in the `Authors` storage and are claimed by their owners
+    ///   if the `authors` parameter is provided.
+    /// - Validates that all related entities exist in the `Entities` storage and are unique if the `related_entities` parameter is provided.
+    /// - Indexes the relations in the `Derivatives` storage and the entity in the `EntitiesByAuthority`
+    ///   and `EntitiesByAuthor` storages.
+    /// - Validates the royalty split if the `royalty_parts` parameter is provided.
+    /// - Validates that the `url` fits the declared metadata standard.
     /// - Ensures the caller has the necessary access rights to create the entity.
+    /// - The metadata URL, the kind, the ID and the owner of the entity are mirrored into its NFT, see `mirror_entity_nft`.
+    /// - The deposit of the entity is held from the caller.
+    /// - The entity is stamped with the current block and its details are kept as revision `0`.
     ///
     /// # Parameters
     /// - `entity_kind`: Specifies the type of the entity (e.g., `Loop`, `Music`, etc.).
@@ -17,7 +28,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// - `metadata_standard`: The standard format for the metadata.
     /// - `authors`: An optional bounded vector of author IDs linked to the entity.
     /// - `royalty_parts`: An optional bounded vector of wallets defining the royalty distribution for the entity.
-    /// - `related_entities`: An optional bounded vector of entity IDs linked as related entities.
+    /// - `related_entities`: An optional bounded vector of typed relations to the entities this entity derives from.
     /// - `nft_item_id`: An optional NFT item ID associated with the entity.
     /// - `nft_owner`: An optional account ID representing the owner of the NFT.
     /// - `nft_item_config`: An optional configuration for the NFT item.
@@ -26,8 +37,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// - Returns `Error::<T, I>::EntityAlreadyExists` if the entity ID already exists in the storage.
     /// - Returns `Error::<T, I>::EntityIdIncrementFailed` if the `NextEntityId` cannot be incremented or initialized.
     /// - Returns `Error::<T, I>::EntityAuthorNotFound` if any of the provided authors do not exist in the `Authors` storage.
+    /// - Returns `Error::<T, I>::AuthorNotClaimed` if any of the provided authors is not claimed by its owner.
     /// - Returns `Error::<T, I>::EntityRelatedEntityNotFound` if any of the provided related entities do not exist in the `Entities` storage.
+    /// - Returns `Error::<T, I>::EntityRelationDuplicate` if an entity appears in the provided relations more than once.
+    /// - Returns a royalty error if the provided `royalty_parts` are not a valid split.
+    /// - Returns a metadata error if the standard is not supported or the `url` does not fit it.
     /// - Returns an access control error if the caller does not have the necessary rights to create the entity.
+    /// - Returns an error if the caller can not afford the deposit.
+    ///
+    /// # Returns
+    /// - The unique identifier of the new entity.
     ///
     /// # Events
     /// - Emits `Event::EntityAdded` with the newly created entity ID.
@@ -40,15 +59,23 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         metadata_features: MetadataFeatures,
         authors: Option<BoundedVec<T::AuthorId, T::MaxEntityAuthors>>,
         royalty_parts: Option<BoundedVec<Wallet<T::AccountId>, T::MaxRoyaltyParts>>,
-        related_entities: Option<BoundedVec<T::EntityId, T::MaxRelatedEntities>>,
+        related_entities: Option<BoundedVec<Relation<T::EntityId>, T::MaxRelatedEntities>>,
         nft_item_id: Option<T::ItemId>,
         nft_owner: Option<T::AccountId>,
         nft_item_config: Option<pallet_nfts::ItemConfig>,
-    ) -> DispatchResult {
+    ) -> Result<T::EntityId, DispatchError> {
         Self::ensure_access_right(&origin, &owner, AuthorityAccessSetting::CreateEntity.into())?;
-        let collection_id =
-            Self::mint_nft_for_entity(&owner, nft_item_id, nft_owner, nft_item_config)?;
-        NextEntityId::<T, I>::try_mutate(|maybe_entity_id| -> DispatchResult {
+        if let Some(royalty_parts) = &royalty_parts {
+            Self::ensure_valid_royalty_parts(royalty_parts)?;
+        }
+        let collection_id = Self::mint_nft_for_entity(
+            &owner,
+            nft_item_id,
+            nft_owner,
+            nft_item_config,
+            &metadata_features,
+        )?;
+        NextEntityId::<T, I>::try_mutate(|maybe_entity_id| -> Result<T::EntityId, DispatchError> {
             let entity_id = maybe_entity_id
                 .map_or(T::EntityId::initial_value(), Some)
                 .ok_or(Error::<T, I>::EntityIdIncrementFailed)?;
@@ -58,40 +85,51 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                 Error::<T, I>::EntityAlreadyExists
             );
 
+            let metadata = Metadata {
+                url,
+                standard: metadata_standard,
+                features: metadata_features,
+            };
+            Self::ensure_valid_metadata(&metadata)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
             let mut entity_details = EntityDetails {
                 entity_kind,
                 owner,
                 authors: None,
                 royalty_parts,
                 related_to: None,
-                metadata: Metadata {
-                    url,
-                    standard: metadata_standard,
-                    features: metadata_features,
-                },
+                metadata,
                 item_id: nft_item_id,
                 collection_id,
+                content: None,
+                identifiers: Default::default(),
+                created_at: now,
+                updated_at: now,
+                revision: 0,
             };
 
             if let Some(new_authors) = authors {
-                ensure!(
-                    new_authors.iter().all(Authors::<T, I>::contains_key),
-                    Error::<T, I>::EntityAuthorNotFound
-                );
+                Self::ensure_credited_authors(new_authors.iter(), None)?;
 
                 entity_details.authors = Some(new_authors);
             }
 
             if let Some(new_related_entities) = related_entities {
-                ensure!(
-                    new_related_entities
-                        .iter()
-                        .all(Entities::<T, I>::contains_key),
-                    Error::<T, I>::EntityRelatedEntityNotFound
-                );
+                Self::ensure_valid_relations(None, &new_related_entities)?;
                 entity_details.related_to = Some(new_related_entities);
             }
 
+            Self::index_relations(entity_id, &None, &entity_details.related_to);
+            Self::index_entity(entity_id, None, Some(&entity_details));
+            let deposit =
+                Self::update_deposit(None, &origin, Self::entity_deposit(&entity_details))?;
+            EntityDeposits::<T, I>::set(entity_id, deposit);
+            Self::store_revision(entity_id, &entity_details, &origin);
+            Self::mirror_entity_nft(entity_id, &entity_details)?;
+            if entity_details.collection_id.is_some() {
+                EntityNftItemConfigs::<T, I>::set(entity_id, nft_item_config);
+            }
             Entities::<T, I>::insert(entity_id, entity_details);
 
             Self::deposit_event(Event::EntityAdded { entity_id });
@@ -102,7 +140,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
             *maybe_entity_id = Some(new_entity_id);
 
-            Ok(())
+            Ok(entity_id)
         })
     }
 
@@ -110,13 +148,24 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     ///
     /// # It ensures
     /// - The entity with the given `entity_id` exists in the storage before making any changes.
+    /// - The entity is not frozen by a dispute or a takedown, which also prevents minting its NFT.
     /// - Validates that the caller has the authority to modify the entity details.
-    /// - Updates the `metadata` field if a new value is provided.
-    /// - Updates the `owner` field if a new value is provided.
-    /// - Updates the `authors` field if a new value is provided, ensuring all provided authors exist in the `Authors` storage.
-    /// - Updates the `royalty_parts` field if a new value is provided.
-    /// - Updates the `related_to` field if a new value is provided, ensuring all related entities exist in the `Entities` storage.
+    /// - Updates the `metadata` field if a new value is provided, a new `url` or a new NFT makes the caller take over
+    ///   the deposit of the entity. A new `url` or standard is validated against the standard.
+    /// - Updates the `owner` field if a new value is provided, the caller must be able to create entities for the new owner.
+    ///   A pending transfer offer of the entity is cancelled by an owner change.
+    /// - Updates the `authors` field if a new value is provided, ensuring all provided authors exist in the `Authors` storage
+    ///   and the newly credited authors are claimed by their owners.
+    /// - Updates the `royalty_parts` field if a new value is provided, ensuring it is a valid royalty split.
+    /// - Updates the `related_to` field if a new value is provided, ensuring all related entities exist in the `Entities` storage,
+    ///   the entity does not relate to itself and the new relations do not close a cycle.
+    /// - Keeps the `Derivatives` storage in sync with the `related_to` field.
+    /// - Keeps the `EntitiesByAuthority` and `EntitiesByAuthor` storages in sync with the `owner` and `authors` fields.
+    /// - The NFT linked to the entity moves to the collection of a new owner, if it has one, see `move_entity_nft`.
+    /// - Mirrors the entity into its NFT again if the `url` or the `owner` changes or the NFT is minted,
+    ///   see `mirror_entity_nft`.
     /// - Ensures the caller has the necessary access rights to edit the entity.
+    /// - The edited details are kept as the next revision of the entity with the caller as its editor.
     ///
     /// # Parameters
     /// - `origin`: The account ID of the caller attempting to edit the entity.
@@ -126,17 +175,30 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// - `owner`: An optional authority ID representing the new owner of the entity. If `None`, the `owner` field remains unchanged.
     /// - `authors`: An optional bounded vector of author IDs to update the entity's authors. If `None`, the `authors` field remains unchanged.
     /// - `royalty_parts`: An optional bounded vector of wallets representing the new royalty parts for the entity. If `None`, the `royalty_parts` field remains unchanged.
-    /// - `related_entities`: An optional bounded vector of entity IDs representing the new related entities for the entity. If `None`, the `related_to` field remains unchanged.
+    /// - `related_entities`: An optional bounded vector of typed relations representing the new related entities for the entity. If `None`, the `related_to` field remains unchanged.
     /// - `nft_item_id`: An optional NFT item ID for the entity.
     /// - `nft_owner`: An optional account ID for the NFT owner.
     /// - `nft_item_config`: An optional configuration for the NFT item.
     ///
     /// # Errors
     /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
     /// - Returns `Error::<T, I>::EntityAuthorNotFound` if any of the provided authors do not exist in the `Authors` storage.
+    /// - Returns `Error::<T, I>::AuthorNotClaimed` if any of the newly credited authors is not claimed by its owner.
     /// - Returns `Error::<T, I>::EntityRelatedEntityNotFound` if any of the provided related entities do not exist in the `Entities` storage.
+    /// - Returns `Error::<T, I>::EntityRelationToSelf` if the entity relates to itself.
+    /// - Returns `Error::<T, I>::EntityRelationDuplicate` if an entity appears in the provided relations more than once.
+    /// - Returns `Error::<T, I>::EntityRelationCycle` if the provided relations close a cycle.
+    /// - Returns `Error::<T, I>::EntityLineageTooLarge` if the lineage is too large to be checked for cycles.
     /// - Returns `Error::<T, I>::EntityNftImmutable` if caller try to rewrite item_id for entity.
-    /// - Returns an access control error if the caller does not have the necessary rights to edit the entity.
+    /// - Returns a royalty error if the provided `royalty_parts` are not a valid split.
+    /// - Returns a metadata error if the standard is not supported or the `url` does not fit it.
+    /// - Returns `Error::<T, I>::ProposalRequired` if the owner or the `royalty_parts` change while the owning
+    ///   authority governs entity transfers or royalty edits by its policy.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit the entity
+    ///   or to create entities for the new owner.
+    /// - Returns an error if the caller can not afford the deposit.
+    /// - Returns `Error::<T, I>::Overflow` if the revision number can not be incremented.
     ///
     /// # Events
     /// - Emits `Event::EntityEdited` with the `entity_id` of the edited entity.
@@ -149,13 +211,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         owner: Option<T::AuthorityId>,
         authors: Option<BoundedVec<T::AuthorId, T::MaxEntityAuthors>>,
         royalty_parts: Option<BoundedVec<Wallet<T::AccountId>, T::MaxRoyaltyParts>>,
-        related_entities: Option<BoundedVec<T::EntityId, T::MaxRelatedEntities>>,
+        related_entities: Option<BoundedVec<Relation<T::EntityId>, T::MaxRelatedEntities>>,
         nft_item_id: Option<T::ItemId>,
         nft_owner: Option<T::AccountId>,
         nft_item_config: Option<pallet_nfts::ItemConfig>,
     ) -> DispatchResult {
         Entities::<T, I>::try_mutate(entity_id, |maybe_entity| -> DispatchResult {
             let entity = maybe_entity.as_mut().ok_or(Error::<T, I>::EntityNotFound)?;
+            Self::ensure_not_frozen(entity_id)?;
 
             Self::ensure_access_right(
                 &origin,
@@ -163,11 +226,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                 AuthorityAccessSetting::EditEntity.into(),
             )?;
 
+            let old_entity = entity.clone();
+
             if !entity
                 .metadata
                 .features
                 .has_feature(MetadataFeature::Immutable)
             {
+                let validate_metadata = url.is_some() || metadata_standard.is_some();
+
                 if let Some(new_url) = url {
                     entity.metadata.url = new_url;
                 }
@@ -179,33 +246,47 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                 if let Some(new_metadata_features) = metadata_features {
                     entity.metadata.features = new_metadata_features;
                 }
+
+                if validate_metadata {
+                    Self::ensure_valid_metadata(&entity.metadata)?;
+                }
             }
 
-            if let Some(new_owner) = owner {
+            if let Some(new_owner) = owner.filter(|new_owner| *new_owner != entity.owner) {
+                Self::ensure_not_governed(&old_entity.owner, GovernedAction::EntityTransfer)?;
+                Self::ensure_access_right(
+                    &origin,
+                    &new_owner,
+                    AuthorityAccessSetting::CreateEntity.into(),
+                )?;
+                EntityTransfers::<T, I>::remove(entity_id);
+                Self::move_entity_nft(entity_id, entity, &new_owner)?;
                 entity.owner = new_owner;
             }
 
             if let Some(new_authors) = authors {
-                ensure!(
-                    new_authors.iter().all(Authors::<T, I>::contains_key),
-                    Error::<T, I>::EntityAuthorNotFound
-                );
+                Self::ensure_credited_authors(
+                    new_authors.iter(),
+                    old_entity
+                        .authors
+                        .as_ref()
+                        .map(|authors| authors.as_slice()),
+                )?;
 
                 entity.authors = Some(new_authors);
             }
 
             if let Some(new_royalty_parts) = royalty_parts {
+                Self::ensure_not_governed(&old_entity.owner, GovernedAction::RoyaltyEdit)?;
+                Self::ensure_valid_royalty_parts(&new_royalty_parts)?;
                 entity.royalty_parts = Some(new_royalty_parts);
             }
 
             if let Some(new_related_entities) = related_entities {
-                ensure!(
-                    new_related_entities
-                        .iter()
-                        .all(Entities::<T, I>::contains_key),
-                    Error::<T, I>::EntityRelatedEntityNotFound
-                );
-                entity.related_to = Some(new_related_entities);
+                Self::ensure_valid_relations(Some(entity_id), &new_related_entities)?;
+                let new_related_to = Some(new_related_entities);
+                Self::index_relations(entity_id, &entity.related_to, &new_related_to);
+                entity.related_to = new_related_to;
             }
 
             if let Some(nft_item_id) = nft_item_id {
@@ -219,20 +300,152 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                     Some(nft_item_id),
                     nft_owner,
                     nft_item_config,
+                    &entity.metadata.features,
                 )?;
 
                 if let Some(collection_id) = collection_id {
                     entity.collection_id = Some(collection_id);
                     entity.item_id = Some(nft_item_id);
+                    EntityNftItemConfigs::<T, I>::set(entity_id, nft_item_config);
                 };
             }
 
+            let nft_minted = entity.item_id != old_entity.item_id;
+            if entity.metadata.url != old_entity.metadata.url || nft_minted {
+                EntityDeposits::<T, I>::try_mutate(entity_id, |deposit| -> DispatchResult {
+                    *deposit = Self::update_deposit(
+                        deposit.take(),
+                        &origin,
+                        Self::entity_deposit(entity),
+                    )?;
+                    Ok(())
+                })?;
+            }
+            if entity.metadata.url != old_entity.metadata.url
+                || entity.owner != old_entity.owner
+                || nft_minted
+            {
+                Self::mirror_entity_nft(entity_id, entity)?;
+            }
+
+            Self::index_entity(entity_id, Some(&old_entity), Some(entity));
+            Self::bump_revision(entity_id, entity, &origin)?;
+
             Self::deposit_event(Event::EntityEdited { entity_id });
 
             Ok(())
         })
     }
 
+    /// Removes an entity from the storage.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditEntity` access right for the authority owning the entity.
+    /// - No other entity derives from the entity and the entity is not disputed or taken down, a taken down entity
+    ///   is kept so that its content hash and its identifiers stay reserved.
+    /// - The entity did not issue royalty shares, their asset is administered by the pallet and their holders
+    ///   keep receiving the royalties of the entity.
+    /// - The entity is removed from the `Derivatives`, `EntitiesByAuthority` and `EntitiesByAuthor` storages.
+    /// - The license offers, the pending transfer offer, the resale royalty, the received royalties and the NFT
+    ///   listing of the entity are removed, already purchased licenses are kept.
+    /// - The content hash and the identifiers of the entity are released from the `EntitiesByContentHash`
+    ///   and `EntitiesByIdentifier` storages.
+    /// - A release is removed once it has no track, a track is removed once it is withdrawn from its releases.
+    /// - The deposit of the entity is released and its revisions are removed.
+    /// - The NFT linked to the entity is kept by its holder, the data mirrored into it is cleared
+    ///   and it can be traded in the NFTs pallet again.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the entity to be removed.
+    /// - `currency_count`: The witness of the number of currencies the entity received royalties in.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityHasDerivatives` if other entities still derive from the entity.
+    /// - Returns `Error::<T, I>::EntityHasRoyaltyShares` if the entity issued royalty shares.
+    /// - Returns `Error::<T, I>::ReleaseHasTracks` if the entity is a release that still has tracks.
+    /// - Returns `Error::<T, I>::EntityInRelease` if the entity is still a track of a release.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::BadWitness` if the entity received royalties in more currencies than `currency_count`.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit the entity.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityRemoved` with the `entity_id` of the removed entity.
+    pub(crate) fn delete_entity(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+        currency_count: u32,
+    ) -> DispatchResult {
+        let entity = Self::get_entity(entity_id)?;
+
+        Self::ensure_access_right(
+            &origin,
+            &entity.owner,
+            AuthorityAccessSetting::EditEntity.into(),
+        )?;
+
+        ensure!(
+            Derivatives::<T, I>::iter_key_prefix(entity_id)
+                .next()
+                .is_none(),
+            Error::<T, I>::EntityHasDerivatives
+        );
+        ensure!(
+            !ReleaseTracks::<T, I>::contains_key(entity_id),
+            Error::<T, I>::ReleaseHasTracks
+        );
+        ensure!(
+            TrackReleases::<T, I>::iter_key_prefix(entity_id)
+                .next()
+                .is_none(),
+            Error::<T, I>::EntityInRelease
+        );
+        Self::ensure_not_frozen(entity_id)?;
+        ensure!(
+            !EntityRoyaltyShares::<T, I>::contains_key(entity_id),
+            Error::<T, I>::EntityHasRoyaltyShares
+        );
+
+        let currencies: Vec<PaymentCurrencyFor<T, I>> =
+            RoyaltiesReceived::<T, I>::iter_key_prefix(entity_id)
+                .take(currency_count.saturating_add(1) as usize)
+                .collect();
+        ensure!(
+            currencies.len() <= currency_count as usize,
+            Error::<T, I>::BadWitness
+        );
+
+        if let (Some(collection_id), Some(item_id)) = (entity.collection_id, entity.item_id) {
+            Self::clear_entity_nft(&collection_id, &item_id)?;
+            Self::unlock_entity_nft(&collection_id, &item_id)?;
+        }
+        Self::index_relations(entity_id, &entity.related_to, &None);
+        Self::index_entity(entity_id, Some(&entity), None);
+        LicenseOffers::<T, I>::remove(entity_id);
+        NextLicenseOfferId::<T, I>::remove(entity_id);
+        EntityTransfers::<T, I>::remove(entity_id);
+        EntityResaleRoyalties::<T, I>::remove(entity_id);
+        for currency in currencies {
+            RoyaltiesReceived::<T, I>::remove(entity_id, currency);
+        }
+        NftListings::<T, I>::remove(entity_id);
+        EntityNftItemConfigs::<T, I>::remove(entity_id);
+        if let Some(content) = entity.content {
+            EntitiesByContentHash::<T, I>::remove(content.content_hash);
+        }
+        for identifier in entity.identifiers.iter() {
+            EntitiesByIdentifier::<T, I>::remove(identifier);
+        }
+        Self::release_deposit(EntityDeposits::<T, I>::take(entity_id))?;
+        Self::clear_revisions(entity_id);
+        Entities::<T, I>::remove(entity_id);
+
+        Self::deposit_event(Event::EntityRemoved { entity_id });
+
+        Ok(())
+    }
+
     /// Retrieves the details of an entity from the storage.
     ///
     /// # It ensures
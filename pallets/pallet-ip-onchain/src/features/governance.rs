@@ -0,0 +1,389 @@
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Opts an authority into a threshold policy.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditAccess` access right for the authority.
+    /// - The authority has no policy yet, an existing policy is only changed by an approved proposal.
+    /// - The policy is written by `write_policy`.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `policy`: The threshold policy of the authority.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthorityNotFound` if the authority does not exist in the storage.
+    /// - Returns `Error::<T, I>::ProposalRequired` if the authority already has a policy.
+    /// - Returns `Error::<T, I>::PolicyThresholdInvalid` if the threshold can not be reached.
+    /// - Returns `Error::<T, I>::PolicyActionsInvalid` if the policy does not govern access changes.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit access.
+    ///
+    /// # Events
+    /// - Emits `Event::AuthorityPolicySet` with the `authority_id` and the `policy`.
+    pub(crate) fn set_policy(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+        policy: AuthorityPolicy,
+    ) -> DispatchResult {
+        Self::get_authority(authority_id)?;
+        Self::ensure_access_right(
+            &origin,
+            &authority_id,
+            AuthorityAccessSetting::EditAccess.into(),
+        )?;
+        ensure!(
+            !AuthorityPolicies::<T, I>::contains_key(authority_id),
+            Error::<T, I>::ProposalRequired
+        );
+
+        Self::write_policy(authority_id, Some(policy))
+    }
+
+    /// Writes or removes the threshold policy of an authority.
+    ///
+    /// # It ensures
+    /// - The threshold needs at least two approvals, at most `MaxPolicyThreshold`, and no more
+    ///   than the accounts currently holding the `EditAccess` right.
+    /// - The policy governs access changes, otherwise a single holder could grant the access rights
+    ///   needed to act alone on the other governed actions. A policy governing no action is rejected as well.
+    /// - The pending proposals are evaluated against the new policy once they are approved again.
+    ///
+    /// # Parameters
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `policy`: The new policy, `None` to let single accounts act alone again.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::PolicyThresholdInvalid` if the threshold is out of bounds.
+    /// - Returns `Error::<T, I>::PolicyActionsInvalid` if the policy does not govern access changes.
+    ///
+    /// # Events
+    /// - Emits `Event::AuthorityPolicySet` with the `authority_id` and the `policy`.
+    pub(crate) fn write_policy(
+        authority_id: T::AuthorityId,
+        policy: Option<AuthorityPolicy>,
+    ) -> DispatchResult {
+        match policy {
+            Some(policy) => {
+                ensure!(
+                    policy.threshold >= 2
+                        && policy.threshold <= T::MaxPolicyThreshold::get()
+                        && policy.threshold <= EditAccessHolders::<T, I>::get(authority_id),
+                    Error::<T, I>::PolicyThresholdInvalid
+                );
+                ensure!(
+                    policy.actions.has_action(GovernedAction::AccessChange),
+                    Error::<T, I>::PolicyActionsInvalid
+                );
+                AuthorityPolicies::<T, I>::insert(authority_id, policy);
+            }
+            None => AuthorityPolicies::<T, I>::remove(authority_id),
+        }
+
+        Self::deposit_event(Event::AuthorityPolicySet {
+            authority_id,
+            policy,
+        });
+
+        Ok(())
+    }
+
+    /// Proposes an action of an authority governed by its policy.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditAccess` access right for the authority and counts as the first approval.
+    /// - The authority has a policy, the proposal executes once it reaches the policy threshold.
+    /// - The authority has less than `MaxAuthorityProposals` pending proposals.
+    /// - The `ProposalDeposit` is held from the proposer until the proposal is executed or cancelled.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the proposer, it holds the deposit of the proposal and of a new access
+    ///   and receives the price of an offered entity.
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `action`: The proposed action.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthorityPolicyNotFound` if the authority has no policy.
+    /// - Returns `Error::<T, I>::LimitExceeded` if the authority has `MaxAuthorityProposals` pending proposals.
+    /// - Returns `Error::<T, I>::Overflow` if the proposal ID can not be incremented.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit access.
+    /// - Returns an error if the proposer can not afford the deposit.
+    ///
+    /// # Events
+    /// - Emits `Event::ProposalCreated` with the `authority_id`, the `proposal_id` and the proposer.
+    pub(crate) fn add_proposal(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+        action: AuthorityActionFor<T, I>,
+    ) -> DispatchResult {
+        Self::ensure_access_right(
+            &origin,
+            &authority_id,
+            AuthorityAccessSetting::EditAccess.into(),
+        )?;
+        ensure!(
+            AuthorityPolicies::<T, I>::contains_key(authority_id),
+            Error::<T, I>::AuthorityPolicyNotFound
+        );
+        let pending = PendingProposals::<T, I>::get(authority_id);
+        ensure!(
+            pending < T::MaxAuthorityProposals::get(),
+            Error::<T, I>::LimitExceeded
+        );
+
+        let proposal_id = NextProposalId::<T, I>::get(authority_id);
+        NextProposalId::<T, I>::insert(
+            authority_id,
+            proposal_id.checked_add(1).ok_or(Error::<T, I>::Overflow)?,
+        );
+
+        let mut approvals = BoundedVec::new();
+        approvals
+            .try_push(origin.clone())
+            .map_err(|_| Error::<T, I>::LimitExceeded)?;
+
+        Proposals::<T, I>::insert(
+            authority_id,
+            proposal_id,
+            AuthorityProposal {
+                proposer: origin.clone(),
+                action,
+                approvals,
+            },
+        );
+        PendingProposals::<T, I>::insert(authority_id, pending.saturating_add(1));
+        let deposit = Self::update_deposit(None, &origin, T::ProposalDeposit::get())?;
+        ProposalDeposits::<T, I>::set(authority_id, proposal_id, deposit);
+
+        Self::deposit_event(Event::ProposalCreated {
+            authority_id,
+            proposal_id,
+            proposer: origin,
+        });
+
+        Ok(())
+    }
+
+    /// Approves a pending proposal of an authority.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditAccess` access right for the authority and did not approve the proposal yet.
+    /// - Only the approvals of accounts still holding the `EditAccess` right count towards the threshold.
+    /// - Once the threshold of the policy is reached, the proposal is removed and its action executes
+    ///   with the proposer in place of the caller, a failing action reverts the approval.
+    /// - The deposit of an executed proposal is released to the proposer.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the approver.
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `proposal_id`: The unique identifier of the proposal for the authority.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::ProposalNotFound` if the proposal does not exist in the storage.
+    /// - Returns `Error::<T, I>::ProposalAlreadyApproved` if the caller already approved the proposal.
+    /// - Returns `Error::<T, I>::AuthorityPolicyNotFound` if the policy of the authority was removed.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit access.
+    /// - Returns the error of the action if it can not be executed.
+    ///
+    /// # Events
+    /// - Emits `Event::ProposalApproved` with the `authority_id`, the `proposal_id` and the approver.
+    /// - Emits `Event::ProposalExecuted` with the `authority_id` and the `proposal_id` once the action executed.
+    pub(crate) fn add_approval(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+        proposal_id: u32,
+    ) -> DispatchResult {
+        Self::ensure_access_right(
+            &origin,
+            &authority_id,
+            AuthorityAccessSetting::EditAccess.into(),
+        )?;
+
+        let mut proposal = Proposals::<T, I>::get(authority_id, proposal_id)
+            .ok_or(Error::<T, I>::ProposalNotFound)?;
+        let policy = AuthorityPolicies::<T, I>::get(authority_id)
+            .ok_or(Error::<T, I>::AuthorityPolicyNotFound)?;
+
+        ensure!(
+            !proposal.approvals.contains(&origin),
+            Error::<T, I>::ProposalAlreadyApproved
+        );
+        // Approvals of former holders are dropped to make room for the current ones.
+        proposal.approvals.retain(|approver| {
+            Self::ensure_access_right(
+                approver,
+                &authority_id,
+                AuthorityAccessSetting::EditAccess.into(),
+            )
+            .is_ok()
+        });
+        proposal
+            .approvals
+            .try_push(origin.clone())
+            .map_err(|_| Error::<T, I>::LimitExceeded)?;
+
+        Self::deposit_event(Event::ProposalApproved {
+            authority_id,
+            proposal_id,
+            approver: origin,
+        });
+
+        if (proposal.approvals.len() as u32) < policy.threshold {
+            Proposals::<T, I>::insert(authority_id, proposal_id, proposal);
+            return Ok(());
+        }
+
+        Self::take_proposal(authority_id, proposal_id)?;
+        Self::execute_action(authority_id, &proposal.proposer, proposal.action)?;
+
+        Self::deposit_event(Event::ProposalExecuted {
+            authority_id,
+            proposal_id,
+        });
+
+        Ok(())
+    }
+
+    /// Cancels a pending proposal of an authority.
+    ///
+    /// # It ensures
+    /// - Only the proposer can cancel the proposal.
+    /// - The deposit of the proposal is released to the proposer.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `proposal_id`: The unique identifier of the proposal for the authority.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::ProposalNotFound` if the proposal does not exist in the storage.
+    /// - Returns `Error::<T, I>::NotAuthorized` if the caller is not the proposer.
+    ///
+    /// # Events
+    /// - Emits `Event::ProposalCancelled` with the `authority_id` and the `proposal_id`.
+    pub(crate) fn remove_proposal(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+        proposal_id: u32,
+    ) -> DispatchResult {
+        let proposal = Proposals::<T, I>::get(authority_id, proposal_id)
+            .ok_or(Error::<T, I>::ProposalNotFound)?;
+        ensure!(proposal.proposer == origin, Error::<T, I>::NotAuthorized);
+
+        Self::take_proposal(authority_id, proposal_id)?;
+
+        Self::deposit_event(Event::ProposalCancelled {
+            authority_id,
+            proposal_id,
+        });
+
+        Ok(())
+    }
+
+    /// Removes a pending proposal of an authority and releases its deposit.
+    fn take_proposal(authority_id: T::AuthorityId, proposal_id: u32) -> DispatchResult {
+        Proposals::<T, I>::remove(authority_id, proposal_id);
+        PendingProposals::<T, I>::mutate(authority_id, |pending| {
+            *pending = pending.saturating_sub(1)
+        });
+        Self::release_deposit(ProposalDeposits::<T, I>::take(authority_id, proposal_id))
+    }
+
+    /// Executes an approved action of an authority.
+    ///
+    /// # It ensures
+    /// - Access changes hold the deposit of a new access from the proposer.
+    /// - Transfer offers and royalty edits only apply to entities owned by the authority,
+    ///   the proposer receives the price of an offered entity and is the editor of the new royalty split.
+    ///
+    /// # Parameters
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `proposer`: The account ID that proposed the action.
+    /// - `action`: The approved action.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::NotAuthorized` if the entity is not owned by the authority.
+    /// - Returns the errors of `change_access`, `write_transfer_offer`, `write_policy`
+    ///   and of the royalty split validation.
+    fn execute_action(
+        authority_id: T::AuthorityId,
+        proposer: &T::AccountId,
+        action: AuthorityActionFor<T, I>,
+    ) -> DispatchResult {
+        match action {
+            AuthorityAction::SetAccess {
+                account_id,
+                access,
+                expires_at,
+            } => Self::change_access(proposer, authority_id, account_id, access, expires_at),
+            AuthorityAction::OfferTransfer {
+                entity_id,
+                to_authority,
+                price,
+                currency,
+            } => {
+                let entity = Self::get_entity(entity_id)?;
+                ensure!(entity.owner == authority_id, Error::<T, I>::NotAuthorized);
+
+                Self::write_transfer_offer(
+                    entity_id,
+                    &entity,
+                    to_authority,
+                    price,
+                    currency,
+                    proposer.clone(),
+                )
+            }
+            AuthorityAction::SetRoyaltyParts {
+                entity_id,
+                royalty_parts,
+            } => Entities::<T, I>::try_mutate(entity_id, |maybe_entity| -> DispatchResult {
+                let entity = maybe_entity.as_mut().ok_or(Error::<T, I>::EntityNotFound)?;
+                ensure!(entity.owner == authority_id, Error::<T, I>::NotAuthorized);
+                Self::ensure_not_frozen(entity_id)?;
+
+                Self::ensure_valid_royalty_parts(&royalty_parts)?;
+                entity.royalty_parts = Some(royalty_parts);
+                Self::bump_revision(entity_id, entity, proposer)?;
+
+                Self::deposit_event(Event::EntityEdited { entity_id });
+
+                Ok(())
+            }),
+            AuthorityAction::SetPolicy(policy) => Self::write_policy(authority_id, policy),
+            AuthorityAction::SetResaleRoyalty { entity_id, royalty } => {
+                Self::write_resale_royalty(authority_id, entity_id, royalty)
+            }
+        }
+    }
+
+    /// Validates that an authority lets a single account perform an action.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::ProposalRequired` if the policy of the authority governs the action.
+    pub(crate) fn ensure_not_governed(
+        authority_id: &T::AuthorityId,
+        action: GovernedAction,
+    ) -> DispatchResult {
+        ensure!(
+            !AuthorityPolicies::<T, I>::get(authority_id)
+                .is_some_and(|policy| policy.actions.has_action(action)),
+            Error::<T, I>::ProposalRequired
+        );
+        Ok(())
+    }
+
+    /// Retrieves the threshold policy of an authority.
+    pub fn get_authority_policy(authority_id: T::AuthorityId) -> Option<AuthorityPolicy> {
+        AuthorityPolicies::<T, I>::get(authority_id)
+    }
+
+    /// Retrieves a pending proposal of an authority.
+    pub fn get_proposal(
+        authority_id: T::AuthorityId,
+        proposal_id: u32,
+    ) -> Option<AuthorityProposalFor<T, I>> {
+        Proposals::<T, I>::get(authority_id, proposal_id)
+    }
+}
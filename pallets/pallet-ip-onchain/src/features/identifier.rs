@@ -0,0 +1,139 @@
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Sets the industry identifiers of an entity.
+    ///
+    /// # It ensures
+    /// - The entity with the given `entity_id` exists in the storage and is not frozen by a dispute or a takedown.
+    /// - The caller has the `EditEntity` access right for the authority owning the entity.
+    /// - Every identifier has a valid format and is not registered by another entity.
+    /// - Keeps the `EntitiesByIdentifier` storage in sync with the identifiers of the entity.
+    /// - The new identifiers are kept as the next revision of the entity with the caller as its editor.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `identifiers`: The new identifiers, replacing all previous identifiers of the entity.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::IdentifierInvalid` if an identifier does not have a valid format.
+    /// - Returns `Error::<T, I>::IdentifierAlreadyRegistered` if another entity registered an identifier.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit the entity.
+    /// - Returns `Error::<T, I>::Overflow` if the revision number can not be incremented.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityIdentifiersSet` with the `entity_id`.
+    pub(crate) fn set_identifiers(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+        identifiers: EntityIdentifiers,
+    ) -> DispatchResult {
+        Entities::<T, I>::try_mutate(entity_id, |maybe_entity| -> DispatchResult {
+            let entity = maybe_entity.as_mut().ok_or(Error::<T, I>::EntityNotFound)?;
+            Self::ensure_not_frozen(entity_id)?;
+
+            Self::ensure_access_right(
+                &origin,
+                &entity.owner,
+                AuthorityAccessSetting::EditEntity.into(),
+            )?;
+
+            for identifier in identifiers.iter() {
+                ensure!(identifier.is_valid(), Error::<T, I>::IdentifierInvalid);
+                ensure!(
+                    EntitiesByIdentifier::<T, I>::get(identifier)
+                        .map_or(true, |registered_by| registered_by == entity_id),
+                    Error::<T, I>::IdentifierAlreadyRegistered
+                );
+            }
+
+            for identifier in entity.identifiers.iter() {
+                EntitiesByIdentifier::<T, I>::remove(identifier);
+            }
+            for identifier in identifiers.iter() {
+                EntitiesByIdentifier::<T, I>::insert(identifier, entity_id);
+            }
+            entity.identifiers = identifiers;
+            Self::bump_revision(entity_id, entity, &origin)?;
+
+            Self::deposit_event(Event::EntityIdentifiersSet { entity_id });
+
+            Ok(())
+        })
+    }
+
+    /// Sets the IPI name number of an author.
+    ///
+    /// # It ensures
+    /// - The author with the given `author_id` exists in the storage.
+    /// - Only the owner of the author can set its IPI name number, once it claimed the author.
+    /// - The IPI name number has a valid format and is not registered by another author.
+    /// - A verified author falls back to `Claimed` when its IPI name number changes.
+    /// - Keeps the `AuthorsByIpi` storage in sync with the IPI name number of the author.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `author_id`: The unique identifier of the author.
+    /// - `ipi`: The new IPI name number, `None` to clear it.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthorNotFound` if the author with the given `author_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::NoPermission` if the caller is not the owner of the author.
+    /// - Returns `Error::<T, I>::AuthorNotClaimed` if the owner did not claim the author yet.
+    /// - Returns `Error::<T, I>::IdentifierInvalid` if the IPI name number does not have a valid format.
+    /// - Returns `Error::<T, I>::IdentifierAlreadyRegistered` if another author registered the IPI name number.
+    ///
+    /// # Events
+    /// - Emits `Event::AuthorIpiSet` with the `author_id` and the new IPI name number.
+    pub(crate) fn set_ipi(
+        origin: T::AccountId,
+        author_id: T::AuthorId,
+        ipi: Option<Ipi>,
+    ) -> DispatchResult {
+        Authors::<T, I>::try_mutate(author_id, |maybe_author| -> DispatchResult {
+            let author = maybe_author.as_mut().ok_or(Error::<T, I>::AuthorNotFound)?;
+
+            Self::ensure_author_owner(&origin, author)?;
+
+            if let Some(new_ipi) = &ipi {
+                ensure!(new_ipi.is_valid(), Error::<T, I>::IdentifierInvalid);
+                ensure!(
+                    AuthorsByIpi::<T, I>::get(new_ipi)
+                        .map_or(true, |registered_by| registered_by == author_id),
+                    Error::<T, I>::IdentifierAlreadyRegistered
+                );
+            }
+
+            if author.ipi != ipi {
+                if let Some(old_ipi) = author.ipi.take() {
+                    AuthorsByIpi::<T, I>::remove(old_ipi);
+                }
+                if let Some(new_ipi) = &ipi {
+                    AuthorsByIpi::<T, I>::insert(new_ipi, author_id);
+                }
+                author.ipi = ipi;
+                author.updated_at = frame_system::Pallet::<T>::block_number();
+
+                if author.status == AuthorStatus::Verified {
+                    author.status = AuthorStatus::Claimed;
+                }
+            }
+
+            Self::deposit_event(Event::AuthorIpiSet { author_id, ipi });
+
+            Ok(())
+        })
+    }
+
+    /// Retrieves the entity that registered an industry identifier.
+    pub fn get_entity_by_identifier(identifier: IndustryIdentifier) -> Option<T::EntityId> {
+        EntitiesByIdentifier::<T, I>::get(identifier)
+    }
+
+    /// Retrieves the author that registered an IPI name number.
+    pub fn get_author_by_ipi(ipi: Ipi) -> Option<T::AuthorId> {
+        AuthorsByIpi::<T, I>::get(ipi)
+    }
+}
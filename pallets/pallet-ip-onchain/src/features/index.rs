@@ -0,0 +1,113 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Keeps the `EntitiesByAuthority`, `EntitiesByAuthor` and `EntitiesByNft` indexes in sync with an entity.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `old_entity`: The details of the entity before the change, `None` for a new entity.
+    /// - `new_entity`: The details of the entity after the change, `None` for a removed entity.
+    pub(crate) fn index_entity(
+        entity_id: T::EntityId,
+        old_entity: Option<&EntityDetailsFor<T, I>>,
+        new_entity: Option<&EntityDetailsFor<T, I>>,
+    ) {
+        if let Some(old_entity) = old_entity {
+            EntitiesByAuthority::<T, I>::remove(old_entity.owner, entity_id);
+            for author_id in old_entity.authors.iter().flatten() {
+                EntitiesByAuthor::<T, I>::remove(author_id, entity_id);
+            }
+            if let (Some(collection_id), Some(item_id)) =
+                (old_entity.collection_id, old_entity.item_id)
+            {
+                EntitiesByNft::<T, I>::remove(collection_id, item_id);
+            }
+        }
+
+        if let Some(new_entity) = new_entity {
+            EntitiesByAuthority::<T, I>::insert(new_entity.owner, entity_id, ());
+            for author_id in new_entity.authors.iter().flatten() {
+                EntitiesByAuthor::<T, I>::insert(author_id, entity_id, ());
+            }
+            if let (Some(collection_id), Some(item_id)) =
+                (new_entity.collection_id, new_entity.item_id)
+            {
+                EntitiesByNft::<T, I>::insert(collection_id, item_id, entity_id);
+            }
+        }
+    }
+
+    /// Keeps the `AuthorsByOwner` index in sync with the owner of an author.
+    ///
+    /// # Parameters
+    /// - `author_id`: The unique identifier of the author.
+    /// - `old_owner`: The owner of the author before the change, `None` for a new author.
+    /// - `new_owner`: The owner of the author after the change, `None` for a removed author.
+    pub(crate) fn index_author(
+        author_id: T::AuthorId,
+        old_owner: Option<&T::AccountId>,
+        new_owner: Option<&T::AccountId>,
+    ) {
+        if let Some(old_owner) = old_owner {
+            AuthorsByOwner::<T, I>::remove(old_owner, author_id);
+        }
+        if let Some(new_owner) = new_owner {
+            AuthorsByOwner::<T, I>::insert(new_owner, author_id, ());
+        }
+    }
+
+    /// Retrieves the entities owned by an authority.
+    ///
+    /// # It ensures
+    /// - The function performs a read-only operation and does not modify the storage.
+    ///
+    /// # Parameters
+    /// - `authority_id`: The unique identifier of the authority.
+    ///
+    /// # Returns
+    /// - A vector of the IDs of the entities owned by the authority.
+    pub fn get_entities_of_authority(authority_id: T::AuthorityId) -> Vec<T::EntityId> {
+        EntitiesByAuthority::<T, I>::iter_key_prefix(authority_id).collect()
+    }
+
+    /// Retrieves the entities an author is credited on.
+    ///
+    /// # It ensures
+    /// - The function performs a read-only operation and does not modify the storage.
+    ///
+    /// # Parameters
+    /// - `author_id`: The unique identifier of the author.
+    ///
+    /// # Returns
+    /// - A vector of the IDs of the entities listing the author in their `authors`.
+    pub fn get_entities_of_author(author_id: T::AuthorId) -> Vec<T::EntityId> {
+        EntitiesByAuthor::<T, I>::iter_key_prefix(author_id).collect()
+    }
+
+    /// Retrieves everything an account controls.
+    ///
+    /// # It ensures
+    /// - The function performs a read-only operation and does not modify the storage.
+    ///
+    /// # Parameters
+    /// - `account_id`: The account ID to look up.
+    ///
+    /// # Returns
+    /// - `AccountOverview` with the authors owned by the account and the authorities it has access to,
+    ///   together with its access settings, expired accesses are left out.
+    pub fn get_account_overview(
+        account_id: T::AccountId,
+    ) -> AccountOverview<T::AuthorId, T::AuthorityId> {
+        AccountOverview {
+            authors: AuthorsByOwner::<T, I>::iter_key_prefix(&account_id).collect(),
+            authorities: AccessByAccount::<T, I>::iter_key_prefix(&account_id)
+                .filter_map(|authority_id| {
+                    Self::get_access(authority_id, &account_id).map(|access| (authority_id, access))
+                })
+                .collect(),
+        }
+    }
+}
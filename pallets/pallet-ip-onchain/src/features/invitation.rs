@@ -0,0 +1,161 @@
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Invites an account to an authority, the access is granted once the account accepts it.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditAccess` access right for the authority and access changes are not governed.
+    /// - The account has no access and no pending invitation for the authority, an expired access is pruned first.
+    /// - The deposit of the access is held from the caller, it stays held once the invitation is accepted.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `account_id`: The unique identifier of the invited account.
+    /// - `access`: The access settings granted on acceptance.
+    /// - `expires_at`: The block from which the accepted access is treated as absent, `None` for a permanent access.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthoritiesAccessExist` if the account already has access to the authority.
+    /// - Returns `Error::<T, I>::AccessInvitationPending` if the account is already invited to the authority.
+    /// - Returns `Error::<T, I>::ProposalRequired` if the authority governs access changes by its policy.
+    /// - Returns an expiry error if the `expires_at` block is not valid, see `write_expiry`.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit access.
+    /// - Returns an error if the caller can not afford the deposit.
+    ///
+    /// # Events
+    /// - Emits `Event::AccessInvited` with the `authority_id` and `account_id`.
+    pub(crate) fn add_invitation(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+        account_id: T::AccountId,
+        access: AuthorityAccessSettings,
+        expires_at: Option<BlockNumberFor<T>>,
+    ) -> DispatchResult {
+        Self::ensure_access_right(
+            &origin,
+            &authority_id,
+            AuthorityAccessSetting::EditAccess.into(),
+        )?;
+        Self::ensure_not_governed(&authority_id, GovernedAction::AccessChange)?;
+
+        Self::prune_expired_access(authority_id, &account_id)?;
+        ensure!(
+            !AuthoritiesAccess::<T, I>::contains_key(authority_id, &account_id),
+            Error::<T, I>::AuthoritiesAccessExist
+        );
+        ensure!(
+            !AccessInvitations::<T, I>::contains_key(authority_id, &account_id),
+            Error::<T, I>::AccessInvitationPending
+        );
+        if let Some(expires_at) = expires_at {
+            Self::ensure_valid_expiry(access, expires_at)?;
+        }
+
+        let deposit = Self::update_deposit(None, &origin, T::AccessDeposit::get())?;
+        AccessDeposits::<T, I>::set(authority_id, &account_id, deposit);
+        AccessInvitations::<T, I>::insert(
+            authority_id,
+            &account_id,
+            AccessInvitation { access, expires_at },
+        );
+
+        Self::deposit_event(Event::AccessInvited {
+            authority_id,
+            account_id,
+        });
+
+        Ok(())
+    }
+
+    /// Accepts the invitation of the caller to an authority.
+    ///
+    /// # It ensures
+    /// - The invited access is written with its expiry, see `write_access` and `write_expiry`.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the invited account.
+    /// - `authority_id`: The unique identifier of the authority.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AccessInvitationNotFound` if the caller is not invited to the authority.
+    /// - Returns `Error::<T, I>::AccessExpiryInvalid` if the access expired before it was accepted.
+    ///
+    /// # Events
+    /// - Emits `Event::AuthoritiesAccessAdded` with the `authority_id` and `account_id`.
+    pub(crate) fn accept_invitation(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+    ) -> DispatchResult {
+        let invitation = AccessInvitations::<T, I>::take(authority_id, &origin)
+            .ok_or(Error::<T, I>::AccessInvitationNotFound)?;
+
+        Self::write_access(authority_id, &origin, Some(invitation.access))?;
+        Self::write_expiry(
+            authority_id,
+            &origin,
+            invitation.access,
+            invitation.expires_at,
+        )?;
+
+        Self::deposit_event(Event::AuthoritiesAccessAdded {
+            authority_id,
+            account_id: origin,
+        });
+
+        Ok(())
+    }
+
+    /// Cancels or declines a pending invitation to an authority.
+    ///
+    /// # It ensures
+    /// - The caller is the invited account, or has the `EditAccess` access right for the authority.
+    /// - The deposit of the invitation is released to its depositor.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `account_id`: The unique identifier of the invited account.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AccessInvitationNotFound` if the account is not invited to the authority.
+    /// - Returns an access control error if the caller is neither the invited account nor can edit access.
+    ///
+    /// # Events
+    /// - Emits `Event::AccessInvitationCancelled` with the `authority_id` and `account_id`.
+    pub(crate) fn remove_invitation(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+        account_id: T::AccountId,
+    ) -> DispatchResult {
+        ensure!(
+            AccessInvitations::<T, I>::contains_key(authority_id, &account_id),
+            Error::<T, I>::AccessInvitationNotFound
+        );
+        if origin != account_id {
+            Self::ensure_access_right(
+                &origin,
+                &authority_id,
+                AuthorityAccessSetting::EditAccess.into(),
+            )?;
+        }
+
+        AccessInvitations::<T, I>::remove(authority_id, &account_id);
+        Self::release_deposit(AccessDeposits::<T, I>::take(authority_id, &account_id))?;
+
+        Self::deposit_event(Event::AccessInvitationCancelled {
+            authority_id,
+            account_id,
+        });
+
+        Ok(())
+    }
+
+    /// Retrieves the pending invitation of an account to an authority.
+    pub fn get_access_invitation(
+        authority_id: T::AuthorityId,
+        account_id: &T::AccountId,
+    ) -> Option<AccessInvitationFor<T>> {
+        AccessInvitations::<T, I>::get(authority_id, account_id)
+    }
+}
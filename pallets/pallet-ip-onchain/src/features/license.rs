@@ -0,0 +1,281 @@
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Attaches a new license offer to an entity.
+    ///
+    /// # It ensures
+    /// - The entity with the given `entity_id` exists in the storage and is not frozen by a dispute or a takedown.
+    /// - The caller has the `EditEntity` access right for the authority owning the entity.
+    /// - A paid offer is only attached to an entity whose royalties have recipients.
+    /// - The currency of the offer is one of the accepted payment currencies.
+    /// - The offer gets the next offer id of the entity, ids are never reused.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the entity the offer is attached to.
+    /// - `offer`: The license offer with its kind, price, currency, duration and territory.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::EntityRoyaltyPartsNotFound` if the offer is paid and the entity has no royalty recipients.
+    /// - Returns `Error::<T, I>::PaymentCurrencyNotSupported` if the currency of the offer is not accepted.
+    /// - Returns `Error::<T, I>::LimitExceeded` if the entity already has the maximum number of license offers.
+    /// - Returns `Error::<T, I>::Overflow` if the offer ids of the entity are exhausted.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit the entity.
+    ///
+    /// # Events
+    /// - Emits `Event::LicenseOfferAdded` with the `entity_id` and the id of the new offer.
+    pub(crate) fn add_new_license_offer(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+        offer: LicenseOfferFor<T, I>,
+    ) -> DispatchResult {
+        let entity = Self::get_entity(entity_id)?;
+        Self::ensure_access_right(
+            &origin,
+            &entity.owner,
+            AuthorityAccessSetting::EditEntity.into(),
+        )?;
+        Self::ensure_not_frozen(entity_id)?;
+        ensure!(
+            offer.price.is_zero() || Self::has_royalty_recipients(entity_id, &entity),
+            Error::<T, I>::EntityRoyaltyPartsNotFound
+        );
+        Self::ensure_payment_currency(&offer.currency)?;
+
+        LicenseOffers::<T, I>::try_mutate(entity_id, |offers| -> DispatchResult {
+            let offer_id = NextLicenseOfferId::<T, I>::get(entity_id);
+            let next_offer_id = offer_id.checked_add(1).ok_or(Error::<T, I>::Overflow)?;
+
+            offers
+                .try_push((offer_id, offer))
+                .map_err(|_| Error::<T, I>::LimitExceeded)?;
+            NextLicenseOfferId::<T, I>::insert(entity_id, next_offer_id);
+
+            Self::deposit_event(Event::LicenseOfferAdded {
+                entity_id,
+                offer_id,
+            });
+
+            Ok(())
+        })
+    }
+
+    /// Removes a license offer from an entity.
+    ///
+    /// # It ensures
+    /// - The entity with the given `entity_id` exists in the storage.
+    /// - The caller has the `EditEntity` access right for the authority owning the entity.
+    /// - The ids of the other offers are not affected.
+    /// - Already purchased licenses are not affected.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `offer_id`: The id of the offer to remove.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::LicenseOfferNotFound` if the entity has no offer with the given `offer_id`.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit the entity.
+    ///
+    /// # Events
+    /// - Emits `Event::LicenseOfferRemoved` with the `entity_id` and the `offer_id`.
+    pub(crate) fn delete_license_offer(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+        offer_id: u32,
+    ) -> DispatchResult {
+        let entity = Self::get_entity(entity_id)?;
+        Self::ensure_access_right(
+            &origin,
+            &entity.owner,
+            AuthorityAccessSetting::EditEntity.into(),
+        )?;
+
+        LicenseOffers::<T, I>::try_mutate_exists(entity_id, |maybe_offers| -> DispatchResult {
+            let offers = maybe_offers
+                .as_mut()
+                .ok_or(Error::<T, I>::LicenseOfferNotFound)?;
+            let position = offers
+                .iter()
+                .position(|(id, _)| *id == offer_id)
+                .ok_or(Error::<T, I>::LicenseOfferNotFound)?;
+
+            offers.remove(position);
+            if offers.is_empty() {
+                *maybe_offers = None;
+            }
+
+            Self::deposit_event(Event::LicenseOfferRemoved {
+                entity_id,
+                offer_id,
+            });
+
+            Ok(())
+        })
+    }
+
+    /// Purchases a license offered for an entity.
+    ///
+    /// # It ensures
+    /// - The entity is not frozen by a dispute or a takedown.
+    /// - The price of the offer is paid by the buyer in the currency of the offer through the entity royalty split.
+    /// - A license record is stored for the buyer, expired licenses of the buyer for the entity are pruned.
+    /// - The buyer holds a `LicenseDeposit` for every license kept for the entity, free licenses included.
+    /// - The license expires `duration` blocks after the purchase, or never if the offer has no duration.
+    ///
+    /// # Parameters
+    /// - `buyer`: The account ID purchasing the license.
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `offer_id`: The id of the purchased offer.
+    /// - `max_price`: The maximum price the buyer agrees to pay.
+    /// - `currency`: The currency the buyer agrees to pay in, an offer priced in another currency is rejected.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::LicenseOfferNotFound` if the entity has no offer with the given `offer_id`.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::LicensePriceTooHigh` if the offer price is above `max_price`.
+    /// - Returns `Error::<T, I>::PaymentCurrencyMismatch` if the offer is priced in another currency than `currency`.
+    /// - Returns `Error::<T, I>::LimitExceeded` if the buyer already holds the maximum number of active licenses for the entity.
+    /// - Returns a royalty error if the payment can not be distributed.
+    /// - Returns an error if the buyer can not afford the deposit of the license.
+    ///
+    /// # Events
+    /// - Emits `Event::RoyaltyPaid` for every recipient of the payment.
+    /// - Emits `Event::LicensePurchased` with the `entity_id`, the buyer, the license kind, the price and the currency.
+    pub(crate) fn buy_license(
+        buyer: T::AccountId,
+        entity_id: T::EntityId,
+        offer_id: u32,
+        max_price: BalanceOf<T, I>,
+        currency: PaymentCurrencyFor<T, I>,
+    ) -> DispatchResult {
+        let (_, offer) = LicenseOffers::<T, I>::get(entity_id)
+            .into_iter()
+            .find(|(id, _)| *id == offer_id)
+            .ok_or(Error::<T, I>::LicenseOfferNotFound)?;
+        ensure!(offer.price <= max_price, Error::<T, I>::LicensePriceTooHigh);
+        ensure!(
+            offer.currency == currency,
+            Error::<T, I>::PaymentCurrencyMismatch
+        );
+        Self::ensure_not_frozen(entity_id)?;
+
+        if !offer.price.is_zero() {
+            Self::distribute_royalties(&buyer, entity_id, &offer.currency, offer.price)?;
+        }
+
+        let now = frame_system::Pallet::<T>::block_number();
+
+        let mut licenses = Licenses::<T, I>::get(&buyer, entity_id);
+        licenses.retain(|license| license.is_active(&now));
+        licenses
+            .try_push(License {
+                kind: offer.kind,
+                price: offer.price,
+                currency: offer.currency.clone(),
+                territory: offer.territory.clone(),
+                purchased_at: now,
+                expires_at: offer.duration.map(|duration| now.saturating_add(duration)),
+            })
+            .map_err(|_| Error::<T, I>::LimitExceeded)?;
+        Self::store_licenses(&buyer, entity_id, licenses)?;
+
+        Self::deposit_event(Event::LicensePurchased {
+            entity_id,
+            buyer,
+            kind: offer.kind,
+            price: offer.price,
+            currency: offer.currency,
+        });
+
+        Ok(())
+    }
+
+    /// Removes the expired licenses an account holds for an entity.
+    ///
+    /// # It ensures
+    /// - The active licenses of the caller are kept, the deposits of the removed ones are released.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the license holder.
+    /// - `entity_id`: The unique identifier of the entity.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::LicenseNotFound` if the caller holds no expired license for the entity.
+    ///
+    /// # Events
+    /// - Emits `Event::LicensesPruned` with the `entity_id`, the holder and the number of removed licenses.
+    pub(crate) fn prune_expired_licenses(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+    ) -> DispatchResult {
+        let now = frame_system::Pallet::<T>::block_number();
+
+        let mut licenses = Licenses::<T, I>::get(&origin, entity_id);
+        let count = licenses.len();
+        licenses.retain(|license| license.is_active(&now));
+        let pruned = (count - licenses.len()) as u32;
+        ensure!(pruned > 0, Error::<T, I>::LicenseNotFound);
+
+        Self::store_licenses(&origin, entity_id, licenses)?;
+
+        Self::deposit_event(Event::LicensesPruned {
+            entity_id,
+            holder: origin,
+            pruned,
+        });
+
+        Ok(())
+    }
+
+    /// Stores the licenses an account holds for an entity and holds their deposit, the record is
+    /// removed with its deposit once no license is left.
+    fn store_licenses(
+        holder: &T::AccountId,
+        entity_id: T::EntityId,
+        licenses: BoundedVec<LicenseFor<T, I>, T::MaxHolderLicenses>,
+    ) -> DispatchResult {
+        let old_deposit = LicenseDeposits::<T, I>::take(holder, entity_id);
+
+        if licenses.is_empty() {
+            Self::release_deposit(old_deposit)?;
+            Licenses::<T, I>::remove(holder, entity_id);
+            return Ok(());
+        }
+
+        let amount = T::LicenseDeposit::get().saturating_mul((licenses.len() as u32).into());
+        if let Some(deposit) = Self::update_deposit(old_deposit, holder, amount)? {
+            LicenseDeposits::<T, I>::insert(holder, entity_id, deposit);
+        }
+        Licenses::<T, I>::insert(holder, entity_id, licenses);
+
+        Ok(())
+    }
+
+    /// Checks whether an account holds an active license of the given kind for an entity.
+    ///
+    /// # It ensures
+    /// - The function performs a read-only operation and does not modify the storage.
+    ///
+    /// # Parameters
+    /// - `account_id`: The account ID of the license holder.
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `kind`: The kind of the license.
+    ///
+    /// # Returns
+    /// - `true` if the account holds a license of the given kind that has not expired yet.
+    pub fn is_licensed(
+        account_id: T::AccountId,
+        entity_id: T::EntityId,
+        kind: LicenseKind,
+    ) -> bool {
+        let now = frame_system::Pallet::<T>::block_number();
+
+        Licenses::<T, I>::get(account_id, entity_id)
+            .iter()
+            .any(|license| license.kind == kind && license.is_active(&now))
+    }
+}
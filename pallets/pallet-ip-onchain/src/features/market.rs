@@ -0,0 +1,382 @@
+use crate::*;
+
+use frame::traits::tokens::{nonfungibles_v2::Inspect, Preservation};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Sets or removes the resale royalty of an authority or of one of its entities.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditAuthority` access right for the authority, or the `EditEntity` access right
+    ///   if the royalty of an entity is set.
+    /// - The authority does not require a proposal for royalty edits.
+    /// - The royalty is written by `write_resale_royalty`.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `entity_id`: The unique identifier of an entity of the authority, `None` to set the royalty of the authority.
+    /// - `royalty`: The share of the price of every sale, `None` to remove the royalty.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::ProposalRequired` if the authority governs royalty edits by its policy.
+    /// - Returns an access control error if the caller does not have the necessary rights.
+    /// - Returns an error if the royalty can not be written, see `write_resale_royalty`.
+    ///
+    /// # Events
+    /// - Emits `Event::ResaleRoyaltySet` with the `authority_id`, the `entity_id` and the `royalty`.
+    pub(crate) fn change_resale_royalty(
+        origin: T::AccountId,
+        authority_id: T::AuthorityId,
+        entity_id: Option<T::EntityId>,
+        royalty: Option<Perbill>,
+    ) -> DispatchResult {
+        let access = if entity_id.is_some() {
+            AuthorityAccessSetting::EditEntity
+        } else {
+            AuthorityAccessSetting::EditAuthority
+        };
+        Self::ensure_access_right(&origin, &authority_id, access.into())?;
+        Self::ensure_not_governed(&authority_id, GovernedAction::RoyaltyEdit)?;
+
+        Self::write_resale_royalty(authority_id, entity_id, royalty)
+    }
+
+    /// Writes or removes the resale royalty of an authority or of one of its entities.
+    ///
+    /// # It ensures
+    /// - The royalty does not exceed `MaxResaleRoyalty`.
+    /// - The royalty of an entity overrides the royalty of its owning authority, the royalty of an authority
+    ///   applies to all of its entities without a royalty of their own.
+    /// - The entity is owned by the authority and is not frozen by a dispute or a takedown.
+    ///
+    /// # Parameters
+    /// - `authority_id`: The unique identifier of the authority.
+    /// - `entity_id`: The unique identifier of an entity of the authority, `None` to set the royalty of the authority.
+    /// - `royalty`: The share of the price of every sale, `None` to remove the royalty.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::ResaleRoyaltyTooHigh` if the royalty exceeds `MaxResaleRoyalty`.
+    /// - Returns `Error::<T, I>::AuthorityNotFound` if the authority does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity does not exist in the storage.
+    /// - Returns `Error::<T, I>::NotAuthorized` if the entity is not owned by the authority.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    ///
+    /// # Events
+    /// - Emits `Event::ResaleRoyaltySet` with the `authority_id`, the `entity_id` and the `royalty`.
+    pub(crate) fn write_resale_royalty(
+        authority_id: T::AuthorityId,
+        entity_id: Option<T::EntityId>,
+        royalty: Option<Perbill>,
+    ) -> DispatchResult {
+        ensure!(
+            royalty.map_or(true, |royalty| royalty <= T::MaxResaleRoyalty::get()),
+            Error::<T, I>::ResaleRoyaltyTooHigh
+        );
+
+        match entity_id {
+            Some(entity_id) => {
+                let entity = Self::get_entity(entity_id)?;
+                ensure!(entity.owner == authority_id, Error::<T, I>::NotAuthorized);
+                Self::ensure_not_frozen(entity_id)?;
+
+                EntityResaleRoyalties::<T, I>::set(entity_id, royalty);
+            }
+            None => {
+                Self::get_authority(authority_id)?;
+
+                AuthorityResaleRoyalties::<T, I>::set(authority_id, royalty);
+            }
+        }
+
+        Self::deposit_event(Event::ResaleRoyaltySet {
+            authority_id,
+            entity_id,
+            royalty,
+        });
+
+        Ok(())
+    }
+
+    /// Lists the NFT of an entity for sale.
+    ///
+    /// # It ensures
+    /// - The caller holds the NFT of the entity and the entity is not frozen by a dispute or a takedown.
+    /// - The price is not zero.
+    /// - The currency of the listing is one of the accepted payment currencies.
+    /// - A previous listing of the NFT is replaced.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller, it receives the price minus the resale royalty.
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `price`: The price of the NFT.
+    /// - `currency`: The currency the price and the resale royalty are paid in.
+    /// - `buyer`: The only account allowed to buy the NFT, `None` to let anyone buy it.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::ZeroAmount` if the price is zero.
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::EntityNftNotFound` if the entity has no NFT.
+    /// - Returns `Error::<T, I>::EntityNftHolderInvalid` if the caller does not hold the NFT.
+    /// - Returns `Error::<T, I>::PaymentCurrencyNotSupported` if the currency of the listing is not accepted.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityNftListed` with the `entity_id`, the seller, the price and the currency.
+    pub(crate) fn list_nft(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+        price: BalanceOf<T, I>,
+        currency: PaymentCurrencyFor<T, I>,
+        buyer: Option<T::AccountId>,
+    ) -> DispatchResult {
+        ensure!(!price.is_zero(), Error::<T, I>::ZeroAmount);
+        let entity = Self::get_entity(entity_id)?;
+        Self::ensure_not_frozen(entity_id)?;
+        Self::ensure_entity_nft_holder(&entity, &origin)?;
+        Self::ensure_payment_currency(&currency)?;
+
+        NftListings::<T, I>::insert(
+            entity_id,
+            NftListing {
+                seller: origin.clone(),
+                price,
+                currency: currency.clone(),
+                buyer,
+            },
+        );
+
+        Self::deposit_event(Event::EntityNftListed {
+            entity_id,
+            seller: origin,
+            price,
+            currency,
+        });
+
+        Ok(())
+    }
+
+    /// Removes the listing of the NFT of an entity.
+    ///
+    /// # It ensures
+    /// - The caller is the seller of the listing, or the seller no longer holds the NFT and anyone can drop the listing.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the entity.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNftListingNotFound` if the NFT of the entity is not listed.
+    /// - Returns `Error::<T, I>::NotAuthorized` if the caller is not the seller and the seller still holds the NFT.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityNftDelisted` with the `entity_id`.
+    pub(crate) fn delist_nft(origin: T::AccountId, entity_id: T::EntityId) -> DispatchResult {
+        let listing =
+            NftListings::<T, I>::get(entity_id).ok_or(Error::<T, I>::EntityNftListingNotFound)?;
+
+        if listing.seller != origin {
+            let entity = Self::get_entity(entity_id)?;
+            ensure!(
+                Self::ensure_entity_nft_holder(&entity, &listing.seller).is_err(),
+                Error::<T, I>::NotAuthorized
+            );
+        }
+
+        NftListings::<T, I>::remove(entity_id);
+
+        Self::deposit_event(Event::EntityNftDelisted { entity_id });
+
+        Ok(())
+    }
+
+    /// Buys the listed NFT of an entity.
+    ///
+    /// # It ensures
+    /// - The entity is not frozen by a dispute or a takedown and the seller still holds the NFT.
+    /// - The resale royalty of the entity is taken from the price and paid like any royalty of the entity,
+    ///   no royalty is taken if the entity has neither a royalty split nor royalty shares.
+    /// - The rest of the price is paid to the seller, both in the currency of the listing.
+    /// - The NFT is transferred to the buyer, it stays locked for transfers in the NFTs pallet.
+    /// - The listing is removed.
+    ///
+    /// # Parameters
+    /// - `buyer`: The account ID buying the NFT.
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `max_price`: The maximum price the buyer agrees to pay.
+    /// - `currency`: The currency the buyer agrees to pay in, a listing priced in another currency is rejected.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNftListingNotFound` if the NFT of the entity is not listed.
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::NotAuthorized` if the NFT is listed for another buyer.
+    /// - Returns `Error::<T, I>::EntityNftPriceTooHigh` if the price is above `max_price`.
+    /// - Returns `Error::<T, I>::PaymentCurrencyMismatch` if the listing is priced in another currency than `currency`.
+    /// - Returns `Error::<T, I>::EntityNftNotFound` if the entity has no NFT.
+    /// - Returns `Error::<T, I>::EntityNftHolderInvalid` if the seller no longer holds the NFT.
+    /// - Returns an error if a payment or the transfer of the NFT fails.
+    ///
+    /// # Events
+    /// - Emits `Event::RoyaltyPaid` for every recipient of the resale royalty.
+    /// - Emits `Event::EntityNftSold` with the `entity_id`, the seller, the buyer, the price, the royalty and the currency.
+    pub(crate) fn buy_nft(
+        buyer: T::AccountId,
+        entity_id: T::EntityId,
+        max_price: BalanceOf<T, I>,
+        currency: PaymentCurrencyFor<T, I>,
+    ) -> DispatchResult {
+        let listing =
+            NftListings::<T, I>::get(entity_id).ok_or(Error::<T, I>::EntityNftListingNotFound)?;
+        let entity = Self::get_entity(entity_id)?;
+        Self::ensure_not_frozen(entity_id)?;
+        ensure!(
+            listing
+                .buyer
+                .as_ref()
+                .map_or(true, |allowed| *allowed == buyer),
+            Error::<T, I>::NotAuthorized
+        );
+        ensure!(
+            listing.price <= max_price,
+            Error::<T, I>::EntityNftPriceTooHigh
+        );
+        ensure!(
+            listing.currency == currency,
+            Error::<T, I>::PaymentCurrencyMismatch
+        );
+        let (collection_id, item_id) = Self::ensure_entity_nft_holder(&entity, &listing.seller)?;
+
+        let royalty = if Self::has_royalty_recipients(entity_id, &entity) {
+            Self::resale_royalty(entity_id, &entity.owner).mul_floor(listing.price)
+        } else {
+            BalanceOf::<T, I>::zero()
+        };
+        if !royalty.is_zero() {
+            Self::distribute_royalties(&buyer, entity_id, &listing.currency, royalty)?;
+        }
+        let proceeds = listing.price.saturating_sub(royalty);
+        if !proceeds.is_zero() {
+            Self::transfer_payment(
+                &listing.currency,
+                &buyer,
+                &listing.seller,
+                proceeds,
+                Preservation::Preserve,
+            )?;
+        }
+
+        Self::unlock_entity_nft(&collection_id, &item_id)?;
+        T::Nfts::transfer(&collection_id, &item_id, &buyer)?;
+        Self::lock_entity_nft(&collection_id, &item_id)?;
+        NftListings::<T, I>::remove(entity_id);
+
+        Self::deposit_event(Event::EntityNftSold {
+            entity_id,
+            seller: listing.seller,
+            buyer,
+            price: listing.price,
+            royalty,
+            currency: listing.currency,
+        });
+
+        Ok(())
+    }
+
+    /// Gives the NFT of an entity back to an account of the authority owning the entity.
+    ///
+    /// # It ensures
+    /// - The caller holds the NFT of the entity and the entity is not frozen by a dispute or a takedown.
+    /// - The receiving account has access to the authority owning the entity, a gift to any other account
+    ///   could hide a sale and skip the resale royalty.
+    /// - No resale royalty is taken, nothing is paid for the NFT.
+    /// - The NFT is transferred to the receiving account, a locked NFT stays locked for transfers
+    ///   in the NFTs pallet.
+    /// - A listing of the NFT is removed.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `to`: The account ID receiving the NFT.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::EntityNftNotFound` if the entity has no NFT.
+    /// - Returns `Error::<T, I>::EntityNftHolderInvalid` if the caller does not hold the NFT.
+    /// - Returns `Error::<T, I>::EntityNftRecipientInvalid` if the receiving account has no access to the owning authority.
+    /// - Returns an error if the transfer of the NFT fails.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityNftGifted` with the `entity_id`, the previous and the new holder.
+    pub(crate) fn gift_nft(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+        to: T::AccountId,
+    ) -> DispatchResult {
+        let entity = Self::get_entity(entity_id)?;
+        Self::ensure_not_frozen(entity_id)?;
+        let (collection_id, item_id) = Self::ensure_entity_nft_holder(&entity, &origin)?;
+        ensure!(
+            Self::get_access(entity.owner, &to).is_some(),
+            Error::<T, I>::EntityNftRecipientInvalid
+        );
+
+        let locked = Self::is_entity_nft_locked(&collection_id, &item_id);
+        if locked {
+            Self::unlock_entity_nft(&collection_id, &item_id)?;
+        }
+        T::Nfts::transfer(&collection_id, &item_id, &to)?;
+        if locked {
+            Self::lock_entity_nft(&collection_id, &item_id)?;
+        }
+        NftListings::<T, I>::remove(entity_id);
+
+        Self::deposit_event(Event::EntityNftGifted {
+            entity_id,
+            from: origin,
+            to,
+        });
+
+        Ok(())
+    }
+
+    /// Validates that an account holds the NFT of an entity.
+    ///
+    /// # Returns
+    /// - The collection ID and the item ID of the NFT.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNftNotFound` if the entity has no NFT.
+    /// - Returns `Error::<T, I>::EntityNftHolderInvalid` if the account does not hold the NFT or it was burned.
+    fn ensure_entity_nft_holder(
+        entity: &EntityDetailsFor<T, I>,
+        who: &T::AccountId,
+    ) -> Result<(T::CollectionId, T::ItemId), DispatchError> {
+        let (Some(collection_id), Some(item_id)) = (entity.collection_id, entity.item_id) else {
+            return Err(Error::<T, I>::EntityNftNotFound.into());
+        };
+        ensure!(
+            T::Nfts::owner(&collection_id, &item_id).as_ref() == Some(who),
+            Error::<T, I>::EntityNftHolderInvalid
+        );
+        Ok((collection_id, item_id))
+    }
+
+    /// Resolves the resale royalty of an entity, the royalty of the entity overrides the royalty of its owner.
+    fn resale_royalty(entity_id: T::EntityId, owner: &T::AuthorityId) -> Perbill {
+        EntityResaleRoyalties::<T, I>::get(entity_id)
+            .or_else(|| AuthorityResaleRoyalties::<T, I>::get(owner))
+            .unwrap_or_default()
+    }
+
+    /// Retrieves the resale royalty taken from the sales of the NFT of an entity.
+    pub fn get_resale_royalty(entity_id: T::EntityId) -> Result<Perbill, DispatchError> {
+        let entity = Self::get_entity(entity_id)?;
+        Ok(Self::resale_royalty(entity_id, &entity.owner))
+    }
+
+    /// Retrieves the listing of the NFT of an entity.
+    pub fn get_nft_listing(entity_id: T::EntityId) -> Option<NftListingFor<T, I>> {
+        NftListings::<T, I>::get(entity_id)
+    }
+}
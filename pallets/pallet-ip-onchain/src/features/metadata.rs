@@ -0,0 +1,43 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Validates the metadata of an entity.
+    ///
+    /// # It ensures
+    /// - The declared standard and version are supported by the pallet.
+    /// - The `url` starts with one of the schemes accepted for the standard and is not just the scheme.
+    ///
+    /// # Parameters
+    /// - `metadata`: The metadata to validate.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::MetadataStandardNotSupported` if the standard or its version is not supported.
+    /// - Returns `Error::<T, I>::MetadataUrlInvalid` if the `url` does not fit the standard.
+    pub(crate) fn ensure_valid_metadata(
+        metadata: &Metadata<T::MaxLongStringLength>,
+    ) -> DispatchResult {
+        ensure!(
+            MetadataStandard::SUPPORTED.contains(&metadata.standard),
+            Error::<T, I>::MetadataStandardNotSupported
+        );
+
+        let schemes = metadata.standard.url_schemes();
+        ensure!(
+            schemes.is_empty()
+                || schemes.iter().any(|scheme| {
+                    metadata.url.len() > scheme.len() && metadata.url.starts_with(scheme)
+                }),
+            Error::<T, I>::MetadataUrlInvalid
+        );
+
+        Ok(())
+    }
+
+    /// Retrieves the metadata standards, with their versions, an entity can declare.
+    pub fn get_metadata_standards() -> Vec<MetadataStandard> {
+        MetadataStandard::SUPPORTED.to_vec()
+    }
+}
@@ -1,5 +1,23 @@
 pub mod access;
 pub mod author;
 pub mod authority;
+pub mod content;
+pub mod delegation;
+pub mod deposit;
+pub mod dispute;
 pub mod entity;
+pub mod governance;
+pub mod identifier;
+pub mod index;
+pub mod invitation;
+pub mod license;
+pub mod market;
+pub mod metadata;
 pub mod nfts;
+pub mod payment;
+pub mod relation;
+pub mod release;
+pub mod revision;
+pub mod royalty;
+pub mod shares;
+pub mod transfer;
@@ -1,5 +1,9 @@
 use crate::*;
 
+use frame::traits::tokens::nonfungibles_v2::Inspect;
+
+use polkadot_sdk::pallet_nfts::PalletAttributes;
+
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// Creates a new NFT collection.
     ///
@@ -35,21 +39,28 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     ///
     /// # It ensures
     /// - An NFT is minted into the specified collection and assigned to the specified owner.
+    /// - The NFT is not linked to another entity, e.g. one whose NFT was burned directly in the NFTs pallet.
+    /// - The item metadata is locked if the entity is immutable, see `entity_nft_config`.
+    /// - The NFT is locked for transfers in the NFTs pallet, so that it only moves through `buy_entity_nft`
+    ///   or `gift_entity_nft`.
     ///
     /// # Parameters
     /// - `authority_id`: The unique identifier of the authority associated with the entity.
     /// - `item_id`: An optional item ID for the NFT.
     /// - `nft_owner`: An optional account ID representing the owner of the NFT.
     /// - `config`: An optional configuration for the NFT item.
+    /// - `features`: The metadata features of the entity.
     ///
     /// # Errors
     /// - Returns `Error::<T, I>::EntityNftOwnerMustBeSpecified` if the `nft_owner` is not provided.
+    /// - Returns `Error::<T, I>::EntityNftAlreadyLinked` if the NFT is still linked to another entity.
     /// - Returns an error if the minting process fails.
     pub fn mint_nft_for_entity(
         authority_id: &T::AuthorityId,
         item_id: Option<T::ItemId>,
         nft_owner: Option<T::AccountId>,
         config: Option<pallet_nfts::ItemConfig>,
+        features: &MetadataFeatures,
     ) -> Result<Option<T::CollectionId>, DispatchError> {
         if item_id.is_none() {
             return Ok(None);
@@ -65,13 +76,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         if let (Some(collection_id), Some(item_id), Some(nft_owner)) =
             (authority.collection_id, item_id, nft_owner)
         {
+            ensure!(
+                !EntitiesByNft::<T, I>::contains_key(collection_id, item_id),
+                Error::<T, I>::EntityNftAlreadyLinked
+            );
             T::Nfts::mint_into(
                 &collection_id,
                 &item_id,
                 &nft_owner,
-                &config.unwrap_or_default(),
+                &Self::entity_nft_config(config, features),
                 true,
             )?;
+            Self::lock_entity_nft(&collection_id, &item_id)?;
 
             Ok(Some(collection_id))
         } else {
@@ -79,6 +95,362 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         }
     }
 
+    /// Moves the NFT linked to an entity into the collection of another authority.
+    ///
+    /// # It ensures
+    /// - The NFT is burned in its current collection and minted with the same item ID, owner and item config
+    ///   into the collection of `to_authority`, where it is locked for transfers again.
+    /// - The metadata and the attributes mirrored from the entity are cleared from the burned NFT,
+    ///   the caller mirrors the entity into the new NFT once its owner is updated.
+    /// - Nothing happens if the entity has no NFT, the authority has no collection or the NFT is already in it.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `entity`: The details of the entity, its `collection_id` is updated on success.
+    /// - `to_authority`: The unique identifier of the authority receiving the NFT.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthorityNotFound` if the authority does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityNftAlreadyLinked` if the item ID is linked to another entity in the new collection.
+    /// - Returns an error if burning or minting the NFT fails, e.g. the item ID is taken in the new collection.
+    pub(crate) fn move_entity_nft(
+        entity_id: T::EntityId,
+        entity: &mut EntityDetailsFor<T, I>,
+        to_authority: &T::AuthorityId,
+    ) -> DispatchResult {
+        let (Some(collection_id), Some(item_id)) = (entity.collection_id, entity.item_id) else {
+            return Ok(());
+        };
+
+        let Some(new_collection_id) = Self::get_authority(*to_authority)?.collection_id else {
+            return Ok(());
+        };
+
+        if new_collection_id == collection_id {
+            return Ok(());
+        }
+
+        let Some(nft_owner) = T::Nfts::owner(&collection_id, &item_id) else {
+            return Ok(());
+        };
+        ensure!(
+            !EntitiesByNft::<T, I>::contains_key(new_collection_id, item_id),
+            Error::<T, I>::EntityNftAlreadyLinked
+        );
+
+        Self::clear_entity_nft(&collection_id, &item_id)?;
+        Self::unlock_entity_nft(&collection_id, &item_id)?;
+        T::Nfts::burn(&collection_id, &item_id, None)?;
+        T::Nfts::mint_into(
+            &new_collection_id,
+            &item_id,
+            &nft_owner,
+            &Self::entity_nft_config(
+                EntityNftItemConfigs::<T, I>::get(entity_id),
+                &entity.metadata.features,
+            ),
+            true,
+        )?;
+        Self::lock_entity_nft(&new_collection_id, &item_id)?;
+        entity.collection_id = Some(new_collection_id);
+
+        Ok(())
+    }
+
+    /// Mirrors an entity into the metadata and the attributes of its NFT.
+    ///
+    /// # It ensures
+    /// - The metadata URL of the entity is set as the item metadata.
+    /// - The entity ID, the entity kind and the ID of the owning authority are set as SCALE encoded attributes
+    ///   under the `NFT_ATTRIBUTE_ENTITY_ID`, `NFT_ATTRIBUTE_ENTITY_KIND` and `NFT_ATTRIBUTE_AUTHORITY_ID` keys.
+    /// - The data is written by the pallet, so no NFT deposit is taken for it,
+    ///   the deposit of the entity covers it instead, see `entity_nft_bytes`.
+    /// - Nothing happens if the entity has no NFT or its NFT was burned directly in the NFTs pallet.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `entity`: The details of the entity.
+    ///
+    /// # Errors
+    /// - Returns an error if the metadata or an attribute can not be set.
+    pub(crate) fn mirror_entity_nft(
+        entity_id: T::EntityId,
+        entity: &EntityDetailsFor<T, I>,
+    ) -> DispatchResult {
+        let (Some(collection_id), Some(item_id)) = (entity.collection_id, entity.item_id) else {
+            return Ok(());
+        };
+        if T::Nfts::owner(&collection_id, &item_id).is_none() {
+            return Ok(());
+        }
+
+        T::Nfts::set_item_metadata(None, &collection_id, &item_id, &entity.metadata.url)?;
+        T::Nfts::set_attribute(
+            &collection_id,
+            &item_id,
+            NFT_ATTRIBUTE_ENTITY_ID,
+            &entity_id.encode(),
+        )?;
+        T::Nfts::set_attribute(
+            &collection_id,
+            &item_id,
+            NFT_ATTRIBUTE_ENTITY_KIND,
+            &entity.entity_kind.encode(),
+        )?;
+        T::Nfts::set_attribute(
+            &collection_id,
+            &item_id,
+            NFT_ATTRIBUTE_AUTHORITY_ID,
+            &entity.owner.encode(),
+        )
+    }
+
+    /// Clears the metadata and the attributes mirrored from an entity from its NFT.
+    ///
+    /// # It ensures
+    /// - Nothing happens if the NFT holds no mirrored data, e.g. it was minted before the entities were mirrored
+    ///   or it was burned.
+    ///
+    /// # Errors
+    /// - Returns an error if the metadata or an attribute can not be cleared.
+    pub(crate) fn clear_entity_nft(
+        collection_id: &T::CollectionId,
+        item_id: &T::ItemId,
+    ) -> DispatchResult {
+        if T::Nfts::owner(collection_id, item_id).is_none()
+            || T::Nfts::system_attribute(collection_id, Some(item_id), NFT_ATTRIBUTE_ENTITY_ID)
+                .is_none()
+        {
+            return Ok(());
+        }
+
+        T::Nfts::clear_item_metadata(None, collection_id, item_id)?;
+        for key in [
+            NFT_ATTRIBUTE_ENTITY_ID,
+            NFT_ATTRIBUTE_ENTITY_KIND,
+            NFT_ATTRIBUTE_AUTHORITY_ID,
+        ] {
+            T::Nfts::clear_attribute(collection_id, item_id, key)?;
+        }
+        Ok(())
+    }
+
+    /// Locks an entity NFT for transfers in the NFTs pallet, so that `set_price` and `buy_item` can not
+    /// bypass the resale royalty of the entity, nothing happens if it is already locked.
+    pub(crate) fn lock_entity_nft(
+        collection_id: &T::CollectionId,
+        item_id: &T::ItemId,
+    ) -> DispatchResult {
+        if Self::is_entity_nft_locked(collection_id, item_id) {
+            return Ok(());
+        }
+        T::Nfts::disable_transfer(collection_id, item_id)
+    }
+
+    /// Unlocks an entity NFT for transfers in the NFTs pallet, nothing happens if it is not locked or was burned.
+    pub(crate) fn unlock_entity_nft(
+        collection_id: &T::CollectionId,
+        item_id: &T::ItemId,
+    ) -> DispatchResult {
+        if T::Nfts::owner(collection_id, item_id).is_none()
+            || !Self::is_entity_nft_locked(collection_id, item_id)
+        {
+            return Ok(());
+        }
+        T::Nfts::enable_transfer(collection_id, item_id)
+    }
+
+    /// Checks whether the transfers of an NFT are disabled in the NFTs pallet.
+    pub fn is_entity_nft_locked(collection_id: &T::CollectionId, item_id: &T::ItemId) -> bool {
+        T::Nfts::system_attribute(
+            collection_id,
+            Some(item_id),
+            &PalletAttributes::<T::CollectionId>::TransferDisabled.encode(),
+        )
+        .is_some()
+    }
+
+    /// Builds the config of the NFT of an entity, the item metadata of an immutable entity is locked
+    /// so that it can only be set by the pallet when the NFT is minted.
+    pub(crate) fn entity_nft_config(
+        config: Option<pallet_nfts::ItemConfig>,
+        features: &MetadataFeatures,
+    ) -> pallet_nfts::ItemConfig {
+        let mut config = config.unwrap_or_default();
+        if features.has_feature(MetadataFeature::Immutable) {
+            config.disable_setting(pallet_nfts::ItemSetting::UnlockedMetadata);
+        }
+        config
+    }
+
+    /// Calculates the bytes mirrored from an entity into its NFT, `0` if the entity has no NFT.
+    pub fn entity_nft_bytes(entity: &EntityDetailsFor<T, I>) -> usize {
+        if entity.collection_id.is_none() || entity.item_id.is_none() {
+            return 0;
+        }
+
+        entity.metadata.url.len()
+            + NFT_ATTRIBUTE_ENTITY_ID.len()
+            + NFT_ATTRIBUTE_ENTITY_KIND.len()
+            + NFT_ATTRIBUTE_AUTHORITY_ID.len()
+            + T::EntityId::max_encoded_len()
+            + IPEntityKind::max_encoded_len()
+            + T::AuthorityId::max_encoded_len()
+    }
+
+    /// Detaches the NFT from an entity, the NFT itself is kept by its holder.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditEntity` access right for the authority owning the entity.
+    /// - The entity is not frozen by a dispute or a takedown.
+    /// - The data mirrored into the NFT is cleared and the NFT is removed from the `EntitiesByNft` storage.
+    /// - The NFT is unlocked for transfers in the NFTs pallet and its listing is removed.
+    /// - The caller takes over the deposit of the entity, which no longer covers the mirrored data.
+    /// - A new NFT can then be minted for the entity with `edit_entity`, e.g. into another collection.
+    /// - The detachment is kept as the next revision of the entity with the caller as its editor.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the entity.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::EntityNftNotFound` if the entity has no NFT.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit the entity.
+    /// - Returns an error if the caller can not afford the deposit.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityNftDetached` with the `entity_id` and the former NFT.
+    pub(crate) fn detach_nft(origin: T::AccountId, entity_id: T::EntityId) -> DispatchResult {
+        Entities::<T, I>::try_mutate(entity_id, |maybe_entity| -> DispatchResult {
+            let entity = maybe_entity.as_mut().ok_or(Error::<T, I>::EntityNotFound)?;
+            Self::ensure_not_frozen(entity_id)?;
+            Self::ensure_access_right(
+                &origin,
+                &entity.owner,
+                AuthorityAccessSetting::EditEntity.into(),
+            )?;
+            let (Some(collection_id), Some(item_id)) = (entity.collection_id, entity.item_id)
+            else {
+                return Err(Error::<T, I>::EntityNftNotFound.into());
+            };
+
+            let old_entity = entity.clone();
+            Self::clear_entity_nft(&collection_id, &item_id)?;
+            Self::unlock_entity_nft(&collection_id, &item_id)?;
+            NftListings::<T, I>::remove(entity_id);
+            EntityNftItemConfigs::<T, I>::remove(entity_id);
+            entity.collection_id = None;
+            entity.item_id = None;
+
+            EntityDeposits::<T, I>::try_mutate(entity_id, |deposit| -> DispatchResult {
+                *deposit =
+                    Self::update_deposit(deposit.take(), &origin, Self::entity_deposit(entity))?;
+                Ok(())
+            })?;
+            Self::index_entity(entity_id, Some(&old_entity), Some(entity));
+            Self::bump_revision(entity_id, entity, &origin)?;
+
+            Self::deposit_event(Event::EntityNftDetached {
+                entity_id,
+                collection_id,
+                item_id,
+            });
+
+            Ok(())
+        })
+    }
+
+    /// Burns the NFT of an entity and detaches it from the entity.
+    ///
+    /// # It ensures
+    /// - The caller holds the NFT, the data mirrored into it is cleared and it is unlocked before it is burned.
+    /// - The listing of the NFT is removed.
+    /// - An NFT already burned directly in the NFTs pallet is only detached, anyone can drop such a dangling link.
+    /// - The NFT is removed from the `EntitiesByNft` storage and the deposit of the entity
+    ///   no longer covers the mirrored data.
+    /// - The burn is kept as the next revision of the entity with the caller as its editor.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the entity.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityNftNotFound` if the entity has no NFT.
+    /// - Returns `Error::<T, I>::EntityNftHolderInvalid` if the caller does not hold the NFT.
+    /// - Returns an error if the NFT can not be burned.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityNftBurned` with the `entity_id` and the burned NFT.
+    pub(crate) fn burn_nft(origin: T::AccountId, entity_id: T::EntityId) -> DispatchResult {
+        Entities::<T, I>::try_mutate(entity_id, |maybe_entity| -> DispatchResult {
+            let entity = maybe_entity.as_mut().ok_or(Error::<T, I>::EntityNotFound)?;
+            let (Some(collection_id), Some(item_id)) = (entity.collection_id, entity.item_id)
+            else {
+                return Err(Error::<T, I>::EntityNftNotFound.into());
+            };
+
+            if let Some(holder) = T::Nfts::owner(&collection_id, &item_id) {
+                ensure!(holder == origin, Error::<T, I>::EntityNftHolderInvalid);
+                Self::clear_entity_nft(&collection_id, &item_id)?;
+                Self::unlock_entity_nft(&collection_id, &item_id)?;
+                T::Nfts::burn(&collection_id, &item_id, Some(&origin))?;
+            }
+            NftListings::<T, I>::remove(entity_id);
+            EntityNftItemConfigs::<T, I>::remove(entity_id);
+
+            let old_entity = entity.clone();
+            entity.collection_id = None;
+            entity.item_id = None;
+
+            EntityDeposits::<T, I>::try_mutate(entity_id, |deposit| -> DispatchResult {
+                if let Some(old_deposit) = deposit.take() {
+                    let depositor = old_deposit.depositor.clone();
+                    *deposit = Self::update_deposit(
+                        Some(old_deposit),
+                        &depositor,
+                        Self::entity_deposit(entity),
+                    )?;
+                }
+                Ok(())
+            })?;
+            Self::index_entity(entity_id, Some(&old_entity), Some(entity));
+            Self::bump_revision(entity_id, entity, &origin)?;
+
+            Self::deposit_event(Event::EntityNftBurned {
+                entity_id,
+                collection_id,
+                item_id,
+            });
+
+            Ok(())
+        })
+    }
+
+    /// Retrieves the current holder of the NFT of an entity.
+    ///
+    /// The holder is read from the NFTs pallet, so it follows transfers made there directly,
+    /// `None` if the entity has no NFT or its NFT was burned.
+    pub fn get_entity_nft_holder(
+        entity_id: T::EntityId,
+    ) -> Result<Option<T::AccountId>, DispatchError> {
+        let entity = Self::get_entity(entity_id)?;
+        Ok(entity
+            .collection_id
+            .zip(entity.item_id)
+            .and_then(|(collection_id, item_id)| T::Nfts::owner(&collection_id, &item_id)))
+    }
+
+    /// Retrieves the entity linked to an NFT.
+    pub fn get_nft_entity(
+        collection_id: T::CollectionId,
+        item_id: T::ItemId,
+    ) -> Option<T::EntityId> {
+        EntitiesByNft::<T, I>::get(collection_id, item_id)
+    }
+
     /// Init collection id for authority, if nft support is enabled.
     ///
     /// # It ensures
@@ -0,0 +1,75 @@
+use crate::*;
+
+use frame::traits::{
+    fungible::{Inspect as _, Mutate as _},
+    fungibles::{Inspect as _, Mutate as _},
+    tokens::{DepositConsequence, Preservation, Provenance},
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Transfers a payment in the native `Currency` or in an asset of the `Payments` fungibles.
+    ///
+    /// # Parameters
+    /// - `currency`: The currency of the payment.
+    /// - `from`: The account ID paying the amount.
+    /// - `to`: The account ID receiving the amount.
+    /// - `amount`: The amount to transfer.
+    /// - `preservation`: Whether the paying account must be kept alive.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::PaymentCurrencyNotSupported` if the asset is not one of the `PaymentAssets`.
+    /// - Returns an error if the transfer fails, e.g. the asset does not exist or the balance is too low.
+    pub(crate) fn transfer_payment(
+        currency: &PaymentCurrencyFor<T, I>,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: BalanceOf<T, I>,
+        preservation: Preservation,
+    ) -> DispatchResult {
+        Self::ensure_payment_currency(currency)?;
+
+        match currency {
+            PaymentCurrency::Native => {
+                T::Currency::transfer(from, to, amount, preservation)?;
+            }
+            PaymentCurrency::Asset(asset_id) => {
+                T::Payments::transfer(asset_id.clone(), from, to, amount, preservation)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether an account can receive a payment, e.g. an amount below the existential deposit
+    /// of the currency can not be paid to an account that does not exist yet.
+    pub(crate) fn can_receive_payment(
+        currency: &PaymentCurrencyFor<T, I>,
+        to: &T::AccountId,
+        amount: BalanceOf<T, I>,
+    ) -> bool {
+        let consequence = match currency {
+            PaymentCurrency::Native => T::Currency::can_deposit(to, amount, Provenance::Extant),
+            PaymentCurrency::Asset(asset_id) => {
+                T::Payments::can_deposit(asset_id.clone(), to, amount, Provenance::Extant)
+            }
+        };
+
+        consequence == DepositConsequence::Success
+    }
+
+    /// Validates the currency of a payment, an offer or a listing.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::PaymentCurrencyNotSupported` if the asset is not one of the `PaymentAssets`,
+    ///   e.g. the native currency written as an asset of `Payments`.
+    pub(crate) fn ensure_payment_currency(currency: &PaymentCurrencyFor<T, I>) -> DispatchResult {
+        if let PaymentCurrency::Asset(asset_id) = currency {
+            ensure!(
+                T::PaymentAssets::contains(asset_id),
+                Error::<T, I>::PaymentCurrencyNotSupported
+            );
+        }
+
+        Ok(())
+    }
+}
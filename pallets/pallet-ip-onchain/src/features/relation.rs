@@ -0,0 +1,110 @@
+extern crate alloc;
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Validates the relations of an entity before they are written to the storage.
+    ///
+    /// # It ensures
+    /// - The entity does not relate to itself.
+    /// - Every related entity exists in the `Entities` storage and appears in the relations only once.
+    /// - The new relations do not close a cycle, e.g. an entity can not be a remix of its own remix.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the entity the relations belong to, `None` for a new entity.
+    /// - `relations`: The relations of the entity.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityRelationToSelf` if the entity relates to itself.
+    /// - Returns `Error::<T, I>::EntityRelatedEntityNotFound` if any of the related entities do not exist in the `Entities` storage.
+    /// - Returns `Error::<T, I>::EntityRelationDuplicate` if an entity appears in the relations more than once.
+    /// - Returns `Error::<T, I>::EntityRelationCycle` if the relations close a cycle.
+    /// - Returns `Error::<T, I>::EntityLineageTooLarge` if the cycle check needs more than `MaxLineageLookups` lookups.
+    pub fn ensure_valid_relations(
+        entity_id: Option<T::EntityId>,
+        relations: &[Relation<T::EntityId>],
+    ) -> DispatchResult {
+        let mut sources = BTreeSet::new();
+
+        for relation in relations {
+            ensure!(
+                Some(relation.entity_id) != entity_id,
+                Error::<T, I>::EntityRelationToSelf
+            );
+            ensure!(
+                Entities::<T, I>::contains_key(relation.entity_id),
+                Error::<T, I>::EntityRelatedEntityNotFound
+            );
+            ensure!(
+                sources.insert(relation.entity_id.encode()),
+                Error::<T, I>::EntityRelationDuplicate
+            );
+        }
+
+        // A new entity has no derivatives yet, so it can not be a part of a cycle.
+        let Some(entity_id) = entity_id else {
+            return Ok(());
+        };
+
+        let mut visited = BTreeSet::new();
+        let mut pending: Vec<T::EntityId> = relations
+            .iter()
+            .map(|relation| relation.entity_id)
+            .collect();
+        let mut lookups: u32 = 0;
+
+        while let Some(source_id) = pending.pop() {
+            ensure!(source_id != entity_id, Error::<T, I>::EntityRelationCycle);
+
+            if !visited.insert(source_id.encode()) {
+                continue;
+            }
+
+            lookups += 1;
+            ensure!(
+                lookups <= T::MaxLineageLookups::get(),
+                Error::<T, I>::EntityLineageTooLarge
+            );
+
+            if let Some(related_to) = Entities::<T, I>::get(source_id).and_then(|e| e.related_to) {
+                pending.extend(related_to.iter().map(|relation| relation.entity_id));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Keeps the `Derivatives` reverse index in sync with the relations of an entity.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the derivative entity.
+    /// - `old_relations`: The relations of the entity before the change.
+    /// - `new_relations`: The relations of the entity after the change.
+    pub(crate) fn index_relations(
+        entity_id: T::EntityId,
+        old_relations: &Option<BoundedVec<Relation<T::EntityId>, T::MaxRelatedEntities>>,
+        new_relations: &Option<BoundedVec<Relation<T::EntityId>, T::MaxRelatedEntities>>,
+    ) {
+        for relation in old_relations.iter().flatten() {
+            Derivatives::<T, I>::remove(relation.entity_id, entity_id);
+        }
+        for relation in new_relations.iter().flatten() {
+            Derivatives::<T, I>::insert(relation.entity_id, entity_id, relation.kind);
+        }
+    }
+
+    /// Retrieves the entities deriving from an entity.
+    ///
+    /// # It ensures
+    /// - The function performs a read-only operation and does not modify the storage.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the source entity.
+    ///
+    /// # Returns
+    /// - A vector of derivative entity IDs with the kind of their relation to `entity_id`.
+    pub fn get_derivatives(entity_id: T::EntityId) -> Vec<(T::EntityId, RelationKind)> {
+        Derivatives::<T, I>::iter_prefix(entity_id).collect()
+    }
+}
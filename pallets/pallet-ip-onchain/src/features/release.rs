@@ -0,0 +1,309 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::*;
+
+use frame::deps::frame_support::storage::with_storage_layer;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Adds a release entity together with its tracks.
+    ///
+    /// # It ensures
+    /// - The release and every track are added as entities, see `add_new_entity`.
+    /// - The tracks are numbered from `1` in their order.
+    /// - A track without authors or royalty parts takes the ones of the release.
+    /// - The NFTs of the release and of the tracks with an `nft_item_id` are minted to the same owner and with the same config.
+    /// - The tracks are linked to the release in their order in the `ReleaseTracks` and `TrackReleases` storages.
+    /// - The release is created atomically, a failing track leaves no entity nor NFT behind.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_kind`: The release kind of the release.
+    /// - `owner`: The unique authority ID of the owner of the release and of its tracks.
+    /// - `url`: A bounded vector containing the metadata URL for the release.
+    /// - `metadata_standard`: The standard format for the metadata of the release.
+    /// - `metadata_features`: The metadata features of the release.
+    /// - `authors`: An optional bounded vector of author IDs shared by the release and its tracks.
+    /// - `royalty_parts`: An optional bounded vector of wallets shared by the release and its tracks.
+    /// - `tracks`: The tracks of the release in their order.
+    /// - `nft_item_id`: An optional NFT item ID for the release.
+    /// - `nft_owner`: An optional account ID representing the owner of the NFTs.
+    /// - `nft_item_config`: An optional configuration for the NFT items.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::ReleaseKindInvalid` if the `entity_kind` is not a release kind.
+    /// - Returns `Error::<T, I>::ReleaseTracksNotFound` if no track is provided.
+    /// - Returns `Error::<T, I>::ReleaseTrackInvalid` if a track is itself a release.
+    /// - Returns an entity error if the release or a track can not be added, see `add_new_entity`.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityAdded` for the release and for each track.
+    /// - Emits `Event::ReleaseAdded` with the release entity ID and the track entity IDs.
+    pub(crate) fn add_release(
+        origin: T::AccountId,
+        entity_kind: IPEntityKind,
+        owner: T::AuthorityId,
+        url: BoundedVec<u8, T::MaxLongStringLength>,
+        metadata_standard: MetadataStandard,
+        metadata_features: MetadataFeatures,
+        authors: Option<BoundedVec<T::AuthorId, T::MaxEntityAuthors>>,
+        royalty_parts: Option<BoundedVec<Wallet<T::AccountId>, T::MaxRoyaltyParts>>,
+        tracks: BoundedVec<ReleaseTrackFor<T, I>, T::MaxReleaseTracks>,
+        nft_item_id: Option<T::ItemId>,
+        nft_owner: Option<T::AccountId>,
+        nft_item_config: Option<pallet_nfts::ItemConfig>,
+    ) -> DispatchResult {
+        ensure!(entity_kind.is_release(), Error::<T, I>::ReleaseKindInvalid);
+        ensure!(!tracks.is_empty(), Error::<T, I>::ReleaseTracksNotFound);
+        ensure!(
+            tracks.iter().all(|track| !track.entity_kind.is_release()),
+            Error::<T, I>::ReleaseTrackInvalid
+        );
+
+        with_storage_layer(|| -> DispatchResult {
+            let release_id = Self::add_new_entity(
+                origin.clone(),
+                entity_kind,
+                owner,
+                url,
+                metadata_standard,
+                metadata_features,
+                authors.clone(),
+                royalty_parts.clone(),
+                None,
+                nft_item_id,
+                nft_owner.clone(),
+                nft_item_config,
+            )?;
+
+            let mut track_ids = Vec::with_capacity(tracks.len());
+            let mut members = Vec::with_capacity(tracks.len());
+            for (track_number, track) in (1..).zip(tracks) {
+                let track_id = Self::add_new_entity(
+                    origin.clone(),
+                    track.entity_kind,
+                    owner,
+                    track.url,
+                    track.metadata_standard,
+                    track.metadata_features,
+                    track.authors.or_else(|| authors.clone()),
+                    track.royalty_parts.or_else(|| royalty_parts.clone()),
+                    track.related_to,
+                    track.nft_item_id,
+                    nft_owner.clone(),
+                    nft_item_config,
+                )?;
+                TrackReleases::<T, I>::insert(track_id, release_id, ());
+                track_ids.push(track_id);
+                members.push(ReleaseMember {
+                    entity_id: track_id,
+                    track_number,
+                });
+            }
+
+            let members: BoundedVec<ReleaseMember<T::EntityId>, T::MaxReleaseTracks> = members
+                .try_into()
+                .map_err(|_| Error::<T, I>::LimitExceeded)?;
+            ReleaseTracks::<T, I>::insert(release_id, members);
+            let track_ids: BoundedVec<T::EntityId, T::MaxReleaseTracks> = track_ids
+                .try_into()
+                .map_err(|_| Error::<T, I>::LimitExceeded)?;
+
+            Self::deposit_event(Event::ReleaseAdded {
+                entity_id: release_id,
+                track_ids,
+            });
+
+            Ok(())
+        })
+    }
+
+    /// Replaces the tracks of a release.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditEntity` access right for the authority owning the release.
+    /// - The track numbers start from `1` and strictly increase, so the tracks are ordered by track number.
+    /// - Every track exists, appears once and is neither a release nor the release itself.
+    /// - A new track owned by another authority is added only if that authority consents to it,
+    ///   the caller must have the `EditEntity` access right for the owner of the track.
+    /// - The `TrackReleases` storage is kept in sync with the tracks of the release.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `release_id`: The unique identifier of the release.
+    /// - `tracks`: The new tracks of the release with their track numbers, an empty list clears the release.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the release or a track does not exist.
+    /// - Returns `Error::<T, I>::ReleaseNotFound` if the entity is not a release.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the release is disputed or taken down.
+    /// - Returns `Error::<T, I>::ReleaseTrackNumberInvalid` if the track numbers do not strictly increase from `1`.
+    /// - Returns `Error::<T, I>::ReleaseTrackDuplicate` if a track appears more than once.
+    /// - Returns `Error::<T, I>::ReleaseTrackInvalid` if a track is a release.
+    /// - Returns an access control error if the caller can not edit the release or add a track of another authority.
+    ///
+    /// # Events
+    /// - Emits `Event::ReleaseTracksSet` with the `release_id`.
+    pub(crate) fn set_release_members(
+        origin: T::AccountId,
+        release_id: T::EntityId,
+        tracks: BoundedVec<ReleaseMember<T::EntityId>, T::MaxReleaseTracks>,
+    ) -> DispatchResult {
+        let release = Self::get_release_entity(release_id)?;
+        Self::ensure_not_frozen(release_id)?;
+        Self::ensure_access_right(
+            &origin,
+            &release.owner,
+            AuthorityAccessSetting::EditEntity.into(),
+        )?;
+
+        let mut previous_number = 0;
+        for (index, member) in tracks.iter().enumerate() {
+            ensure!(
+                member.track_number > previous_number,
+                Error::<T, I>::ReleaseTrackNumberInvalid
+            );
+            previous_number = member.track_number;
+            ensure!(
+                tracks[..index]
+                    .iter()
+                    .all(|other| other.entity_id != member.entity_id),
+                Error::<T, I>::ReleaseTrackDuplicate
+            );
+
+            let track = Self::get_entity(member.entity_id)?;
+            ensure!(
+                !track.entity_kind.is_release(),
+                Error::<T, I>::ReleaseTrackInvalid
+            );
+            if track.owner != release.owner
+                && !TrackReleases::<T, I>::contains_key(member.entity_id, release_id)
+            {
+                Self::ensure_access_right(
+                    &origin,
+                    &track.owner,
+                    AuthorityAccessSetting::EditEntity.into(),
+                )?;
+            }
+        }
+
+        for member in ReleaseTracks::<T, I>::take(release_id) {
+            TrackReleases::<T, I>::remove(member.entity_id, release_id);
+        }
+        for member in tracks.iter() {
+            TrackReleases::<T, I>::insert(member.entity_id, release_id, ());
+        }
+        if !tracks.is_empty() {
+            ReleaseTracks::<T, I>::insert(release_id, tracks);
+        }
+
+        Self::deposit_event(Event::ReleaseTracksSet {
+            entity_id: release_id,
+        });
+
+        Ok(())
+    }
+
+    /// Removes a track from a release, the order and the numbers of the other tracks are kept.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditEntity` access right for the authority owning the release
+    ///   or for the authority owning the track, so an authority can always withdraw its consent.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `release_id`: The unique identifier of the release.
+    /// - `track_id`: The unique identifier of the track.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::ReleaseTrackNotFound` if the track is not a track of the release.
+    /// - Returns an access control error if the caller can edit neither the release nor the track.
+    ///
+    /// # Events
+    /// - Emits `Event::ReleaseTrackWithdrawn` with the `release_id` and `track_id`.
+    pub(crate) fn remove_release_member(
+        origin: T::AccountId,
+        release_id: T::EntityId,
+        track_id: T::EntityId,
+    ) -> DispatchResult {
+        ensure!(
+            TrackReleases::<T, I>::contains_key(track_id, release_id),
+            Error::<T, I>::ReleaseTrackNotFound
+        );
+        let release = Self::get_entity(release_id)?;
+        if Self::ensure_access_right(
+            &origin,
+            &release.owner,
+            AuthorityAccessSetting::EditEntity.into(),
+        )
+        .is_err()
+        {
+            let track = Self::get_entity(track_id)?;
+            Self::ensure_access_right(
+                &origin,
+                &track.owner,
+                AuthorityAccessSetting::EditEntity.into(),
+            )?;
+        }
+
+        TrackReleases::<T, I>::remove(track_id, release_id);
+        ReleaseTracks::<T, I>::mutate_exists(release_id, |maybe_tracks| {
+            if let Some(tracks) = maybe_tracks {
+                tracks.retain(|member| member.entity_id != track_id);
+                if tracks.is_empty() {
+                    *maybe_tracks = None;
+                }
+            }
+        });
+
+        Self::deposit_event(Event::ReleaseTrackWithdrawn {
+            entity_id: release_id,
+            track_id,
+        });
+
+        Ok(())
+    }
+
+    /// Retrieves a release entity.
+    fn get_release_entity(
+        release_id: T::EntityId,
+    ) -> Result<EntityDetailsFor<T, I>, DispatchError> {
+        let release = Self::get_entity(release_id)?;
+        ensure!(
+            release.entity_kind.is_release(),
+            Error::<T, I>::ReleaseNotFound
+        );
+        Ok(release)
+    }
+
+    /// Retrieves a release together with its tracks and their track numbers.
+    pub fn get_release(
+        release_id: T::EntityId,
+    ) -> Result<
+        (
+            EntityDetailsFor<T, I>,
+            Vec<(u32, T::EntityId, EntityDetailsFor<T, I>)>,
+        ),
+        DispatchError,
+    > {
+        let release = Self::get_release_entity(release_id)?;
+        let tracks = ReleaseTracks::<T, I>::get(release_id)
+            .into_iter()
+            .filter_map(|member| {
+                Entities::<T, I>::get(member.entity_id)
+                    .map(|track| (member.track_number, member.entity_id, track))
+            })
+            .collect();
+        Ok((release, tracks))
+    }
+
+    /// Retrieves the tracks of a release in their order.
+    pub fn get_release_tracks(release_id: T::EntityId) -> Vec<ReleaseMember<T::EntityId>> {
+        ReleaseTracks::<T, I>::get(release_id).into_inner()
+    }
+
+    /// Retrieves the releases of a track.
+    pub fn get_track_releases(track_id: T::EntityId) -> Vec<T::EntityId> {
+        TrackReleases::<T, I>::iter_key_prefix(track_id).collect()
+    }
+}
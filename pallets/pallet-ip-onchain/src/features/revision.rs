@@ -0,0 +1,86 @@
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Makes the edited details of an entity its next revision.
+    ///
+    /// # It ensures
+    /// - The `updated_at` block of the entity is the current block and its `revision` number is incremented.
+    /// - The new revision is kept in the `EntityRevisions` storage, see `store_revision`.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the edited entity.
+    /// - `entity`: The edited details of the entity.
+    /// - `editor`: The account ID that made the edit.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::Overflow` if the revision number can not be incremented.
+    pub(crate) fn bump_revision(
+        entity_id: T::EntityId,
+        entity: &mut EntityDetailsFor<T, I>,
+        editor: &T::AccountId,
+    ) -> DispatchResult {
+        entity.updated_at = frame_system::Pallet::<T>::block_number();
+        entity.revision = entity
+            .revision
+            .checked_add(1)
+            .ok_or(Error::<T, I>::Overflow)?;
+
+        Self::store_revision(entity_id, entity, editor);
+
+        Ok(())
+    }
+
+    /// Keeps the current revision of an entity in its history.
+    ///
+    /// # It ensures
+    /// - At most `MaxEntityRevisions` revisions are kept per entity, the oldest revision is pruned.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `entity`: The details of the entity at its current revision.
+    /// - `editor`: The account ID that made the revision.
+    pub(crate) fn store_revision(
+        entity_id: T::EntityId,
+        entity: &EntityDetailsFor<T, I>,
+        editor: &T::AccountId,
+    ) {
+        let max_revisions = T::MaxEntityRevisions::get();
+        if max_revisions == 0 {
+            return;
+        }
+
+        if let Some(pruned) = entity.revision.checked_sub(max_revisions) {
+            EntityRevisions::<T, I>::remove(entity_id, pruned);
+        }
+
+        EntityRevisions::<T, I>::insert(
+            entity_id,
+            entity.revision,
+            EntityRevision {
+                editor: editor.clone(),
+                details: entity.clone(),
+            },
+        );
+    }
+
+    /// Removes the history of a removed entity.
+    pub(crate) fn clear_revisions(entity_id: T::EntityId) {
+        let _ =
+            EntityRevisions::<T, I>::clear_prefix(entity_id, T::MaxEntityRevisions::get(), None);
+    }
+
+    /// Retrieves a revision of the details of an entity.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `revision`: The number of the revision, the current revision is `EntityDetails.revision`.
+    ///
+    /// # Returns
+    /// - The revision with the account that made it, `None` if the revision was pruned or never made.
+    pub fn get_entity_revision(
+        entity_id: T::EntityId,
+        revision: u32,
+    ) -> Option<EntityRevisionFor<T, I>> {
+        EntityRevisions::<T, I>::get(entity_id, revision)
+    }
+}
@@ -0,0 +1,255 @@
+extern crate alloc;
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use crate::*;
+
+use frame::arithmetic::Perbill;
+use frame::traits::{fungibles::Inspect as _, tokens::Preservation};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Distributes a royalty payment across the royalty parts of an entity.
+    ///
+    /// # It ensures
+    /// - The entity is not frozen by a dispute or a takedown.
+    /// - The `amount` is split across `royalty_parts` according to each wallet `share`.
+    /// - The remainder left after rounding every share down is paid to the first wallet of the split,
+    ///   so the whole `amount` is always distributed.
+    /// - Wallets whose share rounds down to zero are skipped.
+    /// - Shares a recipient can not receive, e.g. below the existential deposit of an account that does not
+    ///   exist yet, are added to the share of the first recipient that can receive its share, see
+    ///   `carry_over_dust`. A single dust share does not fail the whole payment.
+    /// - The `amount` is split across the holders of the royalty shares instead if the entity issued them,
+    ///   see `royalty_share_payouts`.
+    /// - The payment is made in the native currency or in an asset of the `Payments` fungibles,
+    ///   the amount is added to the royalties the entity received in that currency.
+    ///
+    /// # Parameters
+    /// - `payer`: The account ID paying the royalties.
+    /// - `entity_id`: The unique identifier of the entity the royalties are paid for.
+    /// - `currency`: The currency the royalties are paid in.
+    /// - `amount`: The total amount to distribute.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::ZeroAmount` if the `amount` is zero.
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::EntityRoyaltyPartsNotFound` if the entity has neither royalty parts nor royalty shares.
+    /// - Returns an error if any of the transfers fails.
+    ///
+    /// # Events
+    /// - Emits `Event::RoyaltyPaid` for every recipient of the split.
+    pub(crate) fn distribute_royalties(
+        payer: &T::AccountId,
+        entity_id: T::EntityId,
+        currency: &PaymentCurrencyFor<T, I>,
+        amount: BalanceOf<T, I>,
+    ) -> DispatchResult {
+        ensure!(!amount.is_zero(), Error::<T, I>::ZeroAmount);
+
+        let entity = Self::get_entity(entity_id)?;
+        Self::ensure_not_frozen(entity_id)?;
+        let payouts = match EntityRoyaltyShares::<T, I>::get(entity_id) {
+            Some(shares) => Self::royalty_share_payouts(&shares, amount),
+            None => {
+                let royalty_parts = entity
+                    .royalty_parts
+                    .filter(|parts| !parts.is_empty())
+                    .ok_or(Error::<T, I>::EntityRoyaltyPartsNotFound)?;
+                Self::royalty_payouts(&royalty_parts, amount)
+            }
+        };
+
+        for (recipient, share) in Self::carry_over_dust(currency, payouts) {
+            Self::transfer_payment(currency, payer, &recipient, share, Preservation::Preserve)?;
+
+            Self::deposit_event(Event::RoyaltyPaid {
+                entity_id,
+                payer: payer.clone(),
+                recipient,
+                amount: share,
+                currency: currency.clone(),
+            });
+        }
+        RoyaltiesReceived::<T, I>::mutate(entity_id, currency, |received| {
+            *received = received.saturating_add(amount)
+        });
+
+        Ok(())
+    }
+
+    /// Calculates how an amount is split across royalty parts.
+    ///
+    /// # It ensures
+    /// - The function performs a read-only operation and does not modify the storage.
+    /// - Every share is rounded down, the remainder is added to the share of the first wallet.
+    /// - The returned shares always sum up to `amount`, zero shares are omitted.
+    ///
+    /// # Parameters
+    /// - `royalty_parts`: The wallets of the split with their shares.
+    /// - `amount`: The total amount to split.
+    ///
+    /// # Returns
+    /// - A vector of recipients with the amount each of them receives, in the order of `royalty_parts`.
+    pub fn royalty_payouts(
+        royalty_parts: &[Wallet<T::AccountId>],
+        amount: BalanceOf<T, I>,
+    ) -> Vec<(T::AccountId, BalanceOf<T, I>)> {
+        let mut payouts: Vec<(T::AccountId, BalanceOf<T, I>)> = royalty_parts
+            .iter()
+            .map(|wallet| (wallet.address_id.clone(), wallet.share.mul_floor(amount)))
+            .collect();
+
+        let distributed = payouts
+            .iter()
+            .fold(BalanceOf::<T, I>::zero(), |total, (_, share)| {
+                total.saturating_add(*share)
+            });
+
+        if let Some((_, first_share)) = payouts.first_mut() {
+            *first_share = first_share.saturating_add(amount.saturating_sub(distributed));
+        }
+
+        payouts.retain(|(_, share)| !share.is_zero());
+
+        payouts
+    }
+
+    /// Adds the shares their recipients can not receive to the share of the first recipient that can.
+    ///
+    /// # It ensures
+    /// - The payouts sum up to the same amount, the order of the payable recipients is kept.
+    /// - The payouts are returned unchanged if no recipient can receive its share, the payment then fails
+    ///   on the first transfer.
+    ///
+    /// # Parameters
+    /// - `currency`: The currency the royalties are paid in.
+    /// - `payouts`: The recipients with the amount each of them receives.
+    fn carry_over_dust(
+        currency: &PaymentCurrencyFor<T, I>,
+        payouts: Vec<(T::AccountId, BalanceOf<T, I>)>,
+    ) -> Vec<(T::AccountId, BalanceOf<T, I>)> {
+        let (mut payable, dust): (Vec<_>, Vec<_>) = payouts
+            .iter()
+            .cloned()
+            .partition(|(recipient, share)| Self::can_receive_payment(currency, recipient, *share));
+
+        match payable.first_mut() {
+            Some((_, first_share)) => {
+                for (_, share) in dust {
+                    *first_share = first_share.saturating_add(share);
+                }
+                payable
+            }
+            None => payouts,
+        }
+    }
+
+    /// Calculates how an amount is split across the holders of royalty shares.
+    ///
+    /// # It ensures
+    /// - The function performs a read-only operation and does not modify the storage.
+    /// - The issuer and every registered holder receive the share of `amount` their balance of the asset holds
+    ///   in the balances of all of them. Nothing is held for the unregistered holders, so no royalties are left
+    ///   to claim once the shares change hands.
+    /// - The issuer receives the remainder left after rounding, or the whole `amount` if no registered
+    ///   account holds any shares.
+    ///
+    /// # Parameters
+    /// - `shares`: The royalty shares of the entity.
+    /// - `amount`: The total amount to split.
+    ///
+    /// # Returns
+    /// - A vector of recipients with the amount each of them receives, the issuer first.
+    pub fn royalty_share_payouts(
+        shares: &RoyaltySharesFor<T, I>,
+        amount: BalanceOf<T, I>,
+    ) -> Vec<(T::AccountId, BalanceOf<T, I>)> {
+        let balances: Vec<(T::AccountId, AssetBalanceOf<T, I>)> = core::iter::once(&shares.issuer)
+            .chain(shares.holders.iter())
+            .map(|account_id| {
+                (
+                    account_id.clone(),
+                    T::Assets::balance(shares.asset_id.clone(), account_id),
+                )
+            })
+            .collect();
+        let registered = balances.iter().fold(
+            AssetBalanceOf::<T, I>::zero(),
+            |registered, (_, balance)| registered.saturating_add(*balance),
+        );
+
+        let wallets: Vec<Wallet<T::AccountId>> = balances
+            .into_iter()
+            .map(|(address_id, balance)| Wallet {
+                address_id,
+                share: if registered.is_zero() {
+                    Perbill::zero()
+                } else {
+                    Perbill::from_rational(balance, registered)
+                },
+            })
+            .collect();
+
+        Self::royalty_payouts(&wallets, amount)
+    }
+
+    /// Checks whether the royalties of an entity have recipients, its royalty split or the holders of its royalty shares.
+    pub fn has_royalty_recipients(entity_id: T::EntityId, entity: &EntityDetailsFor<T, I>) -> bool {
+        entity
+            .royalty_parts
+            .as_ref()
+            .is_some_and(|parts| !parts.is_empty())
+            || EntityRoyaltyShares::<T, I>::contains_key(entity_id)
+    }
+
+    /// Maximum number of recipients of a royalty payment, the weight of the payments is bounded by it.
+    pub fn max_royalty_recipients() -> u32 {
+        T::MaxRoyaltyParts::get().max(T::MaxRoyaltyShareHolders::get().saturating_add(1))
+    }
+
+    /// Validates a royalty split before it is written to the storage.
+    ///
+    /// # It ensures
+    /// - Every wallet appears in the split only once.
+    /// - Every wallet has a non-zero share.
+    /// - The shares of all wallets sum up to exactly 100%.
+    ///
+    /// # Parameters
+    /// - `royalty_parts`: The wallets of the split with their shares.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::RoyaltyDuplicateWallet` if an `address_id` appears more than once.
+    /// - Returns `Error::<T, I>::RoyaltyZeroShare` if a wallet has a zero share.
+    /// - Returns `Error::<T, I>::RoyaltySharesNotFull` if the shares do not sum up to 100%.
+    pub fn ensure_valid_royalty_parts(royalty_parts: &[Wallet<T::AccountId>]) -> DispatchResult {
+        let mut wallets = BTreeSet::new();
+        let mut total: u32 = 0;
+
+        for wallet in royalty_parts {
+            ensure!(
+                wallets.insert(&wallet.address_id),
+                Error::<T, I>::RoyaltyDuplicateWallet
+            );
+            ensure!(!wallet.share.is_zero(), Error::<T, I>::RoyaltyZeroShare);
+
+            total = total
+                .checked_add(wallet.share.deconstruct())
+                .ok_or(Error::<T, I>::RoyaltySharesNotFull)?;
+        }
+
+        ensure!(
+            total == Perbill::one().deconstruct(),
+            Error::<T, I>::RoyaltySharesNotFull
+        );
+
+        Ok(())
+    }
+
+    /// Retrieves the total royalties an entity received in a currency.
+    pub fn get_royalties_received(
+        entity_id: T::EntityId,
+        currency: PaymentCurrencyFor<T, I>,
+    ) -> BalanceOf<T, I> {
+        RoyaltiesReceived::<T, I>::get(entity_id, currency)
+    }
+}
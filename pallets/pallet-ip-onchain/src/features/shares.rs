@@ -0,0 +1,168 @@
+use crate::*;
+
+use frame::traits::fungibles::{Create as _, Inspect as _, Mutate as _};
+
+use polkadot_sdk::sp_runtime::traits::AccountIdConversion;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Fractionalizes the royalty income of an entity into an asset of the assets pallet.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditEntity` access right for the owning authority of the entity.
+    /// - The authority does not require a proposal for royalty edits.
+    /// - The entity is not frozen by a dispute or a takedown and did not issue royalty shares before.
+    /// - The asset takes the next ID of the range starting at `RoyaltySharesAssetIdStart`, it is created with
+    ///   the pallet as its admin, so no more shares can be minted after the issuance.
+    /// - The `RoyaltySharesDeposit` is held from the caller for as long as the shares exist.
+    /// - The whole `supply` is minted to the caller, who becomes the issuer of the shares.
+    /// - The registered holders always fit in `MaxRoyaltyShareHolders`, since `min_balance` times
+    ///   `MaxRoyaltyShareHolders` covers the `supply`.
+    /// - All royalties of the entity are paid to the holders of the shares from now on, see `royalty_share_payouts`.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the entity.
+    /// - `supply`: The total issuance of the asset, representing 100% of the royalty income.
+    /// - `min_balance`: The minimum balance of the asset an account must hold.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::ProposalRequired` if the authority governs royalty edits by its policy.
+    /// - Returns `Error::<T, I>::RoyaltySharesAlreadyIssued` if the entity already issued royalty shares.
+    /// - Returns `Error::<T, I>::ZeroAmount` if the `supply` is zero.
+    /// - Returns `Error::<T, I>::RoyaltySharesMinBalanceTooLow` if the holders of `min_balance` could exceed
+    ///   `MaxRoyaltyShareHolders`.
+    /// - Returns `Error::<T, I>::RoyaltySharesAssetAlreadyExists` if the next asset ID of the range is taken.
+    /// - Returns `Error::<T, I>::RoyaltySharesAssetIdIncrementFailed` if the range is exhausted.
+    /// - Returns an access control error if the caller does not have the necessary rights.
+    /// - Returns an error if the deposit can not be held or the asset can not be created or minted.
+    ///
+    /// # Events
+    /// - Emits `Event::RoyaltySharesIssued` with the `entity_id`, the `asset_id` and the `supply`.
+    pub(crate) fn add_royalty_shares(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+        supply: AssetBalanceOf<T, I>,
+        min_balance: AssetBalanceOf<T, I>,
+    ) -> DispatchResult {
+        let entity = Self::get_entity(entity_id)?;
+        Self::ensure_not_frozen(entity_id)?;
+        Self::ensure_access_right(
+            &origin,
+            &entity.owner,
+            AuthorityAccessSetting::EditEntity.into(),
+        )?;
+        Self::ensure_not_governed(&entity.owner, GovernedAction::RoyaltyEdit)?;
+        ensure!(
+            !EntityRoyaltyShares::<T, I>::contains_key(entity_id),
+            Error::<T, I>::RoyaltySharesAlreadyIssued
+        );
+        ensure!(!supply.is_zero(), Error::<T, I>::ZeroAmount);
+        ensure!(
+            !min_balance.is_zero()
+                && min_balance.saturating_mul(T::MaxRoyaltyShareHolders::get().into()) >= supply,
+            Error::<T, I>::RoyaltySharesMinBalanceTooLow
+        );
+
+        let asset_id = NextRoyaltySharesAssetId::<T, I>::get()
+            .unwrap_or_else(T::RoyaltySharesAssetIdStart::get);
+        ensure!(
+            !T::Assets::asset_exists(asset_id.clone()),
+            Error::<T, I>::RoyaltySharesAssetAlreadyExists
+        );
+        let next_asset_id = asset_id
+            .increment()
+            .ok_or(Error::<T, I>::RoyaltySharesAssetIdIncrementFailed)?;
+
+        let deposit = Self::update_deposit(None, &origin, T::RoyaltySharesDeposit::get())?;
+        RoyaltySharesDeposits::<T, I>::set(entity_id, deposit);
+
+        T::Assets::create(asset_id.clone(), Self::pallet_account(), false, min_balance)?;
+        T::Assets::mint_into(asset_id.clone(), &origin, supply)?;
+        NextRoyaltySharesAssetId::<T, I>::put(next_asset_id);
+
+        EntityRoyaltyShares::<T, I>::insert(
+            entity_id,
+            RoyaltyShares {
+                asset_id: asset_id.clone(),
+                issuer: origin,
+                holders: BoundedVec::new(),
+            },
+        );
+
+        Self::deposit_event(Event::RoyaltySharesIssued {
+            entity_id,
+            asset_id,
+            supply,
+        });
+
+        Ok(())
+    }
+
+    /// Registers the caller as a holder of the royalty shares of an entity.
+    ///
+    /// # It ensures
+    /// - The caller holds at least the minimum balance of the asset and is neither the issuer nor registered already.
+    /// - Holders whose balance dropped below the minimum balance are removed before the caller is added.
+    /// - The caller receives its share of every following royalty payment of the entity. Royalties paid before
+    ///   the registration are not claimed, they were split across the holders registered at the time.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the entity.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::RoyaltySharesNotFound` if the entity did not issue royalty shares.
+    /// - Returns `Error::<T, I>::RoyaltyShareHolderAlreadyRegistered` if the caller is the issuer or already registered.
+    /// - Returns `Error::<T, I>::RoyaltyShareBalanceTooLow` if the caller holds less than the minimum balance.
+    /// - Returns `Error::<T, I>::LimitExceeded` if the holders exceed `MaxRoyaltyShareHolders`.
+    ///
+    /// # Events
+    /// - Emits `Event::RoyaltyShareHolderRegistered` with the `entity_id` and the `account_id`.
+    pub(crate) fn add_royalty_share_holder(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+    ) -> DispatchResult {
+        let mut shares = Self::get_royalty_shares(entity_id)?;
+        ensure!(
+            shares.issuer != origin && !shares.holders.contains(&origin),
+            Error::<T, I>::RoyaltyShareHolderAlreadyRegistered
+        );
+        let asset_id = shares.asset_id.clone();
+        let min_balance = T::Assets::minimum_balance(asset_id.clone());
+        ensure!(
+            T::Assets::balance(asset_id.clone(), &origin) >= min_balance,
+            Error::<T, I>::RoyaltyShareBalanceTooLow
+        );
+
+        shares
+            .holders
+            .retain(|holder| T::Assets::balance(asset_id.clone(), holder) >= min_balance);
+        shares
+            .holders
+            .try_push(origin.clone())
+            .map_err(|_| Error::<T, I>::LimitExceeded)?;
+        EntityRoyaltyShares::<T, I>::insert(entity_id, shares);
+
+        Self::deposit_event(Event::RoyaltyShareHolderRegistered {
+            entity_id,
+            account_id: origin,
+        });
+
+        Ok(())
+    }
+
+    /// The account of the pallet, the admin of the assets of the royalty shares.
+    pub fn pallet_account() -> T::AccountId {
+        PALLET_ID.into_account_truncating()
+    }
+
+    /// Retrieves the royalty shares issued by an entity.
+    pub fn get_royalty_shares(
+        entity_id: T::EntityId,
+    ) -> Result<RoyaltySharesFor<T, I>, DispatchError> {
+        EntityRoyaltyShares::<T, I>::get(entity_id)
+            .ok_or_else(|| Error::<T, I>::RoyaltySharesNotFound.into())
+    }
+}
@@ -0,0 +1,248 @@
+use crate::*;
+
+use frame::traits::tokens::Preservation;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Offers an entity to another authority.
+    ///
+    /// # It ensures
+    /// - The entity with the given `entity_id` exists in the storage and is not frozen by a dispute or a takedown.
+    /// - The caller has the `EditEntity` access right for the authority owning the entity.
+    /// - The owning authority does not require a proposal for entity transfers.
+    /// - The offer is written by `write_transfer_offer`.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller, it receives the price once the offer is accepted.
+    /// - `entity_id`: The unique identifier of the entity to transfer.
+    /// - `to_authority`: The unique identifier of the receiving authority.
+    /// - `price`: The price the receiving authority pays for the entity, zero for a free transfer.
+    /// - `currency`: The currency the price is paid in.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::AuthorityNotFound` if the receiving authority does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::EntityTransferToOwner` if the receiving authority already owns the entity.
+    /// - Returns `Error::<T, I>::ProposalRequired` if the owning authority governs entity transfers by its policy.
+    /// - Returns an access control error if the caller does not have the necessary rights to edit the entity.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityTransferOffered` with the `entity_id`, the receiving authority, the price and the currency.
+    pub(crate) fn offer_transfer(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+        to_authority: T::AuthorityId,
+        price: BalanceOf<T, I>,
+        currency: PaymentCurrencyFor<T, I>,
+    ) -> DispatchResult {
+        let entity = Self::get_entity(entity_id)?;
+        Self::ensure_access_right(
+            &origin,
+            &entity.owner,
+            AuthorityAccessSetting::EditEntity.into(),
+        )?;
+        Self::ensure_not_governed(&entity.owner, GovernedAction::EntityTransfer)?;
+
+        Self::write_transfer_offer(entity_id, &entity, to_authority, price, currency, origin)
+    }
+
+    /// Writes the pending transfer offer of an entity.
+    ///
+    /// # It ensures
+    /// - The entity is not frozen by a dispute or a takedown.
+    /// - The receiving authority exists and is not the current owner of the entity.
+    /// - The currency of the offer is one of the accepted payment currencies.
+    /// - A previous pending offer of the entity is replaced.
+    ///
+    /// # Parameters
+    /// - `entity_id`: The unique identifier of the entity to transfer.
+    /// - `entity`: The details of the entity.
+    /// - `to_authority`: The unique identifier of the receiving authority.
+    /// - `price`: The price the receiving authority pays for the entity, zero for a free transfer.
+    /// - `currency`: The currency the price is paid in.
+    /// - `beneficiary`: The account ID receiving the price once the offer is accepted.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::AuthorityNotFound` if the receiving authority does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::EntityTransferToOwner` if the receiving authority already owns the entity.
+    /// - Returns `Error::<T, I>::PaymentCurrencyNotSupported` if the currency of the offer is not accepted.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityTransferOffered` with the `entity_id`, the receiving authority, the price and the currency.
+    pub(crate) fn write_transfer_offer(
+        entity_id: T::EntityId,
+        entity: &EntityDetailsFor<T, I>,
+        to_authority: T::AuthorityId,
+        price: BalanceOf<T, I>,
+        currency: PaymentCurrencyFor<T, I>,
+        beneficiary: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_not_frozen(entity_id)?;
+        ensure!(
+            entity.owner != to_authority,
+            Error::<T, I>::EntityTransferToOwner
+        );
+        Self::get_authority(to_authority)?;
+        Self::ensure_payment_currency(&currency)?;
+
+        EntityTransfers::<T, I>::insert(
+            entity_id,
+            EntityTransfer {
+                to_authority,
+                price,
+                beneficiary,
+                currency: currency.clone(),
+            },
+        );
+
+        Self::deposit_event(Event::EntityTransferOffered {
+            entity_id,
+            to_authority,
+            price,
+            currency,
+        });
+
+        Ok(())
+    }
+
+    /// Cancels the pending transfer offer of an entity.
+    ///
+    /// # It ensures
+    /// - The caller has the `EditEntity` access right for the authority owning the entity,
+    ///   or the `CreateEntity` access right for the receiving authority to decline the offer.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller.
+    /// - `entity_id`: The unique identifier of the offered entity.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityTransferNotFound` if there is no pending offer for the entity.
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns an access control error if the caller has rights on neither side of the transfer.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityTransferCancelled` with the `entity_id`.
+    pub(crate) fn cancel_transfer(origin: T::AccountId, entity_id: T::EntityId) -> DispatchResult {
+        let transfer =
+            EntityTransfers::<T, I>::get(entity_id).ok_or(Error::<T, I>::EntityTransferNotFound)?;
+        let entity = Self::get_entity(entity_id)?;
+
+        Self::ensure_access_right(
+            &origin,
+            &transfer.to_authority,
+            AuthorityAccessSetting::CreateEntity.into(),
+        )
+        .or_else(|_| {
+            Self::ensure_access_right(
+                &origin,
+                &entity.owner,
+                AuthorityAccessSetting::EditEntity.into(),
+            )
+        })?;
+
+        EntityTransfers::<T, I>::remove(entity_id);
+
+        Self::deposit_event(Event::EntityTransferCancelled { entity_id });
+
+        Ok(())
+    }
+
+    /// Accepts the pending transfer offer of an entity.
+    ///
+    /// # It ensures
+    /// - The caller has the `CreateEntity` access right for the receiving authority.
+    /// - The entity is not frozen by a dispute or a takedown.
+    /// - The beneficiary of the offer still has access to the authority owning the entity, an offer made by
+    ///   an account whose access was removed or expired can only be cancelled.
+    /// - The price is paid by the caller in the currency of the offer directly to the beneficiary of the offer.
+    /// - The NFT linked to the entity moves to the collection of the receiving authority, if it has one,
+    ///   and the new owner is mirrored into it.
+    /// - Keeps the `EntitiesByAuthority` storage in sync with the new owner.
+    /// - The new owner is kept as the next revision of the entity with the caller as its editor.
+    ///
+    /// # Parameters
+    /// - `origin`: The account ID of the caller, it pays the price.
+    /// - `entity_id`: The unique identifier of the offered entity.
+    /// - `max_price`: The maximum price the caller agrees to pay.
+    /// - `currency`: The currency the caller agrees to pay in, an offer priced in another currency is rejected.
+    ///
+    /// # Errors
+    /// - Returns `Error::<T, I>::EntityTransferNotFound` if there is no pending offer for the entity.
+    /// - Returns `Error::<T, I>::EntityTransferPriceTooHigh` if the offer price is above `max_price`.
+    /// - Returns `Error::<T, I>::PaymentCurrencyMismatch` if the offer is priced in another currency than `currency`.
+    /// - Returns `Error::<T, I>::EntityFrozen` if the entity is disputed or taken down.
+    /// - Returns `Error::<T, I>::EntityNotFound` if the entity with the given `entity_id` does not exist in the storage.
+    /// - Returns `Error::<T, I>::EntityTransferBeneficiaryRevoked` if the beneficiary lost its access to the owning authority.
+    /// - Returns an access control error if the caller does not have the necessary rights to create entities
+    ///   for the receiving authority.
+    /// - Returns an error if the payment or the NFT move fails.
+    ///
+    /// # Events
+    /// - Emits `Event::EntityTransferred` with the `entity_id`, both authorities, the price and the currency.
+    pub(crate) fn accept_transfer(
+        origin: T::AccountId,
+        entity_id: T::EntityId,
+        max_price: BalanceOf<T, I>,
+        currency: PaymentCurrencyFor<T, I>,
+    ) -> DispatchResult {
+        let transfer =
+            EntityTransfers::<T, I>::get(entity_id).ok_or(Error::<T, I>::EntityTransferNotFound)?;
+        Self::ensure_access_right(
+            &origin,
+            &transfer.to_authority,
+            AuthorityAccessSetting::CreateEntity.into(),
+        )?;
+        ensure!(
+            transfer.price <= max_price,
+            Error::<T, I>::EntityTransferPriceTooHigh
+        );
+        ensure!(
+            transfer.currency == currency,
+            Error::<T, I>::PaymentCurrencyMismatch
+        );
+        Self::ensure_not_frozen(entity_id)?;
+        let entity = Self::get_entity(entity_id)?;
+        ensure!(
+            Self::get_access(entity.owner, &transfer.beneficiary).is_some(),
+            Error::<T, I>::EntityTransferBeneficiaryRevoked
+        );
+
+        if !transfer.price.is_zero() {
+            Self::transfer_payment(
+                &transfer.currency,
+                &origin,
+                &transfer.beneficiary,
+                transfer.price,
+                Preservation::Preserve,
+            )?;
+        }
+
+        let from_authority =
+            Entities::<T, I>::try_mutate(entity_id, |maybe_entity| -> Result<_, DispatchError> {
+                let entity = maybe_entity.as_mut().ok_or(Error::<T, I>::EntityNotFound)?;
+                let old_entity = entity.clone();
+
+                Self::move_entity_nft(entity_id, entity, &transfer.to_authority)?;
+                entity.owner = transfer.to_authority;
+                Self::mirror_entity_nft(entity_id, entity)?;
+
+                Self::index_entity(entity_id, Some(&old_entity), Some(entity));
+                Self::bump_revision(entity_id, entity, &origin)?;
+
+                Ok(old_entity.owner)
+            })?;
+
+        EntityTransfers::<T, I>::remove(entity_id);
+
+        Self::deposit_event(Event::EntityTransferred {
+            entity_id,
+            from_authority,
+            to_authority: transfer.to_authority,
+            price: transfer.price,
+            currency: transfer.currency,
+        });
+
+        Ok(())
+    }
+}
@@ -5,21 +5,26 @@ use polkadot_sdk::pallet_nfts;
 use polkadot_sdk::pallet_nfts::ItemConfig;
 use polkadot_sdk::polkadot_sdk_frame as frame;
 
+use frame::arithmetic::Perbill;
 use frame::traits::Contains;
 
 use frame::prelude::*;
 
 use frame::traits::{
-    tokens::nonfungibles_v2::{Create, Mutate},
-    Currency, Get, Incrementable,
+    fungible, fungibles,
+    tokens::nonfungibles_v2::{Create, Mutate, Transfer},
+    Get, Incrementable,
 };
 
 use scale_codec::{Decode, Encode, MaxEncodedLen};
 
 mod features;
+pub mod migrations;
+mod traits;
 mod types;
 
 pub use pallet::*;
+pub use traits::*;
 pub use types::*;
 pub mod external_nfts_macros;
 pub mod weights;
@@ -37,12 +42,16 @@ pub use weights::WeightInfo;
 
 pub const LOG_TARGET: &str = "runtime::ip-onchain";
 
+/// Account of the pallet, the admin of the assets of the royalty shares.
+const PALLET_ID: polkadot_sdk::frame_support::PalletId =
+    polkadot_sdk::frame_support::PalletId(*b"ip/onchn");
+
 /// TODO benchmarking for all calls, and remove dev_mode for pallet
 #[frame::pallet]
 pub mod pallet {
     use super::*;
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -93,8 +102,56 @@ pub mod pallet {
         #[pallet::constant]
         type MaxArrayLen: Get<u32>;
 
+        /// Maximum number of entities visited while checking new relations for cycles.
+        #[pallet::constant]
+        type MaxLineageLookups: Get<u32>;
+
+        #[pallet::constant]
+        type MaxLicenseOffers: Get<u32>;
+
+        #[pallet::constant]
+        type MaxHolderLicenses: Get<u32>;
+
+        /// Maximum length of the content hash, CID and fingerprint digest of an entity.
+        #[pallet::constant]
+        type MaxContentHashLength: Get<u32>;
+
+        /// Maximum number of revisions kept in the history of an entity, older revisions are pruned.
+        #[pallet::constant]
+        type MaxEntityRevisions: Get<u32>;
+
+        /// Maximum number of tracks of a release.
+        #[pallet::constant]
+        type MaxReleaseTracks: Get<u32>;
+
+        /// Maximum number of accounts an account can delegate its access to per authority.
+        #[pallet::constant]
+        type MaxAccessDelegates: Get<u32>;
+
+        /// Maximum number of approvals an authority policy can require.
+        #[pallet::constant]
+        type MaxPolicyThreshold: Get<u32>;
+
+        /// Maximum number of pending proposals of an authority.
+        #[pallet::constant]
+        type MaxAuthorityProposals: Get<u32>;
+
+        /// Maximum resale royalty an authority or an entity can take from the sales of entity NFTs.
+        #[pallet::constant]
+        type MaxResaleRoyalty: Get<Perbill>;
+
+        /// Maximum number of registered holders of the royalty shares of an entity.
+        #[pallet::constant]
+        type MaxRoyaltyShareHolders: Get<u32>;
+
         type WhiteListChecker: Contains<Self::AccountId>;
 
+        /// Origin allowed to attest the identity of authors.
+        type VerifierOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to resolve disputes.
+        type ArbiterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         type CollectionId: Member + Parameter + MaxEncodedLen + Copy + Incrementable;
         type ItemId: Member + Parameter + MaxEncodedLen + Copy;
 
@@ -108,9 +165,76 @@ pub mod pallet {
             + Clone;
 
         type Nfts: Mutate<Self::AccountId, ItemConfig, ItemId = Self::ItemId>
-            + Create<Self::AccountId, Self::CollectionConfig, CollectionId = Self::CollectionId>;
+            + Create<Self::AccountId, Self::CollectionConfig, CollectionId = Self::CollectionId>
+            + Transfer<Self::AccountId>;
+
+        type Currency: fungible::Inspect<Self::AccountId>
+            + fungible::Mutate<Self::AccountId>
+            + fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// Assets the royalty shares of the entities are issued in, their IDs are taken in order from
+        /// `RoyaltySharesAssetIdStart`.
+        type Assets: fungibles::Inspect<Self::AccountId, AssetId: Incrementable>
+            + fungibles::Create<Self::AccountId>
+            + fungibles::Mutate<Self::AccountId>;
+
+        /// Assets royalties, licenses and entity transfers can be paid in besides the native `Currency`.
+        type Payments: fungibles::Inspect<Self::AccountId, Balance = BalanceOf<Self, I>>
+            + fungibles::Mutate<Self::AccountId>;
+
+        /// Assets of `Payments` accepted as `PaymentCurrency::Asset`. The native currency must be excluded
+        /// if `Payments` contains it, so that it is paid and accounted only as `PaymentCurrency::Native`.
+        type PaymentAssets: Contains<
+            <Self::Payments as fungibles::Inspect<Self::AccountId>>::AssetId,
+        >;
+
+        /// First asset ID of the range reserved for the assets of the royalty shares. The runtime must not let
+        /// accounts create assets of `Assets` in the range.
+        #[pallet::constant]
+        type RoyaltySharesAssetIdStart: Get<AssetIdOf<Self, I>>;
+
+        /// Overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason<I>>;
 
-        type Currency: Currency<Self::AccountId>;
+        /// Base deposit held for an authority.
+        #[pallet::constant]
+        type AuthorityDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Base deposit held for an author.
+        #[pallet::constant]
+        type AuthorDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Base deposit held for an entity.
+        #[pallet::constant]
+        type EntityDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Deposit held per revision kept in the history of an entity, it covers the largest details of an entity.
+        #[pallet::constant]
+        type RevisionDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Deposit held for an access row of an authority.
+        #[pallet::constant]
+        type AccessDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Deposit held from the proposer of a pending proposal of an authority.
+        #[pallet::constant]
+        type ProposalDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Deposit held from the holder per license kept for an entity.
+        #[pallet::constant]
+        type LicenseDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Deposit held from the issuer of the royalty shares of an entity, it covers the asset of the shares.
+        #[pallet::constant]
+        type RoyaltySharesDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Deposit held per byte of the strings of an authority, author or entity.
+        #[pallet::constant]
+        type ByteDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Bond held from the claimant of a dispute.
+        #[pallet::constant]
+        type DisputeBond: Get<BalanceOf<Self, I>>;
 
         type RuntimeEvent: From<Event<Self, I>>
             + IsType<<Self as polkadot_sdk::frame_system::Config>::RuntimeEvent>;
@@ -118,7 +242,7 @@ pub mod pallet {
         type WeightInfo: weights::WeightInfo;
 
         #[cfg(feature = "runtime-benchmarks")]
-        type BenchmarkHelper: BenchmarkHelper<Self::CollectionId, Self::ItemId>;
+        type BenchmarkHelper: BenchmarkHelper<Self::CollectionId, Self::ItemId, AssetIdOf<Self, I>>;
     }
 
     #[pallet::storage]
@@ -137,16 +261,341 @@ pub mod pallet {
     >;
 
     #[pallet::storage]
-    pub(super) type Authors<T: Config<I>, I: 'static = ()> = StorageMap<
+    pub(super) type Authors<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AuthorId, AuthorFor<T, I>>;
+
+    #[pallet::storage]
+    pub(super) type Entities<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::EntityId, EntityDetailsFor<T, I>>;
+
+    /// Latest revisions of the details of an entity: entity -> revision -> revision details.
+    #[pallet::storage]
+    pub(super) type EntityRevisions<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::EntityId,
+        Blake2_128Concat,
+        u32,
+        EntityRevisionFor<T, I>,
+        OptionQuery,
+    >;
+
+    /// Reverse index of `EntityDetails.related_to`: source entity -> derivative entity -> kind.
+    #[pallet::storage]
+    pub(super) type Derivatives<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::EntityId,
+        Blake2_128Concat,
+        T::EntityId,
+        RelationKind,
+        OptionQuery,
+    >;
+
+    /// Ordered tracks of a release: release entity -> track entities.
+    #[pallet::storage]
+    pub(super) type ReleaseTracks<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::EntityId,
+        BoundedVec<ReleaseMember<T::EntityId>, T::MaxReleaseTracks>,
+        ValueQuery,
+    >;
+
+    /// Reverse index of `ReleaseTracks`: track entity -> release entity.
+    #[pallet::storage]
+    pub(super) type TrackReleases<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::EntityId,
+        Blake2_128Concat,
+        T::EntityId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Reverse lookup indexes
+    #[pallet::storage]
+    pub(super) type EntitiesByAuthority<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuthorityId,
+        Blake2_128Concat,
+        T::EntityId,
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    pub(super) type EntitiesByAuthor<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         T::AuthorId,
-        AuthorDetails<T::AccountId, T::MaxShortStringLength, T::MaxLongStringLength>,
+        Blake2_128Concat,
+        T::EntityId,
+        (),
+        OptionQuery,
     >;
 
+    /// Uniqueness index of `ContentCommitment.content_hash`.
     #[pallet::storage]
-    pub(super) type Entities<T: Config<I>, I: 'static = ()> =
-        StorageMap<_, Blake2_128Concat, T::EntityId, EntityDetailsFor<T, I>>;
+    pub(super) type EntitiesByContentHash<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, ContentHashFor<T, I>, T::EntityId>;
+
+    /// Uniqueness indexes of `EntityDetails.identifiers` and `AuthorDetails.ipi`.
+    #[pallet::storage]
+    pub(super) type EntitiesByIdentifier<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, IndustryIdentifier, T::EntityId>;
+
+    #[pallet::storage]
+    pub(super) type AuthorsByIpi<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, Ipi, T::AuthorId>;
+
+    /// Reverse index of `EntityDetails.collection_id` and `EntityDetails.item_id`: NFT -> entity.
+    #[pallet::storage]
+    pub(super) type EntitiesByNft<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CollectionId,
+        Blake2_128Concat,
+        T::ItemId,
+        T::EntityId,
+        OptionQuery,
+    >;
+
+    /// Item configs chosen for the NFTs of the entities, the NFT is minted with them again
+    /// when it moves into the collection of another authority.
+    #[pallet::storage]
+    pub(super) type EntityNftItemConfigs<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::EntityId, pallet_nfts::ItemConfig>;
+
+    #[pallet::storage]
+    pub(super) type AuthorsByOwner<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AuthorId,
+        (),
+        OptionQuery,
+    >;
+
+    /// `AuthoritiesAccess` keyed account-first.
+    #[pallet::storage]
+    pub(super) type AccessByAccount<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AuthorityId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Expiry blocks of `AuthoritiesAccess` rows, an expired row is treated as absent until it is pruned.
+    #[pallet::storage]
+    pub(super) type AccessExpiries<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuthorityId,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Delegated accesses: authority -> delegate -> delegator.
+    #[pallet::storage]
+    pub(super) type AccessDelegators<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuthorityId,
+        Blake2_128Concat,
+        T::AccountId,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    /// `AccessDelegators` keyed delegator-first.
+    #[pallet::storage]
+    pub(super) type AccessDelegates<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuthorityId,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::AccountId, T::MaxAccessDelegates>,
+        ValueQuery,
+    >;
+
+    /// Pending access invitations, the access is granted once the invited account accepts it.
+    #[pallet::storage]
+    pub(super) type AccessInvitations<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuthorityId,
+        Blake2_128Concat,
+        T::AccountId,
+        AccessInvitationFor<T>,
+        OptionQuery,
+    >;
+
+    /// Number of accounts holding the `EditAccess` right for an authority.
+    #[pallet::storage]
+    pub(super) type EditAccessHolders<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AuthorityId, u32, ValueQuery>;
+
+    /// License offers of an entity with their ids, removing an offer leaves the ids of the others unchanged.
+    #[pallet::storage]
+    pub(super) type LicenseOffers<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::EntityId,
+        BoundedVec<(u32, LicenseOfferFor<T, I>), T::MaxLicenseOffers>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    pub(super) type NextLicenseOfferId<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::EntityId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    pub(super) type Licenses<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::EntityId,
+        BoundedVec<LicenseFor<T, I>, T::MaxHolderLicenses>,
+        ValueQuery,
+    >;
+
+    /// Pending entity transfer offers.
+    #[pallet::storage]
+    pub(super) type EntityTransfers<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::EntityId, EntityTransferFor<T, I>>;
+
+    /// Resale royalties taken from the sales of the NFTs of the entities of an authority.
+    #[pallet::storage]
+    pub(super) type AuthorityResaleRoyalties<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AuthorityId, Perbill>;
+
+    /// Resale royalties of single entities, they override the resale royalty of the owning authority.
+    #[pallet::storage]
+    pub(super) type EntityResaleRoyalties<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::EntityId, Perbill>;
+
+    /// Royalty shares of the entities, they replace the royalty split of an entity once issued.
+    #[pallet::storage]
+    pub(super) type EntityRoyaltyShares<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::EntityId, RoyaltySharesFor<T, I>>;
+
+    /// Total royalties received by the entities: entity -> currency -> amount.
+    #[pallet::storage]
+    pub(super) type RoyaltiesReceived<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::EntityId,
+        Blake2_128Concat,
+        PaymentCurrencyFor<T, I>,
+        BalanceOf<T, I>,
+        ValueQuery,
+    >;
+
+    /// Entity NFTs listed for sale by their holders.
+    #[pallet::storage]
+    pub(super) type NftListings<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::EntityId, NftListingFor<T, I>>;
+
+    /// Open disputes, an entity is frozen while it is disputed.
+    #[pallet::storage]
+    pub(super) type Disputes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::EntityId, DisputeFor<T, I>>;
+
+    /// Entities taken down by an upheld dispute, they stay frozen and can not be removed.
+    #[pallet::storage]
+    pub(super) type TakenDownEntities<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::EntityId, (), OptionQuery>;
+
+    /// Threshold policies of the authorities.
+    #[pallet::storage]
+    pub(super) type AuthorityPolicies<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AuthorityId, AuthorityPolicy>;
+
+    /// Pending proposals: authority -> proposal -> proposal details.
+    #[pallet::storage]
+    pub(super) type Proposals<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuthorityId,
+        Blake2_128Concat,
+        u32,
+        AuthorityProposalFor<T, I>,
+        OptionQuery,
+    >;
+
+    /// Number of pending proposals of an authority.
+    #[pallet::storage]
+    pub(super) type PendingProposals<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AuthorityId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    pub(super) type NextProposalId<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AuthorityId, u32, ValueQuery>;
+
+    /// Deposits held for the records
+    #[pallet::storage]
+    pub(super) type AuthorityDeposits<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AuthorityId, DepositFor<T, I>>;
+
+    #[pallet::storage]
+    pub(super) type AuthorDeposits<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AuthorId, DepositFor<T, I>>;
+
+    #[pallet::storage]
+    pub(super) type EntityDeposits<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::EntityId, DepositFor<T, I>>;
+
+    #[pallet::storage]
+    pub(super) type AccessDeposits<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuthorityId,
+        Blake2_128Concat,
+        T::AccountId,
+        DepositFor<T, I>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    pub(super) type ProposalDeposits<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuthorityId,
+        Blake2_128Concat,
+        u32,
+        DepositFor<T, I>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    pub(super) type LicenseDeposits<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::EntityId,
+        DepositFor<T, I>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    pub(super) type RoyaltySharesDeposits<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::EntityId, DepositFor<T, I>>;
+
+    /// Lets accounts that are not whitelisted call the pallet, they only post deposits.
+    #[pallet::storage]
+    pub type PermissionlessMode<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
 
     /// Incrementable storages
     ///
@@ -162,9 +611,22 @@ pub mod pallet {
     pub type NextEntityId<T: Config<I>, I: 'static = ()> =
         StorageValue<_, T::EntityId, OptionQuery>;
 
+    #[pallet::storage]
+    pub type NextRoyaltySharesAssetId<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, AssetIdOf<T, I>, OptionQuery>;
+
     #[pallet::storage]
     pub type NftsSupport<T: Config<I>, I: 'static = ()> = StorageValue<_, bool>;
 
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason<I: 'static = ()> {
+        /// The funds are held as the storage deposit of a record.
+        StorageDeposit,
+        /// The funds are held as the bond of a dispute.
+        DisputeBond,
+    }
+
     /// Events
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -176,14 +638,33 @@ pub mod pallet {
         AuthorEdited {
             author_id: T::AuthorId,
         },
-
-        /// Authority events
-        AuthorityAdded {
-            authority_id: T::AuthorityId,
+        AuthorRemoved {
+            author_id: T::AuthorId,
+        },
+        AuthorClaimed {
+            author_id: T::AuthorId,
+        },
+        AuthorVerified {
+            author_id: T::AuthorId,
+        },
+        AuthorVerificationRevoked {
+            author_id: T::AuthorId,
+        },
+        AuthorIpiSet {
+            author_id: T::AuthorId,
+            ipi: Option<Ipi>,
+        },
+
+        /// Authority events
+        AuthorityAdded {
+            authority_id: T::AuthorityId,
         },
         AuthorityEdited {
             authority_id: T::AuthorityId,
         },
+        AuthorityRemoved {
+            authority_id: T::AuthorityId,
+        },
         AuthoritiesAccessAdded {
             authority_id: T::AuthorityId,
             account_id: T::AccountId,
@@ -192,6 +673,55 @@ pub mod pallet {
             authority_id: T::AuthorityId,
             account_id: T::AccountId,
         },
+        AuthoritiesAccessRemoved {
+            authority_id: T::AuthorityId,
+            account_id: T::AccountId,
+        },
+        AuthoritiesAccessExpired {
+            authority_id: T::AuthorityId,
+            account_id: T::AccountId,
+        },
+        AccessInvited {
+            authority_id: T::AuthorityId,
+            account_id: T::AccountId,
+        },
+        AccessInvitationCancelled {
+            authority_id: T::AuthorityId,
+            account_id: T::AccountId,
+        },
+        AccessDelegated {
+            authority_id: T::AuthorityId,
+            delegator: T::AccountId,
+            account_id: T::AccountId,
+        },
+        AccessDelegationRevoked {
+            authority_id: T::AuthorityId,
+            account_id: T::AccountId,
+        },
+
+        /// Governance events
+        AuthorityPolicySet {
+            authority_id: T::AuthorityId,
+            policy: Option<AuthorityPolicy>,
+        },
+        ProposalCreated {
+            authority_id: T::AuthorityId,
+            proposal_id: u32,
+            proposer: T::AccountId,
+        },
+        ProposalApproved {
+            authority_id: T::AuthorityId,
+            proposal_id: u32,
+            approver: T::AccountId,
+        },
+        ProposalExecuted {
+            authority_id: T::AuthorityId,
+            proposal_id: u32,
+        },
+        ProposalCancelled {
+            authority_id: T::AuthorityId,
+            proposal_id: u32,
+        },
 
         /// Entity events
         EntityAdded {
@@ -200,6 +730,136 @@ pub mod pallet {
         EntityEdited {
             entity_id: T::EntityId,
         },
+        EntityRemoved {
+            entity_id: T::EntityId,
+        },
+        ReleaseAdded {
+            entity_id: T::EntityId,
+            track_ids: BoundedVec<T::EntityId, T::MaxReleaseTracks>,
+        },
+        ReleaseTracksSet {
+            entity_id: T::EntityId,
+        },
+        ReleaseTrackWithdrawn {
+            entity_id: T::EntityId,
+            track_id: T::EntityId,
+        },
+        EntityTransferOffered {
+            entity_id: T::EntityId,
+            to_authority: T::AuthorityId,
+            price: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+        },
+        EntityTransferCancelled {
+            entity_id: T::EntityId,
+        },
+        EntityTransferred {
+            entity_id: T::EntityId,
+            from_authority: T::AuthorityId,
+            to_authority: T::AuthorityId,
+            price: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+        },
+        EntityContentAnchored {
+            entity_id: T::EntityId,
+            content_hash: ContentHashFor<T, I>,
+        },
+        EntityContentCleared {
+            entity_id: T::EntityId,
+        },
+        EntityIdentifiersSet {
+            entity_id: T::EntityId,
+        },
+        EntityNftDetached {
+            entity_id: T::EntityId,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+        },
+        EntityNftBurned {
+            entity_id: T::EntityId,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+        },
+        EntityNftListed {
+            entity_id: T::EntityId,
+            seller: T::AccountId,
+            price: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+        },
+        EntityNftDelisted {
+            entity_id: T::EntityId,
+        },
+        EntityNftSold {
+            entity_id: T::EntityId,
+            seller: T::AccountId,
+            buyer: T::AccountId,
+            price: BalanceOf<T, I>,
+            royalty: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+        },
+        EntityNftGifted {
+            entity_id: T::EntityId,
+            from: T::AccountId,
+            to: T::AccountId,
+        },
+
+        /// Royalty events
+        RoyaltyPaid {
+            entity_id: T::EntityId,
+            payer: T::AccountId,
+            recipient: T::AccountId,
+            amount: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+        },
+        ResaleRoyaltySet {
+            authority_id: T::AuthorityId,
+            entity_id: Option<T::EntityId>,
+            royalty: Option<Perbill>,
+        },
+        RoyaltySharesIssued {
+            entity_id: T::EntityId,
+            asset_id: AssetIdOf<T, I>,
+            supply: AssetBalanceOf<T, I>,
+        },
+        RoyaltyShareHolderRegistered {
+            entity_id: T::EntityId,
+            account_id: T::AccountId,
+        },
+
+        /// Dispute events
+        DisputeOpened {
+            entity_id: T::EntityId,
+            claimant: T::AccountId,
+        },
+        DisputeResolved {
+            entity_id: T::EntityId,
+            resolution: DisputeResolution<T::AuthorityId>,
+        },
+        EntityReinstated {
+            entity_id: T::EntityId,
+        },
+
+        /// License events
+        LicenseOfferAdded {
+            entity_id: T::EntityId,
+            offer_id: u32,
+        },
+        LicenseOfferRemoved {
+            entity_id: T::EntityId,
+            offer_id: u32,
+        },
+        LicensePurchased {
+            entity_id: T::EntityId,
+            buyer: T::AccountId,
+            kind: LicenseKind,
+            price: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+        },
+        LicensesPruned {
+            entity_id: T::EntityId,
+            holder: T::AccountId,
+            pruned: u32,
+        },
     }
 
     /// Errors
@@ -209,11 +869,17 @@ pub mod pallet {
         AuthorAlreadyExists,
         AuthorNotFound,
         AuthorIdIncrementFailed,
+        AuthorHasEntities,
+        AuthorAlreadyClaimed,
+        AuthorNotClaimed,
+        AuthorNotVerified,
 
         /// Authority errors
         AuthorityAlreadyExists,
         AuthorityNotFound,
         AuthorityIdIncrementFailed,
+        AuthorityHasEntities,
+        AuthorityHasCollection,
         /// Authority nft extension Error
         AuthorityNftCollectionIdAlreadyExist,
 
@@ -221,6 +887,26 @@ pub mod pallet {
         AuthoritiesAccessNotFound,
         AuthoritiesAccessExist,
         AuthoritiesAccessNotExist,
+        LastEditAccessHolder,
+        AccessExpiryInvalid,
+        AccessNotExpired,
+        EditAccessCannotExpire,
+        AccessInvitationPending,
+        AccessInvitationNotFound,
+        AuthorityHasInvitations,
+        AccessDelegated,
+        DelegationInvalid,
+        DelegationNotFound,
+
+        /// Governance errors
+        AuthorityPolicyNotFound,
+        AuthorityHasProposals,
+        PolicyThresholdInvalid,
+        PolicyActionsInvalid,
+        PolicyThresholdUnreachable,
+        ProposalRequired,
+        ProposalNotFound,
+        ProposalAlreadyApproved,
 
         /// Entity errors
         EntityAlreadyExists,
@@ -228,13 +914,80 @@ pub mod pallet {
         EntityIdIncrementFailed,
         EntityAuthorNotFound,
         EntityRelatedEntityNotFound,
+        EntityRelationToSelf,
+        EntityRelationDuplicate,
+        EntityRelationCycle,
+        EntityLineageTooLarge,
         EntityNftOwnerMustBeSpecified,
         EntityNftImmutable,
+        EntityNftNotFound,
+        EntityNftAlreadyLinked,
+        EntityNftHolderInvalid,
+        EntityNftRecipientInvalid,
+        EntityNftListingNotFound,
+        EntityNftPriceTooHigh,
+        EntityHasDerivatives,
+        EntityHasRoyaltyShares,
+        EntityTransferNotFound,
+        EntityTransferToOwner,
+        EntityTransferPriceTooHigh,
+        EntityTransferBeneficiaryRevoked,
+        EntityContentHashInvalid,
+        EntityContentAlreadyAnchored,
+        EntityContentImmutable,
+        ReleaseNotFound,
+        ReleaseKindInvalid,
+        ReleaseTracksNotFound,
+        ReleaseTrackInvalid,
+        ReleaseTrackDuplicate,
+        ReleaseTrackNumberInvalid,
+        ReleaseTrackNotFound,
+        ReleaseHasTracks,
+        EntityInRelease,
+
+        /// Royalty errors
+        EntityRoyaltyPartsNotFound,
+        RoyaltyDuplicateWallet,
+        RoyaltyZeroShare,
+        RoyaltySharesNotFull,
+        ResaleRoyaltyTooHigh,
+        RoyaltySharesAlreadyIssued,
+        RoyaltySharesNotFound,
+        RoyaltySharesMinBalanceTooLow,
+        RoyaltySharesAssetAlreadyExists,
+        RoyaltySharesAssetIdIncrementFailed,
+        RoyaltyShareHolderAlreadyRegistered,
+        RoyaltyShareBalanceTooLow,
+
+        /// Payment errors
+        PaymentCurrencyNotSupported,
+        PaymentCurrencyMismatch,
+
+        /// License errors
+        LicenseOfferNotFound,
+        LicensePriceTooHigh,
+        LicenseNotFound,
+
+        /// Dispute errors
+        DisputeAlreadyOpen,
+        DisputeNotFound,
+        EntityFrozen,
+        EntityNotTakenDown,
+
+        /// Identifier errors
+        IdentifierInvalid,
+        IdentifierAlreadyRegistered,
+
+        /// Metadata errors
+        MetadataStandardNotSupported,
+        MetadataUrlInvalid,
 
         /// General Errors
         Overflow, // checked_add failed
         LimitExceeded,
         BadFormat,
+        BadWitness,
+        ZeroAmount,
 
         /// Permissions Errors
         NoPermission,
@@ -256,10 +1009,7 @@ pub mod pallet {
             owner: Option<T::AccountId>,
         ) -> DispatchResult {
             let origin = ensure_signed(origin)?;
-            ensure!(
-                T::WhiteListChecker::contains(&origin),
-                Error::<T, I>::NotWhitelisted
-            );
+            Self::ensure_whitelisted(&origin)?;
             Self::add_new_author(origin, nickname, real_name, owner)?;
             Ok(())
         }
@@ -272,10 +1022,7 @@ pub mod pallet {
             new_owner: Option<T::AccountId>,
         ) -> DispatchResult {
             let origin = ensure_signed(origin)?;
-            ensure!(
-                T::WhiteListChecker::contains(&origin),
-                Error::<T, I>::NotWhitelisted
-            );
+            Self::ensure_whitelisted(&origin)?;
             Self::set_author(origin, author_id, real_name, new_owner)?;
             Ok(())
         }
@@ -290,10 +1037,7 @@ pub mod pallet {
             collection_cfg: Option<T::CollectionConfig>,
         ) -> DispatchResult {
             let origin = ensure_signed(origin)?;
-            ensure!(
-                T::WhiteListChecker::contains(&origin),
-                Error::<T, I>::NotWhitelisted
-            );
+            Self::ensure_whitelisted(&origin)?;
             Self::add_new_authority(origin, name, authority_kind, collection_cfg)?;
             Ok(())
         }
@@ -307,10 +1051,7 @@ pub mod pallet {
             init_collection_id: Option<T::CollectionConfig>,
         ) -> DispatchResult {
             let origin = ensure_signed(origin)?;
-            ensure!(
-                T::WhiteListChecker::contains(&origin),
-                Error::<T, I>::NotWhitelisted
-            );
+            Self::ensure_whitelisted(&origin)?;
             Self::set_authority(
                 origin,
                 authority_id,
@@ -333,16 +1074,13 @@ pub mod pallet {
             metadata_features: MetadataFeatures,
             authors: Option<BoundedVec<T::AuthorId, T::MaxEntityAuthors>>,
             royalty_parts: Option<BoundedVec<Wallet<T::AccountId>, T::MaxRoyaltyParts>>,
-            related_entities: Option<BoundedVec<T::EntityId, T::MaxRelatedEntities>>,
+            related_entities: Option<BoundedVec<Relation<T::EntityId>, T::MaxRelatedEntities>>,
             nft_item_id: Option<T::ItemId>,
             nft_owner: Option<T::AccountId>,
             nft_item_config: Option<pallet_nfts::ItemConfig>,
         ) -> DispatchResult {
             let origin = ensure_signed(origin)?;
-            ensure!(
-                T::WhiteListChecker::contains(&origin),
-                Error::<T, I>::NotWhitelisted
-            );
+            Self::ensure_whitelisted(&origin)?;
             Self::add_new_entity(
                 origin,
                 entity_kind,
@@ -360,7 +1098,9 @@ pub mod pallet {
             Ok(())
         }
         #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::edit_entity())]
+        #[pallet::weight(T::WeightInfo::edit_entity().saturating_add(
+            T::DbWeight::get().reads(T::MaxLineageLookups::get().into())
+        ))]
         pub fn edit_entity(
             origin: OriginFor<T>,
             entity_id: T::EntityId,
@@ -370,16 +1110,13 @@ pub mod pallet {
             owner: Option<T::AuthorityId>,
             authors: Option<BoundedVec<T::AuthorId, T::MaxEntityAuthors>>,
             royalty_parts: Option<BoundedVec<Wallet<T::AccountId>, T::MaxRoyaltyParts>>,
-            related_entities: Option<BoundedVec<T::EntityId, T::MaxRelatedEntities>>,
+            related_entities: Option<BoundedVec<Relation<T::EntityId>, T::MaxRelatedEntities>>,
             nft_item_id: Option<T::ItemId>,
             nft_owner: Option<T::AccountId>,
             nft_item_config: Option<pallet_nfts::ItemConfig>,
         ) -> DispatchResult {
             let origin = ensure_signed(origin)?;
-            ensure!(
-                T::WhiteListChecker::contains(&origin),
-                Error::<T, I>::NotWhitelisted
-            );
+            Self::ensure_whitelisted(&origin)?;
             Self::set_entity(
                 origin,
                 entity_id,
@@ -404,30 +1141,29 @@ pub mod pallet {
             authority_id: T::AuthorityId,
             account_id: T::AccountId,
             access: AuthorityAccessSettings,
+            expires_at: Option<BlockNumberFor<T>>,
         ) -> DispatchResult {
             let origin = ensure_signed(origin)?;
-            ensure!(
-                T::WhiteListChecker::contains(&origin),
-                Error::<T, I>::NotWhitelisted
-            );
-            Self::add_access(origin, authority_id, account_id, access)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::add_access(origin, authority_id, account_id, access, expires_at)?;
             Ok(())
         }
 
         #[pallet::call_index(7)]
-        #[pallet::weight(T::WeightInfo::edit_account_access())]
+        #[pallet::weight(T::WeightInfo::edit_account_access().saturating_add(
+            T::WeightInfo::revoke_delegated_access()
+                .saturating_mul(T::MaxAccessDelegates::get().into())
+        ))]
         pub fn edit_account_access(
             origin: OriginFor<T>,
             authority_id: T::AuthorityId,
             account_id: T::AccountId,
             access: AuthorityAccessSettings,
+            expires_at: Option<BlockNumberFor<T>>,
         ) -> DispatchResult {
             let origin = ensure_signed(origin)?;
-            ensure!(
-                T::WhiteListChecker::contains(&origin),
-                Error::<T, I>::NotWhitelisted
-            );
-            Self::set_access(origin, authority_id, account_id, access)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::set_access(origin, authority_id, account_id, access, expires_at)?;
             Ok(())
         }
 
@@ -437,18 +1173,606 @@ pub mod pallet {
             ensure_root(origin)?;
             Self::toggle_nfts_support()
         }
+
+        /// Royalty calls
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::pay_royalties(Pallet::<T, I>::max_royalty_recipients()))]
+        pub fn pay_royalties(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            amount: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::distribute_royalties(&origin, entity_id, &currency, amount)
+        }
+
+        /// License calls
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::add_license_offer())]
+        pub fn add_license_offer(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            offer: LicenseOfferFor<T, I>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::add_new_license_offer(origin, entity_id, offer)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::remove_license_offer())]
+        pub fn remove_license_offer(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            offer_id: u32,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::delete_license_offer(origin, entity_id, offer_id)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::purchase_license(Pallet::<T, I>::max_royalty_recipients()))]
+        pub fn purchase_license(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            offer_id: u32,
+            max_price: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::buy_license(origin, entity_id, offer_id, max_price, currency)?;
+            Ok(())
+        }
+
+        /// Removal calls
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::remove_author())]
+        pub fn remove_author(origin: OriginFor<T>, author_id: T::AuthorId) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::delete_author(origin, author_id)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::remove_authority(*access_count))]
+        pub fn remove_authority(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            access_count: u32,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::delete_authority(origin, authority_id, access_count)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::remove_entity(
+            T::MaxEntityAuthors::get(),
+            T::MaxRelatedEntities::get()
+        ).saturating_add(T::DbWeight::get().reads_writes(
+            T::MaxEntityRevisions::get().saturating_add(*currency_count).into(),
+            T::MaxEntityRevisions::get().saturating_add(*currency_count).into()
+        )))]
+        pub fn remove_entity(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            currency_count: u32,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::delete_entity(origin, entity_id, currency_count)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::remove_account_access().saturating_add(
+            T::WeightInfo::revoke_delegated_access()
+                .saturating_mul(T::MaxAccessDelegates::get().into())
+        ))]
+        pub fn remove_account_access(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            account_id: T::AccountId,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::remove_access(origin, authority_id, account_id)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::offer_entity_transfer())]
+        pub fn offer_entity_transfer(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            to_authority: T::AuthorityId,
+            price: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::offer_transfer(origin, entity_id, to_authority, price, currency)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::cancel_entity_transfer())]
+        pub fn cancel_entity_transfer(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::cancel_transfer(origin, entity_id)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::accept_entity_transfer())]
+        pub fn accept_entity_transfer(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            max_price: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::accept_transfer(origin, entity_id, max_price, currency)?;
+            Ok(())
+        }
+
+        /// The owner of an author is not required to be whitelisted to claim it.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::claim_author())]
+        pub fn claim_author(origin: OriginFor<T>, author_id: T::AuthorId) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::claim_author_owner(origin, author_id)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::verify_author())]
+        pub fn verify_author(
+            origin: OriginFor<T>,
+            author_id: T::AuthorId,
+            verified: bool,
+        ) -> DispatchResult {
+            T::VerifierOrigin::ensure_origin(origin)?;
+            Self::set_author_verification(author_id, verified)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::set_permissionless_mode())]
+        pub fn set_permissionless_mode(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::set_permissionless(enabled);
+            Ok(())
+        }
+
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::set_entity_content())]
+        pub fn set_entity_content(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            content: Option<ContentCommitmentFor<T, I>>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::set_content(origin, entity_id, content)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::set_entity_identifiers())]
+        pub fn set_entity_identifiers(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            identifiers: EntityIdentifiers,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::set_identifiers(origin, entity_id, identifiers)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::set_author_ipi())]
+        pub fn set_author_ipi(
+            origin: OriginFor<T>,
+            author_id: T::AuthorId,
+            ipi: Option<Ipi>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::set_ipi(origin, author_id, ipi)?;
+            Ok(())
+        }
+
+        /// Dispute calls
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::open_dispute())]
+        pub fn open_dispute(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            evidence_url: BoundedVec<u8, T::MaxLongStringLength>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::add_dispute(origin, entity_id, evidence_url)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::resolve_dispute())]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            resolution: DisputeResolution<T::AuthorityId>,
+        ) -> DispatchResult {
+            T::ArbiterOrigin::ensure_origin(origin)?;
+            Self::settle_dispute(entity_id, resolution)?;
+            Ok(())
+        }
+
+        /// Governance calls
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::set_authority_policy())]
+        pub fn set_authority_policy(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            policy: AuthorityPolicy,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::set_policy(origin, authority_id, policy)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::propose_authority_action())]
+        pub fn propose_authority_action(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            action: AuthorityActionFor<T, I>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::add_proposal(origin, authority_id, action)?;
+            Ok(())
+        }
+
+        /// The weight covers the most expensive action executed by the last approval.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::approve_authority_proposal().saturating_add(
+            T::WeightInfo::create_account_access()
+                .max(T::WeightInfo::offer_entity_transfer())
+                .max(T::WeightInfo::edit_entity())
+                .max(T::WeightInfo::set_resale_royalty())
+        ).saturating_add(
+            T::WeightInfo::revoke_delegated_access()
+                .saturating_mul(T::MaxAccessDelegates::get().into())
+        ))]
+        pub fn approve_authority_proposal(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            proposal_id: u32,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::add_approval(origin, authority_id, proposal_id)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::cancel_authority_proposal())]
+        pub fn cancel_authority_proposal(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            proposal_id: u32,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::remove_proposal(origin, authority_id, proposal_id)?;
+            Ok(())
+        }
+
+        /// Access invitation calls
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::invite_account_access())]
+        pub fn invite_account_access(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            account_id: T::AccountId,
+            access: AuthorityAccessSettings,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::add_invitation(origin, authority_id, account_id, access, expires_at)?;
+            Ok(())
+        }
+
+        /// The invited account is not required to be whitelisted to accept the invitation.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::accept_account_access())]
+        pub fn accept_account_access(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::accept_invitation(origin, authority_id)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::cancel_account_access_invitation())]
+        pub fn cancel_account_access_invitation(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            account_id: T::AccountId,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::remove_invitation(origin, authority_id, account_id)?;
+            Ok(())
+        }
+
+        /// Anyone can prune an expired access, its deposit returns to its depositor.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::prune_expired_account_access().saturating_add(
+            T::WeightInfo::revoke_delegated_access()
+                .saturating_mul(T::MaxAccessDelegates::get().into())
+        ))]
+        pub fn prune_expired_account_access(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            account_id: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(
+                Self::prune_expired_access(authority_id, &account_id)?,
+                Error::<T, I>::AccessNotExpired
+            );
+            Ok(())
+        }
+
+        /// Delegation calls
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::delegate_account_access())]
+        pub fn delegate_account_access(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            account_id: T::AccountId,
+            access: AuthorityAccessSettings,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::add_delegation(origin, authority_id, account_id, access)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::revoke_delegated_access())]
+        pub fn revoke_delegated_access(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            account_id: T::AccountId,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::remove_delegation(origin, authority_id, account_id)?;
+            Ok(())
+        }
+
+        /// Release calls
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::create_release(tracks.len() as u32))]
+        pub fn create_release(
+            origin: OriginFor<T>,
+            entity_kind: IPEntityKind,
+            owner: T::AuthorityId,
+            url: BoundedVec<u8, T::MaxLongStringLength>,
+            metadata_standard: MetadataStandard,
+            metadata_features: MetadataFeatures,
+            authors: Option<BoundedVec<T::AuthorId, T::MaxEntityAuthors>>,
+            royalty_parts: Option<BoundedVec<Wallet<T::AccountId>, T::MaxRoyaltyParts>>,
+            tracks: BoundedVec<ReleaseTrackFor<T, I>, T::MaxReleaseTracks>,
+            nft_item_id: Option<T::ItemId>,
+            nft_owner: Option<T::AccountId>,
+            nft_item_config: Option<pallet_nfts::ItemConfig>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::add_release(
+                origin,
+                entity_kind,
+                owner,
+                url,
+                metadata_standard,
+                metadata_features,
+                authors,
+                royalty_parts,
+                tracks,
+                nft_item_id,
+                nft_owner,
+                nft_item_config,
+            )?;
+            Ok(())
+        }
+
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::set_release_tracks(tracks.len() as u32))]
+        pub fn set_release_tracks(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            tracks: BoundedVec<ReleaseMember<T::EntityId>, T::MaxReleaseTracks>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::set_release_members(origin, entity_id, tracks)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::withdraw_release_track())]
+        pub fn withdraw_release_track(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            track_id: T::EntityId,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::remove_release_member(origin, entity_id, track_id)?;
+            Ok(())
+        }
+
+        /// Entity NFT calls
+        #[pallet::call_index(41)]
+        #[pallet::weight(T::WeightInfo::detach_entity_nft())]
+        pub fn detach_entity_nft(origin: OriginFor<T>, entity_id: T::EntityId) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::detach_nft(origin, entity_id)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(42)]
+        #[pallet::weight(T::WeightInfo::burn_entity_nft())]
+        pub fn burn_entity_nft(origin: OriginFor<T>, entity_id: T::EntityId) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::burn_nft(origin, entity_id)?;
+            Ok(())
+        }
+
+        /// Resale royalty calls
+        #[pallet::call_index(43)]
+        #[pallet::weight(T::WeightInfo::set_resale_royalty())]
+        pub fn set_resale_royalty(
+            origin: OriginFor<T>,
+            authority_id: T::AuthorityId,
+            entity_id: Option<T::EntityId>,
+            royalty: Option<Perbill>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::change_resale_royalty(origin, authority_id, entity_id, royalty)?;
+            Ok(())
+        }
+
+        /// Entity NFT holders are not required to be whitelisted to trade them.
+        #[pallet::call_index(44)]
+        #[pallet::weight(T::WeightInfo::list_entity_nft())]
+        pub fn list_entity_nft(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            price: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+            buyer: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::list_nft(origin, entity_id, price, currency, buyer)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(45)]
+        #[pallet::weight(T::WeightInfo::delist_entity_nft())]
+        pub fn delist_entity_nft(origin: OriginFor<T>, entity_id: T::EntityId) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::delist_nft(origin, entity_id)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(46)]
+        #[pallet::weight(T::WeightInfo::buy_entity_nft(Pallet::<T, I>::max_royalty_recipients()))]
+        pub fn buy_entity_nft(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            max_price: BalanceOf<T, I>,
+            currency: PaymentCurrencyFor<T, I>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::buy_nft(origin, entity_id, max_price, currency)?;
+            Ok(())
+        }
+
+        /// Royalty share calls
+        #[pallet::call_index(47)]
+        #[pallet::weight(T::WeightInfo::issue_royalty_shares())]
+        pub fn issue_royalty_shares(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            supply: AssetBalanceOf<T, I>,
+            min_balance: AssetBalanceOf<T, I>,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::ensure_whitelisted(&origin)?;
+            Self::add_royalty_shares(origin, entity_id, supply, min_balance)?;
+            Ok(())
+        }
+
+        /// Holders of royalty shares are not required to be whitelisted to register.
+        #[pallet::call_index(48)]
+        #[pallet::weight(T::WeightInfo::register_royalty_share_holder(
+            T::MaxRoyaltyShareHolders::get()
+        ))]
+        pub fn register_royalty_share_holder(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::add_royalty_share_holder(origin, entity_id)?;
+            Ok(())
+        }
+
+        /// Entity NFT holders are not required to be whitelisted to give them back to the owning authority.
+        #[pallet::call_index(49)]
+        #[pallet::weight(T::WeightInfo::gift_entity_nft())]
+        pub fn gift_entity_nft(
+            origin: OriginFor<T>,
+            entity_id: T::EntityId,
+            to: T::AccountId,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::gift_nft(origin, entity_id, to)?;
+            Ok(())
+        }
+
+        /// License holders are not required to be whitelisted to release the deposits of their expired licenses.
+        #[pallet::call_index(50)]
+        #[pallet::weight(T::WeightInfo::prune_licenses())]
+        pub fn prune_licenses(origin: OriginFor<T>, entity_id: T::EntityId) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+            Self::prune_expired_licenses(origin, entity_id)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(51)]
+        #[pallet::weight(T::WeightInfo::reinstate_entity())]
+        pub fn reinstate_entity(origin: OriginFor<T>, entity_id: T::EntityId) -> DispatchResult {
+            T::ArbiterOrigin::ensure_origin(origin)?;
+            Self::reinstate_taken_down_entity(entity_id)?;
+            Ok(())
+        }
     }
 
     #[cfg(feature = "runtime-benchmarks")]
-    pub trait BenchmarkHelper<CollectionId, ItemId> {
+    pub trait BenchmarkHelper<CollectionId, ItemId, AssetId> {
         fn collection_id(i: u32) -> CollectionId;
         fn item_id(i: u32) -> ItemId;
+        fn asset_id(i: u32) -> AssetId;
     }
     #[cfg(feature = "runtime-benchmarks")]
-    impl<CollectionId, ItemId> BenchmarkHelper<CollectionId, ItemId> for ()
+    impl<CollectionId, ItemId, AssetId> BenchmarkHelper<CollectionId, ItemId, AssetId> for ()
     where
         CollectionId: From<u32>,
         ItemId: From<u32>,
+        AssetId: From<u32>,
     {
         fn collection_id(i: u32) -> CollectionId {
             i.into()
@@ -457,5 +1781,9 @@ pub mod pallet {
         fn item_id(i: u32) -> ItemId {
             i.into()
         }
+
+        fn asset_id(i: u32) -> AssetId {
+            i.into()
+        }
     }
 }
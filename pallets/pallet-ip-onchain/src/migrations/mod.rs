@@ -0,0 +1,5 @@
+//! Storage migrations of the pallet.
+//!
+//! Every module migrates the storage from the previous storage version to the one in its name.
+
+pub mod v2;
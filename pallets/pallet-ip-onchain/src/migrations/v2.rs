@@ -0,0 +1,490 @@
+//! Migrates the storage from the layout of storage version 1 to the current layout.
+//!
+//! The migration runs in the multi-block migrations pallet over as many blocks as it needs. Every step
+//! migrates the records one at a time in the order of their storage keys, the reverse indexes are built
+//! in the same pass. The chain only includes inherents until the last record is migrated, since the
+//! storage of the pallet holds both layouts in the meantime. The storage version is set to `2` at the end.
+//!
+//! - Royalty splits move from `Wallet.weight: u32` to normalized `Wallet.share: Perbill`. Weights of
+//!   the same wallet are merged, zero weights are dropped and the remaining weights are converted to
+//!   shares that sum up to exactly 100%. The rounding remainder is added to the first wallet, the same
+//!   way royalty payouts handle it. Splits without any weight are removed.
+//! - The untyped `related_to: Vec<EntityId>` becomes typed relations, indexed in `Derivatives`. The
+//!   kind of a migrated relation is `GeneratedFrom` for generative entities and `RemixOf` for all other
+//!   entities. Self-references and duplicated related entities are dropped.
+//! - Nothing proves who registered the existing authors, so all of them become `Unclaimed`. Their owners
+//!   claim them again with `claim_author`, until then the authors can not be edited, removed or credited on
+//!   new entities. The entities already crediting them keep their authors.
+//! - No existing entity or author has anchored content or registered an identifier, so the
+//!   `EntitiesByContentHash`, `EntitiesByIdentifier` and `AuthorsByIpi` indexes start empty.
+//! - The blocks the existing records were created in are unknown, their `created_at` is the `0`
+//!   sentinel and their `updated_at` is the block they were migrated in. The existing entities start
+//!   at revision `0` with an empty `EntityRevisions` history.
+//! - The `EntitiesByAuthority`, `EntitiesByAuthor`, `EntitiesByNft`, `AuthorsByOwner`,
+//!   `AccessByAccount` and `EditAccessHolders` indexes are built from the existing records.
+//! - The NFTs of the existing entities are locked for transfers in the NFTs pallet, so that they are
+//!   only sold through `buy_entity_nft` and pay the resale royalty of their entity. Burned NFTs are
+//!   skipped, an NFT that can not be locked is logged and left transferable.
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::*;
+
+use frame::arithmetic::Perbill;
+use frame::traits::tokens::nonfungibles_v2::Inspect;
+use polkadot_sdk::frame_support::{
+    migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+    storage_alias,
+    weights::WeightMeter,
+};
+
+#[cfg(feature = "try-runtime")]
+use polkadot_sdk::sp_runtime::TryRuntimeError;
+
+/// Storage types of the storage version 1.
+pub mod v1 {
+    use super::*;
+
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+    pub struct AuthorityDetails<ShortStringLimit: Get<u32>, CollectionId> {
+        pub authority_kind: AuthorityKind,
+        pub name: BoundedVec<u8, ShortStringLimit>,
+        pub collection_id: Option<CollectionId>,
+    }
+
+    pub type AuthorityDetailsFor<T, I = ()> =
+        AuthorityDetails<<T as Config<I>>::MaxShortStringLength, <T as Config<I>>::CollectionId>;
+
+    #[storage_alias]
+    pub type Authorities<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        <T as Config<I>>::AuthorityId,
+        AuthorityDetailsFor<T, I>,
+    >;
+
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+    pub struct AuthorDetails<AccountId, ShortStringLimit: Get<u32>, LongStringLimit: Get<u32>> {
+        pub nickname: BoundedVec<u8, ShortStringLimit>,
+        pub real_name: Option<BoundedVec<u8, LongStringLimit>>,
+        pub owner: AccountId,
+    }
+
+    pub type AuthorFor<T, I = ()> = AuthorDetails<
+        <T as frame_system::Config>::AccountId,
+        <T as Config<I>>::MaxShortStringLength,
+        <T as Config<I>>::MaxLongStringLength,
+    >;
+
+    #[storage_alias]
+    pub type Authors<T: Config<I>, I: 'static> =
+        StorageMap<Pallet<T, I>, Blake2_128Concat, <T as Config<I>>::AuthorId, AuthorFor<T, I>>;
+
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+    pub struct Wallet<AccountId> {
+        pub address_id: AccountId,
+        pub weight: u32,
+    }
+
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+    pub struct EntityDetails<
+        AuthorityId,
+        AuthorId,
+        EntityId,
+        Wallet,
+        Metadata,
+        MaxEntityAuthors: Get<u32>,
+        MaxRoyaltyParts: Get<u32>,
+        MaxRelatedEntities: Get<u32>,
+        CollectionId,
+        ItemId,
+    > {
+        pub entity_kind: IPEntityKind,
+        pub owner: AuthorityId,
+
+        pub authors: Option<BoundedVec<AuthorId, MaxEntityAuthors>>,
+        pub royalty_parts: Option<BoundedVec<Wallet, MaxRoyaltyParts>>,
+        pub related_to: Option<BoundedVec<EntityId, MaxRelatedEntities>>,
+
+        pub metadata: Metadata,
+
+        pub collection_id: Option<CollectionId>,
+        pub item_id: Option<ItemId>,
+    }
+
+    pub type EntityDetailsFor<T, I = ()> = EntityDetails<
+        <T as Config<I>>::AuthorityId,
+        <T as Config<I>>::AuthorId,
+        <T as Config<I>>::EntityId,
+        Wallet<<T as frame_system::Config>::AccountId>,
+        Metadata<<T as Config<I>>::MaxLongStringLength>,
+        <T as Config<I>>::MaxEntityAuthors,
+        <T as Config<I>>::MaxRoyaltyParts,
+        <T as Config<I>>::MaxRelatedEntities,
+        <T as Config<I>>::CollectionId,
+        <T as Config<I>>::ItemId,
+    >;
+
+    #[storage_alias]
+    pub type Entities<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        <T as Config<I>>::EntityId,
+        EntityDetailsFor<T, I>,
+    >;
+}
+
+/// Identifier of the migration in the multi-block migrations pallet.
+const MIGRATION_ID: [u8; 17] = *b"pallet-ip-onchain";
+
+/// Progress of the migration, the storage being migrated with the last key migrated in it.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub enum MigrationCursor<AuthorityId, AuthorId, EntityId, AccountId> {
+    Authorities(Option<AuthorityId>),
+    Authors(Option<AuthorId>),
+    Entities(Option<EntityId>),
+    AuthoritiesAccess(Option<(AuthorityId, AccountId)>),
+}
+
+pub type MigrationCursorFor<T, I = ()> = MigrationCursor<
+    <T as Config<I>>::AuthorityId,
+    <T as Config<I>>::AuthorId,
+    <T as Config<I>>::EntityId,
+    <T as frame_system::Config>::AccountId,
+>;
+
+/// Migrates the storage from version 1 to version 2 over as many blocks as it needs, see the module
+/// documentation. Every step migrates the records one by one until the weight meter of the block is used up.
+pub struct MigrateV1ToV2<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> SteppedMigration for MigrateV1ToV2<T, I> {
+    type Cursor = MigrationCursorFor<T, I>;
+    type Identifier = MigrationId<17>;
+
+    fn id() -> Self::Identifier {
+        MigrationId {
+            pallet_id: MIGRATION_ID,
+            version_from: 1,
+            version_to: 2,
+        }
+    }
+
+    fn step(
+        cursor: Option<Self::Cursor>,
+        meter: &mut WeightMeter,
+    ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+        if Pallet::<T, I>::on_chain_storage_version() != 1 {
+            return Ok(None);
+        }
+
+        let mut cursor = cursor.unwrap_or(MigrationCursor::Authorities(None));
+        let required = Self::record_weight(&cursor);
+        if meter.remaining().any_lt(required) {
+            return Err(SteppedMigrationError::InsufficientWeight { required });
+        }
+
+        while meter.try_consume(Self::record_weight(&cursor)).is_ok() {
+            match Self::migrate_next(cursor) {
+                Some(next) => cursor = next,
+                None => {
+                    StorageVersion::new(2).put::<Pallet<T, I>>();
+                    log::info!(target: LOG_TARGET, "migrated the storage to version 2");
+                    return Ok(None);
+                }
+            }
+        }
+
+        Ok(Some(cursor))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let authorities = v1::Authorities::<T, I>::iter_keys().count() as u32;
+        let authors = v1::Authors::<T, I>::iter_keys().count() as u32;
+        let entities = v1::Entities::<T, I>::iter_keys().count() as u32;
+        Ok((authorities, authors, entities).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let (authorities, authors, entities) = <(u32, u32, u32)>::decode(&mut &state[..])
+            .map_err(|_| "cannot decode the authorities, authors and entities counts")?;
+
+        ensure!(
+            Pallet::<T, I>::on_chain_storage_version() == 2,
+            "storage version is not updated"
+        );
+        ensure!(
+            Authorities::<T, I>::iter_values().count() as u32 == authorities,
+            "authorities count changed during the migration"
+        );
+
+        let mut count: u32 = 0;
+        for (author_id, author) in Authors::<T, I>::iter() {
+            ensure!(
+                AuthorsByOwner::<T, I>::contains_key(author.owner, author_id),
+                "author is missing in the owner index"
+            );
+            count += 1;
+        }
+        ensure!(
+            count == authors,
+            "authors count changed during the migration"
+        );
+
+        let mut count: u32 = 0;
+        for (entity_id, entity) in Entities::<T, I>::iter() {
+            if let Some(royalty_parts) = &entity.royalty_parts {
+                Pallet::<T, I>::ensure_valid_royalty_parts(royalty_parts)?;
+            }
+            for relation in entity.related_to.iter().flatten() {
+                ensure!(
+                    Derivatives::<T, I>::get(relation.entity_id, entity_id) == Some(relation.kind),
+                    "relation is missing in the derivatives index"
+                );
+            }
+            ensure!(
+                EntitiesByAuthority::<T, I>::contains_key(entity.owner, entity_id),
+                "entity is missing in the authority index"
+            );
+            for author_id in entity.authors.iter().flatten() {
+                ensure!(
+                    EntitiesByAuthor::<T, I>::contains_key(author_id, entity_id),
+                    "entity is missing in the author index"
+                );
+            }
+            if let (Some(collection_id), Some(item_id)) = (entity.collection_id, entity.item_id) {
+                ensure!(
+                    EntitiesByNft::<T, I>::get(collection_id, item_id) == Some(entity_id),
+                    "NFT of an entity is not indexed"
+                );
+            }
+            count += 1;
+        }
+        ensure!(
+            count == entities,
+            "entities count changed during the migration"
+        );
+
+        ensure!(
+            AuthoritiesAccess::<T, I>::iter_keys().count()
+                == AccessByAccount::<T, I>::iter_keys().count(),
+            "access settings are missing in the account index"
+        );
+        for authority_id in Authorities::<T, I>::iter_keys() {
+            let holders = AuthoritiesAccess::<T, I>::iter_prefix_values(authority_id)
+                .filter(|access| access.has_access(AuthorityAccessSetting::EditAccess.into()))
+                .count() as u32;
+            ensure!(
+                EditAccessHolders::<T, I>::get(authority_id) == holders,
+                "edit access holders count does not match the access settings"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Config<I>, I: 'static> MigrateV1ToV2<T, I> {
+    /// Weight of migrating the record after the cursor, the worst case of the storage being migrated.
+    fn record_weight(cursor: &MigrationCursorFor<T, I>) -> Weight {
+        match cursor {
+            MigrationCursor::Authorities(_) => T::WeightInfo::migrate_v2_authority(),
+            MigrationCursor::Authors(_) => T::WeightInfo::migrate_v2_author(),
+            MigrationCursor::Entities(_) => T::WeightInfo::migrate_v2_entity(
+                T::MaxEntityAuthors::get(),
+                T::MaxRelatedEntities::get(),
+            ),
+            MigrationCursor::AuthoritiesAccess(_) => T::WeightInfo::migrate_v2_access(),
+        }
+    }
+
+    /// Migrates the record after the cursor, the migration moves on to the next storage once the current one
+    /// is migrated.
+    ///
+    /// # Returns
+    /// - The cursor of the migrated record, `None` once all records are migrated.
+    pub(crate) fn migrate_next(
+        cursor: MigrationCursorFor<T, I>,
+    ) -> Option<MigrationCursorFor<T, I>> {
+        let now = frame_system::Pallet::<T>::block_number();
+
+        match cursor {
+            MigrationCursor::Authorities(last) => {
+                let mut records = match last {
+                    Some(last) => v1::Authorities::<T, I>::iter_from(
+                        v1::Authorities::<T, I>::hashed_key_for(last),
+                    ),
+                    None => v1::Authorities::<T, I>::iter(),
+                };
+                let Some((authority_id, old)) = records.next() else {
+                    return Some(MigrationCursor::Authors(None));
+                };
+
+                let mut authority = AuthorityDetailsFor::<T, I>::from_v1(&(), old);
+                authority.updated_at = now;
+                Authorities::<T, I>::insert(authority_id, authority);
+
+                Some(MigrationCursor::Authorities(Some(authority_id)))
+            }
+            MigrationCursor::Authors(last) => {
+                let mut records = match last {
+                    Some(last) => {
+                        v1::Authors::<T, I>::iter_from(v1::Authors::<T, I>::hashed_key_for(last))
+                    }
+                    None => v1::Authors::<T, I>::iter(),
+                };
+                let Some((author_id, old)) = records.next() else {
+                    return Some(MigrationCursor::Entities(None));
+                };
+
+                let mut author = AuthorFor::<T, I>::from_v1(&(), old);
+                author.updated_at = now;
+                Pallet::<T, I>::index_author(author_id, None, Some(&author.owner));
+                Authors::<T, I>::insert(author_id, author);
+
+                Some(MigrationCursor::Authors(Some(author_id)))
+            }
+            MigrationCursor::Entities(last) => {
+                let mut records = match last {
+                    Some(last) => {
+                        v1::Entities::<T, I>::iter_from(v1::Entities::<T, I>::hashed_key_for(last))
+                    }
+                    None => v1::Entities::<T, I>::iter(),
+                };
+                let Some((entity_id, old)) = records.next() else {
+                    return Some(MigrationCursor::AuthoritiesAccess(None));
+                };
+
+                let mut entity = EntityDetailsFor::<T, I>::from_v1(&entity_id, old);
+                entity.updated_at = now;
+
+                for relation in entity.related_to.iter().flatten() {
+                    Derivatives::<T, I>::insert(relation.entity_id, entity_id, relation.kind);
+                }
+
+                EntitiesByAuthority::<T, I>::insert(entity.owner, entity_id, ());
+                for author_id in entity.authors.iter().flatten() {
+                    EntitiesByAuthor::<T, I>::insert(author_id, entity_id, ());
+                }
+
+                if let (Some(collection_id), Some(item_id)) = (entity.collection_id, entity.item_id)
+                {
+                    EntitiesByNft::<T, I>::insert(collection_id, item_id, entity_id);
+
+                    if T::Nfts::owner(&collection_id, &item_id).is_some() {
+                        if let Err(error) =
+                            Pallet::<T, I>::lock_entity_nft(&collection_id, &item_id)
+                        {
+                            log::warn!(
+                                target: LOG_TARGET,
+                                "failed to lock an entity NFT: {:?}",
+                                error
+                            );
+                        }
+                    }
+                }
+
+                Entities::<T, I>::insert(entity_id, entity);
+
+                Some(MigrationCursor::Entities(Some(entity_id)))
+            }
+            MigrationCursor::AuthoritiesAccess(last) => {
+                let mut records = match last {
+                    Some((authority_id, account_id)) => AuthoritiesAccess::<T, I>::iter_from(
+                        AuthoritiesAccess::<T, I>::hashed_key_for(authority_id, account_id),
+                    ),
+                    None => AuthoritiesAccess::<T, I>::iter(),
+                };
+                let (authority_id, account_id, access) = records.next()?;
+
+                AccessByAccount::<T, I>::insert(&account_id, authority_id, ());
+                if access.has_access(AuthorityAccessSetting::EditAccess.into()) {
+                    EditAccessHolders::<T, I>::mutate(authority_id, |holders| {
+                        *holders = holders.saturating_add(1)
+                    });
+                }
+
+                Some(MigrationCursor::AuthoritiesAccess(Some((
+                    authority_id,
+                    account_id,
+                ))))
+            }
+        }
+    }
+}
+
+/// Converts a weighted split of storage version 1 to a valid split of shares.
+pub(crate) fn normalize_royalty_parts<AccountId: PartialEq, MaxRoyaltyParts: Get<u32>>(
+    royalty_parts: BoundedVec<v1::Wallet<AccountId>, MaxRoyaltyParts>,
+) -> Option<BoundedVec<Wallet<AccountId>, MaxRoyaltyParts>> {
+    let mut weights: Vec<(AccountId, u128)> = Vec::new();
+    for wallet in royalty_parts.into_iter().filter(|wallet| wallet.weight > 0) {
+        match weights
+            .iter_mut()
+            .find(|(address_id, _)| *address_id == wallet.address_id)
+        {
+            Some((_, weight)) => *weight += wallet.weight as u128,
+            None => weights.push((wallet.address_id, wallet.weight as u128)),
+        }
+    }
+
+    let total_weight: u128 = weights.iter().map(|(_, weight)| weight).sum();
+    if total_weight == 0 {
+        return None;
+    }
+
+    let accuracy = Perbill::one().deconstruct();
+    let mut wallets: Vec<Wallet<AccountId>> = weights
+        .into_iter()
+        .map(|(address_id, weight)| Wallet {
+            address_id,
+            share: Perbill::from_parts((weight * accuracy as u128 / total_weight) as u32),
+        })
+        .collect();
+
+    let distributed: u32 = wallets
+        .iter()
+        .map(|wallet| wallet.share.deconstruct())
+        .sum();
+    if let Some(first) = wallets.first_mut() {
+        first.share = Perbill::from_parts(first.share.deconstruct() + (accuracy - distributed));
+    }
+
+    // Shares rounded down to zero were folded into the first wallet.
+    wallets.retain(|wallet| !wallet.share.is_zero());
+
+    BoundedVec::try_from(wallets).ok()
+}
+
+/// Converts the untyped related entities of storage version 1 to typed relations.
+pub(crate) fn migrate_relations<EntityId: PartialEq, MaxRelatedEntities: Get<u32>>(
+    entity_id: &EntityId,
+    entity_kind: &IPEntityKind,
+    related_to: BoundedVec<EntityId, MaxRelatedEntities>,
+) -> BoundedVec<Relation<EntityId>, MaxRelatedEntities> {
+    let kind = match entity_kind {
+        IPEntityKind::GenerativeTrack | IPEntityKind::GenerativeSample => {
+            RelationKind::GeneratedFrom
+        }
+        _ => RelationKind::RemixOf,
+    };
+
+    let mut relations: Vec<Relation<EntityId>> = Vec::new();
+    for source_id in related_to {
+        if source_id != *entity_id
+            && !relations
+                .iter()
+                .any(|relation| relation.entity_id == source_id)
+        {
+            relations.push(Relation {
+                kind,
+                entity_id: source_id,
+            });
+        }
+    }
+
+    // The relations are never longer than the untyped list they are built from.
+    BoundedVec::truncate_from(relations)
+}
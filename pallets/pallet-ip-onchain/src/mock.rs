@@ -1,14 +1,25 @@
-use polkadot_sdk::pallet_nfts::ItemConfig;
+use polkadot_sdk::pallet_assets;
+use polkadot_sdk::pallet_balances;
+use polkadot_sdk::pallet_nfts::{ItemConfig, PalletAttributes};
 use polkadot_sdk::polkadot_sdk_frame as frame;
 
 use frame::{
-    deps::frame_system::GenesisConfig,
+    arithmetic::Perbill,
+    deps::{
+        frame_support::traits::{
+            fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
+            AsEnsureOriginWithArg, Equals, EverythingBut,
+        },
+        frame_system::GenesisConfig,
+    },
     prelude::*,
     runtime::prelude::*,
     testing_prelude::*,
-    traits::tokens::nonfungibles_v2::{Create, Inspect, Mutate},
+    traits::tokens::nonfungibles_v2::{Create, Inspect, Mutate, Transfer},
 };
 
+use std::collections::BTreeMap;
+
 type Block = frame_system::mocking::MockBlock<Test>;
 
 #[frame_construct_runtime]
@@ -32,14 +43,38 @@ mod runtime {
 
     #[runtime::pallet_index(1)]
     pub type CustomPallet = crate;
+
+    #[runtime::pallet_index(2)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(3)]
+    pub type Assets = pallet_assets;
 }
 
 // System pallet configuration
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
     type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
 }
 
+// Balances pallet configuration
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+// Assets pallet configuration
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+    type Freezer = ();
+}
+
+pub type NativeAndAssets = UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<u32>, u64>;
+
 // Custom pallet configuration
 parameter_types! {
     pub const MaxShortStringLength: u32 = 32;
@@ -48,13 +83,41 @@ parameter_types! {
     pub const MaxRoyaltyParts: u32 = 10;
     pub const MaxRelatedEntities: u32 = 10;
     pub const MaxArrayLen: u32 = 10;
+    pub const MaxLineageLookups: u32 = 5;
+    pub const MaxLicenseOffers: u32 = 5;
+    pub const MaxHolderLicenses: u32 = 5;
+    pub const MaxContentHashLength: u32 = 64;
+    pub const MaxEntityRevisions: u32 = 3;
+    pub const MaxReleaseTracks: u32 = 3;
+    pub const MaxAccessDelegates: u32 = 2;
+    pub const MaxPolicyThreshold: u32 = 5;
+    pub const MaxAuthorityProposals: u32 = 3;
+    pub const MaxResaleRoyalty: Perbill = Perbill::from_percent(50);
+    pub const MaxRoyaltyShareHolders: u32 = 3;
+    pub const NativePaymentAsset: NativeOrWithId<u32> = NativeOrWithId::Native;
+    pub const RoyaltySharesAssetIdStart: u32 = 7;
+    pub static AuthorityDeposit: u64 = 0;
+    pub static AuthorDeposit: u64 = 0;
+    pub static EntityDeposit: u64 = 0;
+    pub static RevisionDeposit: u64 = 0;
+    pub static AccessDeposit: u64 = 0;
+    pub static ProposalDeposit: u64 = 0;
+    pub static LicenseDeposit: u64 = 0;
+    pub static RoyaltySharesDeposit: u64 = 0;
+    pub static ByteDeposit: u64 = 0;
+    pub static DisputeBond: u64 = 0;
+    pub static Whitelist: Option<Vec<u64>> = None;
+    pub static NftItemOwners: BTreeMap<(u32, u32), Option<u64>> = BTreeMap::new();
+    pub static NftItemConfigs: BTreeMap<(u32, u32), ItemConfig> = BTreeMap::new();
+    pub static NftItemMetadata: BTreeMap<(u32, u32), Vec<u8>> = BTreeMap::new();
+    pub static NftAttributes: BTreeMap<(u32, u32, Vec<u8>), Vec<u8>> = BTreeMap::new();
 }
 
 pub struct TestWhiteListChecker;
 
-impl<AccountId32> Contains<AccountId32> for TestWhiteListChecker {
-    fn contains(_account: &AccountId32) -> bool {
-        true
+impl Contains<u64> for TestWhiteListChecker {
+    fn contains(account: &u64) -> bool {
+        Whitelist::get().map_or(true, |whitelist| whitelist.contains(account))
     }
 }
 
@@ -68,12 +131,40 @@ impl crate::Config for Test {
     type MaxRoyaltyParts = MaxRoyaltyParts;
     type MaxRelatedEntities = MaxRelatedEntities;
     type MaxArrayLen = MaxArrayLen;
+    type MaxLineageLookups = MaxLineageLookups;
+    type MaxLicenseOffers = MaxLicenseOffers;
+    type MaxHolderLicenses = MaxHolderLicenses;
+    type MaxContentHashLength = MaxContentHashLength;
+    type MaxEntityRevisions = MaxEntityRevisions;
+    type MaxReleaseTracks = MaxReleaseTracks;
+    type MaxAccessDelegates = MaxAccessDelegates;
+    type MaxPolicyThreshold = MaxPolicyThreshold;
+    type MaxAuthorityProposals = MaxAuthorityProposals;
+    type MaxResaleRoyalty = MaxResaleRoyalty;
+    type MaxRoyaltyShareHolders = MaxRoyaltyShareHolders;
     type WhiteListChecker = TestWhiteListChecker;
+    type VerifierOrigin = frame_system::EnsureRoot<u64>;
+    type ArbiterOrigin = frame_system::EnsureRoot<u64>;
     type CollectionId = u32;
     type ItemId = u32;
     type CollectionConfig = u8;
     type Nfts = NftsMock;
-    type Currency = ();
+    type Currency = Balances;
+    type Assets = Assets;
+    type Payments = NativeAndAssets;
+    type PaymentAssets = EverythingBut<Equals<NativePaymentAsset>>;
+    type RoyaltySharesAssetIdStart = RoyaltySharesAssetIdStart;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AuthorityDeposit = AuthorityDeposit;
+    type AuthorDeposit = AuthorDeposit;
+    type EntityDeposit = EntityDeposit;
+    type RevisionDeposit = RevisionDeposit;
+    type AccessDeposit = AccessDeposit;
+    type ProposalDeposit = ProposalDeposit;
+    type LicenseDeposit = LicenseDeposit;
+    type RoyaltySharesDeposit = RoyaltySharesDeposit;
+    type ByteDeposit = ByteDeposit;
+    type DisputeBond = DisputeBond;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     #[cfg(feature = "runtime-benchmarks")]
@@ -86,21 +177,127 @@ impl Inspect<u64> for NftsMock {
     type ItemId = u32;
     type CollectionId = u32;
 
-    fn owner(_collection: &Self::CollectionId, _item: &Self::ItemId) -> Option<u64> {
-        Some(1)
+    /// Items not minted through the mock, e.g. linked to entities inserted in the storage, are held by `1`.
+    fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<u64> {
+        NftItemOwners::get()
+            .get(&(*collection, *item))
+            .copied()
+            .unwrap_or(Some(1))
+    }
+
+    fn system_attribute(
+        collection: &Self::CollectionId,
+        item: Option<&Self::ItemId>,
+        key: &[u8],
+    ) -> Option<Vec<u8>> {
+        NftAttributes::get()
+            .get(&(*collection, *item?, key.to_vec()))
+            .cloned()
     }
 }
 
 impl Mutate<u64, ItemConfig> for NftsMock {
     fn mint_into(
-        _collection: &Self::CollectionId,
-        _item: &Self::ItemId,
-        _who: &u64,
-        _config: &ItemConfig,
+        collection: &Self::CollectionId,
+        item: &Self::ItemId,
+        who: &u64,
+        config: &ItemConfig,
         _deposit_collection_owner: bool,
     ) -> DispatchResult {
+        NftItemOwners::mutate(|owners| owners.insert((*collection, *item), Some(*who)));
+        NftItemConfigs::mutate(|configs| configs.insert((*collection, *item), *config));
+        Ok(())
+    }
+
+    fn burn(
+        collection: &Self::CollectionId,
+        item: &Self::ItemId,
+        _maybe_check_owner: Option<&u64>,
+    ) -> DispatchResult {
+        ensure!(!is_transfer_disabled(collection, item), TokenError::Frozen);
+        NftItemOwners::mutate(|owners| owners.insert((*collection, *item), None));
+        Ok(())
+    }
+
+    fn set_attribute(
+        collection: &Self::CollectionId,
+        item: &Self::ItemId,
+        key: &[u8],
+        value: &[u8],
+    ) -> DispatchResult {
+        NftAttributes::mutate(|attributes| {
+            attributes.insert((*collection, *item, key.to_vec()), value.to_vec())
+        });
+        Ok(())
+    }
+
+    fn clear_attribute(
+        collection: &Self::CollectionId,
+        item: &Self::ItemId,
+        key: &[u8],
+    ) -> DispatchResult {
+        NftAttributes::mutate(|attributes| attributes.remove(&(*collection, *item, key.to_vec())));
+        Ok(())
+    }
+
+    fn set_item_metadata(
+        _who: Option<&u64>,
+        collection: &Self::CollectionId,
+        item: &Self::ItemId,
+        data: &[u8],
+    ) -> DispatchResult {
+        NftItemMetadata::mutate(|metadata| metadata.insert((*collection, *item), data.to_vec()));
         Ok(())
     }
+
+    fn clear_item_metadata(
+        _who: Option<&u64>,
+        collection: &Self::CollectionId,
+        item: &Self::ItemId,
+    ) -> DispatchResult {
+        NftItemMetadata::mutate(|metadata| metadata.remove(&(*collection, *item)));
+        Ok(())
+    }
+}
+
+impl Transfer<u64> for NftsMock {
+    fn transfer(
+        collection: &Self::CollectionId,
+        item: &Self::ItemId,
+        destination: &u64,
+    ) -> DispatchResult {
+        ensure!(!is_transfer_disabled(collection, item), TokenError::Frozen);
+        NftItemOwners::mutate(|owners| owners.insert((*collection, *item), Some(*destination)));
+        Ok(())
+    }
+
+    fn disable_transfer(collection: &Self::CollectionId, item: &Self::ItemId) -> DispatchResult {
+        ensure!(!is_transfer_disabled(collection, item), TokenError::Frozen);
+        Self::set_attribute(
+            collection,
+            item,
+            &PalletAttributes::<u32>::TransferDisabled.encode(),
+            &[],
+        )
+    }
+
+    fn enable_transfer(collection: &Self::CollectionId, item: &Self::ItemId) -> DispatchResult {
+        Self::clear_attribute(
+            collection,
+            item,
+            &PalletAttributes::<u32>::TransferDisabled.encode(),
+        )
+    }
+}
+
+/// Mirrors the NFTs pallet, a burn or a transfer of an item with disabled transfers fails.
+pub fn is_transfer_disabled(collection: &u32, item: &u32) -> bool {
+    NftsMock::system_attribute(
+        collection,
+        Some(item),
+        &PalletAttributes::<u32>::TransferDisabled.encode(),
+    )
+    .is_some()
 }
 
 impl Create<u64, u8> for NftsMock {
@@ -1,3 +1,4 @@
+use polkadot_sdk::pallet_nfts::{ItemConfig, ItemSetting};
 use polkadot_sdk::polkadot_sdk_frame as frame;
 
 use crate::{
@@ -5,7 +6,22 @@ use crate::{
     IPEntityKind, Metadata, *,
 };
 
-use frame::testing_prelude::*;
+use frame::{
+    arithmetic::Perbill,
+    deps::frame_support::{
+        migrations::{SteppedMigration, SteppedMigrationError},
+        traits::fungible::NativeOrWithId,
+        weights::WeightMeter,
+    },
+    testing_prelude::*,
+    traits::{
+        fungible::InspectHold,
+        fungibles,
+        tokens::nonfungibles_v2::{Inspect, Transfer},
+        tokens::Preservation,
+        Currency,
+    },
+};
 
 #[test]
 fn test_get_authors() {
@@ -23,6 +39,10 @@ fn test_get_authors() {
                 nickname: vec![0].try_into().unwrap(),
                 real_name: Some(vec![1].try_into().unwrap()),
                 owner: 0,
+                status: AuthorStatus::Claimed,
+                ipi: None,
+                created_at: 0,
+                updated_at: 0,
             },
         );
         Authors::<Test>::insert(
@@ -31,6 +51,10 @@ fn test_get_authors() {
                 nickname: vec![2].try_into().unwrap(),
                 real_name: Some(vec![3].try_into().unwrap()),
                 owner: 1,
+                status: AuthorStatus::Claimed,
+                ipi: None,
+                created_at: 0,
+                updated_at: 0,
             },
         );
 
@@ -87,6 +111,106 @@ fn test_add_new_author() {
             vec![4, 5, 6]
         );
         assert_eq!(author_details.owner, 0);
+        assert_eq!(author_details.status, AuthorStatus::Claimed);
+    });
+}
+
+#[test]
+fn test_author_claim_and_verification() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // An author created on behalf of account 1 is unclaimed
+        assert_ok!(CustomPallet::add_new_author(
+            0,
+            vec![1].try_into().unwrap(),
+            None,
+            Some(1)
+        ));
+        assert_eq!(
+            Authors::<Test>::get(0).unwrap().status,
+            AuthorStatus::Unclaimed
+        );
+
+        assert_err!(
+            CustomPallet::verify_author(RuntimeOrigin::signed(0), 0, true),
+            DispatchError::BadOrigin
+        );
+        assert_err!(
+            CustomPallet::verify_author(RuntimeOrigin::root(), 0, true),
+            Error::<Test, _>::AuthorNotClaimed
+        );
+
+        // An unclaimed author can neither be edited nor credited on entities
+        assert_err!(
+            CustomPallet::set_author(1, 0, Some(vec![2].try_into().unwrap()), None),
+            Error::<Test, _>::AuthorNotClaimed
+        );
+        add_authority_access_for_test(0, 0, None);
+        let add_entity = || {
+            CustomPallet::add_new_entity(
+                0,
+                IPEntityKind::Track,
+                0,
+                vec![4, 5, 6].try_into().unwrap(),
+                MetadataStandard::M25,
+                MetadataFeatures::default(),
+                Some(vec![0].try_into().unwrap()),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+        };
+        assert_err!(add_entity(), Error::<Test, _>::AuthorNotClaimed);
+
+        assert_err!(
+            CustomPallet::claim_author(RuntimeOrigin::signed(0), 0),
+            Error::<Test, _>::NoPermission
+        );
+        assert_ok!(CustomPallet::claim_author(RuntimeOrigin::signed(1), 0));
+        System::assert_last_event(Event::AuthorClaimed { author_id: 0 }.into());
+        assert_eq!(
+            Authors::<Test>::get(0).unwrap().status,
+            AuthorStatus::Claimed
+        );
+        assert_err!(
+            CustomPallet::claim_author(RuntimeOrigin::signed(1), 0),
+            Error::<Test, _>::AuthorAlreadyClaimed
+        );
+        assert_ok!(add_entity());
+
+        assert_err!(
+            CustomPallet::verify_author(RuntimeOrigin::root(), 0, false),
+            Error::<Test, _>::AuthorNotVerified
+        );
+        assert_ok!(CustomPallet::verify_author(RuntimeOrigin::root(), 0, true));
+        System::assert_last_event(Event::AuthorVerified { author_id: 0 }.into());
+        assert_eq!(
+            Authors::<Test>::get(0).unwrap().status,
+            AuthorStatus::Verified
+        );
+
+        // Editing the real name drops the verification
+        assert_ok!(CustomPallet::set_author(
+            1,
+            0,
+            Some(vec![2].try_into().unwrap()),
+            None
+        ));
+        assert_eq!(
+            Authors::<Test>::get(0).unwrap().status,
+            AuthorStatus::Claimed
+        );
+
+        assert_ok!(CustomPallet::verify_author(RuntimeOrigin::root(), 0, true));
+        assert_ok!(CustomPallet::verify_author(RuntimeOrigin::root(), 0, false));
+        System::assert_last_event(Event::AuthorVerificationRevoked { author_id: 0 }.into());
+        assert_eq!(
+            Authors::<Test>::get(0).unwrap().status,
+            AuthorStatus::Claimed
+        );
     });
 }
 
@@ -116,6 +240,7 @@ fn test_set_author() {
             vec![7, 8, 9]
         );
         assert_eq!(updated_author.owner, 1);
+        assert_eq!(updated_author.status, AuthorStatus::Unclaimed);
 
         // Case 2: Attempt to update a non-existent author
         assert_err!(
@@ -123,7 +248,12 @@ fn test_set_author() {
             Error::<Test, _>::AuthorNotFound,
         );
 
-        // Case 3: No changes provided (real_name and owner are None)
+        // Case 3: No changes provided (real_name and owner are None), once the new owner claimed the author
+        assert_err!(
+            CustomPallet::set_author(1, 0, None, None),
+            Error::<Test, _>::AuthorNotClaimed
+        );
+        assert_ok!(CustomPallet::claim_author_owner(1, 0));
         assert_ok!(CustomPallet::set_author(1, 0, None, None));
 
         // Verify no changes were made
@@ -304,6 +434,11 @@ fn test_set_entity() {
                 },
                 collection_id: None,
                 item_id: None,
+                content: None,
+                identifiers: Default::default(),
+                created_at: 0,
+                updated_at: 0,
+                revision: 0,
             },
         );
 
@@ -314,6 +449,10 @@ fn test_set_entity() {
                 nickname: vec![1].try_into().unwrap(),
                 real_name: Some(vec![2].try_into().unwrap()),
                 owner: 0,
+                status: AuthorStatus::Claimed,
+                ipi: None,
+                created_at: 0,
+                updated_at: 0,
             },
         );
 
@@ -322,6 +461,15 @@ fn test_set_entity() {
             Some(vec![7, 8, 9].try_into().unwrap());
         let new_owner: Option<u32> = Some(1);
 
+        // The new owner must accept the entity
+        assert_err!(
+            CustomPallet::set_entity(
+                0, 0, None, None, None, new_owner, None, None, None, None, None, None,
+            ),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+        add_authority_access_for_test(0, 1, Some(1));
+
         assert_ok!(CustomPallet::set_entity(
             0,
             0,
@@ -429,6 +577,177 @@ fn test_set_entity() {
     });
 }
 
+#[test]
+fn test_entity_transfer() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let seller: u64 = 0;
+        let buyer: u64 = 1;
+        Balances::make_free_balance_be(&buyer, 1_000);
+
+        add_authority_access_for_test(seller, 0, Some(0));
+        add_authority_access_for_test(buyer, 1, Some(1));
+        add_entity_for_test(0, 0, None);
+        Entities::<Test>::mutate(0, |entity| {
+            let entity = entity.as_mut().unwrap();
+            entity.collection_id = Some(0);
+            entity.item_id = Some(5);
+        });
+
+        assert_err!(
+            CustomPallet::offer_transfer(seller, 0, 0, 100, PaymentCurrency::Native),
+            Error::<Test, _>::EntityTransferToOwner
+        );
+        assert_err!(
+            CustomPallet::offer_transfer(seller, 0, 2, 100, PaymentCurrency::Native),
+            Error::<Test, _>::AuthorityNotFound
+        );
+        assert_err!(
+            CustomPallet::offer_transfer(buyer, 0, 1, 100, PaymentCurrency::Native),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+        assert_err!(
+            CustomPallet::accept_transfer(buyer, 0, 100, PaymentCurrency::Native),
+            Error::<Test, _>::EntityTransferNotFound
+        );
+
+        assert_ok!(CustomPallet::offer_transfer(
+            seller,
+            0,
+            1,
+            100,
+            PaymentCurrency::Native
+        ));
+        System::assert_last_event(
+            Event::EntityTransferOffered {
+                entity_id: 0,
+                to_authority: 1,
+                price: 100,
+                currency: PaymentCurrency::Native,
+            }
+            .into(),
+        );
+
+        assert_err!(
+            CustomPallet::accept_transfer(seller, 0, 100, PaymentCurrency::Native),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+        assert_err!(
+            CustomPallet::accept_transfer(buyer, 0, 99, PaymentCurrency::Native),
+            Error::<Test, _>::EntityTransferPriceTooHigh
+        );
+
+        // The receiving side can decline the offer
+        assert_ok!(CustomPallet::cancel_transfer(buyer, 0));
+        System::assert_last_event(Event::EntityTransferCancelled { entity_id: 0 }.into());
+        assert_err!(
+            CustomPallet::cancel_transfer(seller, 0),
+            Error::<Test, _>::EntityTransferNotFound
+        );
+
+        assert_ok!(CustomPallet::offer_transfer(
+            seller,
+            0,
+            1,
+            100,
+            PaymentCurrency::Native
+        ));
+        assert_ok!(CustomPallet::accept_transfer(
+            buyer,
+            0,
+            100,
+            PaymentCurrency::Native
+        ));
+        System::assert_last_event(
+            Event::EntityTransferred {
+                entity_id: 0,
+                from_authority: 0,
+                to_authority: 1,
+                price: 100,
+                currency: PaymentCurrency::Native,
+            }
+            .into(),
+        );
+
+        let entity = Entities::<Test>::get(0).unwrap();
+        assert_eq!(entity.owner, 1);
+        assert_eq!(entity.collection_id, Some(1));
+        assert_eq!(entity.item_id, Some(5));
+        assert!(!EntityTransfers::<Test>::contains_key(0));
+        assert_eq!(CustomPallet::get_entities_of_authority(1), vec![0]);
+
+        assert_eq!(Balances::free_balance(buyer), 900);
+        assert_eq!(Balances::free_balance(seller), 100);
+
+        // A free transfer to an authority without a collection keeps the NFT where it is
+        add_authority_access_for_test(seller, 2, None);
+        assert_ok!(CustomPallet::offer_transfer(
+            buyer,
+            0,
+            2,
+            0,
+            PaymentCurrency::Native
+        ));
+        assert_ok!(CustomPallet::accept_transfer(
+            seller,
+            0,
+            0,
+            PaymentCurrency::Native
+        ));
+
+        let entity = Entities::<Test>::get(0).unwrap();
+        assert_eq!(entity.owner, 2);
+        assert_eq!(entity.collection_id, Some(1));
+        assert_eq!(Balances::free_balance(buyer), 900);
+
+        // Changing the owner with `set_entity` moves the NFT into the collection of the new owner as well
+        assert_ok!(CustomPallet::set_entity(
+            seller,
+            0,
+            None,
+            None,
+            None,
+            Some(0),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+        let entity = Entities::<Test>::get(0).unwrap();
+        assert_eq!(entity.owner, 0);
+        assert_eq!(entity.collection_id, Some(0));
+        assert_eq!(NftsMock::owner(&1, &5), None);
+        assert_eq!(NftsMock::owner(&0, &5), Some(1));
+        assert_eq!(EntitiesByNft::<Test>::get(0, 5), Some(0));
+        assert!(!EntitiesByNft::<Test>::contains_key(1, 5));
+        assert_eq!(
+            NftsMock::system_attribute(&0, Some(&5), NFT_ATTRIBUTE_AUTHORITY_ID),
+            Some(0u32.encode())
+        );
+
+        // An offer can not be accepted once its beneficiary lost access to the owning authority
+        CustomPallet::write_access(0, &3, Some(AuthorityAccessSettings::all())).unwrap();
+        assert_ok!(CustomPallet::offer_transfer(
+            3,
+            0,
+            1,
+            50,
+            PaymentCurrency::Native
+        ));
+        assert_ok!(CustomPallet::remove_access(seller, 0, 3));
+        assert_err!(
+            CustomPallet::accept_transfer(buyer, 0, 50, PaymentCurrency::Native),
+            Error::<Test, _>::EntityTransferBeneficiaryRevoked
+        );
+        assert_ok!(CustomPallet::cancel_transfer(buyer, 0));
+        assert_eq!(Entities::<Test>::get(0).unwrap().owner, 0);
+        assert_eq!(Balances::free_balance(buyer), 900);
+    });
+}
+
 #[test]
 fn test_set_entity_nft_owner_must_be_specified() {
     new_test_ext().execute_with(|| {
@@ -454,6 +773,11 @@ fn test_set_entity_nft_owner_must_be_specified() {
                 },
                 collection_id: None,
                 item_id: None,
+                content: None,
+                identifiers: Default::default(),
+                created_at: 0,
+                updated_at: 0,
+                revision: 0,
             },
         );
 
@@ -464,6 +788,10 @@ fn test_set_entity_nft_owner_must_be_specified() {
                 nickname: vec![1].try_into().unwrap(),
                 real_name: Some(vec![2].try_into().unwrap()),
                 owner: 0,
+                status: AuthorStatus::Claimed,
+                ipi: None,
+                created_at: 0,
+                updated_at: 0,
             },
         );
 
@@ -518,7 +846,8 @@ fn test_add_access() {
             origin,
             authority_id,
             account_id,
-            access
+            access,
+            None
         ));
 
         let access_settings = AuthoritiesAccess::<Test>::get(authority_id, account_id).unwrap();
@@ -538,23 +867,91 @@ fn test_set_access() {
         add_authority_access_for_test(account_id, authority_id, None);
         AuthoritiesAccess::<Test>::insert(authority_id, account_id, AuthorityAccessSettings::all());
 
+        assert_err!(
+            CustomPallet::set_access(account_id, authority_id, account_id, new_access, None),
+            Error::<Test, _>::LastEditAccessHolder
+        );
+
+        add_authority_access_for_test(3, authority_id, None);
+
         assert_ok!(CustomPallet::set_access(
             account_id,
             authority_id,
             account_id,
-            new_access
+            new_access,
+            None
         ));
+        assert_eq!(EditAccessHolders::<Test>::get(authority_id), 1);
 
         let updated_access = AuthoritiesAccess::<Test>::get(authority_id, account_id).unwrap();
         assert_eq!(updated_access, new_access);
 
         assert_err!(
-            CustomPallet::set_access(account_id, 2, account_id, new_access),
+            CustomPallet::set_access(account_id, 2, account_id, new_access, None),
             Error::<Test, _>::AuthoritiesAccessNotFound
         );
     });
 }
 
+#[test]
+fn test_remove_access() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let authority_id: _ = 0;
+
+        add_authority_access_for_test(1, authority_id, None);
+        assert_ok!(CustomPallet::add_access(
+            1,
+            authority_id,
+            2,
+            AuthorityAccessSetting::CreateEntity.into(),
+            None
+        ));
+
+        assert_err!(
+            CustomPallet::remove_access(2, authority_id, 1),
+            Error::<Test, _>::NotAuthorized
+        );
+        assert_err!(
+            CustomPallet::remove_access(1, authority_id, 3),
+            Error::<Test, _>::AuthoritiesAccessNotExist
+        );
+        assert_err!(
+            CustomPallet::remove_access(1, authority_id, 1),
+            Error::<Test, _>::LastEditAccessHolder
+        );
+
+        assert_ok!(CustomPallet::remove_access(1, authority_id, 2));
+        System::assert_last_event(
+            Event::AuthoritiesAccessRemoved {
+                authority_id,
+                account_id: 2,
+            }
+            .into(),
+        );
+        assert!(!AuthoritiesAccess::<Test>::contains_key(authority_id, 2));
+        assert_eq!(CustomPallet::get_account_overview(2).authorities, vec![]);
+
+        // A second editor allows the first one to leave.
+        assert_ok!(CustomPallet::add_access(
+            1,
+            authority_id,
+            2,
+            AuthorityAccessSettings::all(),
+            None
+        ));
+        assert_eq!(EditAccessHolders::<Test>::get(authority_id), 2);
+        assert_ok!(CustomPallet::remove_access(2, authority_id, 1));
+        assert_eq!(EditAccessHolders::<Test>::get(authority_id), 1);
+
+        assert_err!(
+            CustomPallet::set_access(2, authority_id, 2, AuthorityAccessSettings::none(), None),
+            Error::<Test, _>::LastEditAccessHolder
+        );
+    });
+}
+
 #[test]
 fn test_ensure_access_right() {
     new_test_ext().execute_with(|| {
@@ -597,33 +994,3894 @@ fn test_toggle_nfts_support() {
     });
 }
 
-fn add_authority_access_for_test(
-    account_id: <Test as frame_system::Config>::AccountId,
-    authority_id: <Test as Config>::AuthorityId,
-    collection_id: Option<<Test as Config>::CollectionId>,
-) {
-    Authorities::<Test>::insert(
-        authority_id,
-        AuthorityDetails {
-            authority_kind: AuthorityKind::Label,
-            name: vec![1, 2, 3].try_into().unwrap(),
-            collection_id,
-        },
-    );
+#[test]
+fn test_royalty_payouts() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let royalty_parts = vec![
+            Wallet {
+                address_id: 10,
+                share: Perbill::from_parts(333_333_334),
+            },
+            Wallet {
+                address_id: 11,
+                share: Perbill::from_parts(333_333_333),
+            },
+            Wallet {
+                address_id: 12,
+                share: Perbill::from_parts(333_333_333),
+            },
+        ];
 
-    AuthoritiesAccess::<Test>::insert(authority_id, account_id, AuthorityAccessSettings::all());
+        // The remainder of the rounding goes to the first wallet
+        let payouts = CustomPallet::royalty_payouts(&royalty_parts, 100);
+        assert_eq!(payouts, vec![(10, 34), (11, 33), (12, 33)]);
+
+        // Zero shares are omitted
+        let payouts = CustomPallet::royalty_payouts(&royalty_parts, 1);
+        assert_eq!(payouts, vec![(10, 1)]);
+    });
 }
 
-fn add_author_for_test(
-    owner: <Test as frame_system::Config>::AccountId,
-    author_id: <Test as Config>::AuthorId,
-) {
-    Authors::<Test>::insert(
-        author_id,
-        AuthorDetails {
-            nickname: vec![0].try_into().unwrap(),
-            real_name: Some(vec![1].try_into().unwrap()),
-            owner,
-        },
-    );
+#[test]
+fn test_ensure_valid_royalty_parts() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CustomPallet::ensure_valid_royalty_parts(&[
+            Wallet {
+                address_id: 10,
+                share: Perbill::from_percent(70),
+            },
+            Wallet {
+                address_id: 11,
+                share: Perbill::from_percent(30),
+            },
+        ]));
+
+        assert_err!(
+            CustomPallet::ensure_valid_royalty_parts(&[
+                Wallet {
+                    address_id: 10,
+                    share: Perbill::from_percent(50),
+                },
+                Wallet {
+                    address_id: 10,
+                    share: Perbill::from_percent(50),
+                },
+            ]),
+            Error::<Test, _>::RoyaltyDuplicateWallet
+        );
+
+        assert_err!(
+            CustomPallet::ensure_valid_royalty_parts(&[
+                Wallet {
+                    address_id: 10,
+                    share: Perbill::from_percent(100),
+                },
+                Wallet {
+                    address_id: 11,
+                    share: Perbill::zero(),
+                },
+            ]),
+            Error::<Test, _>::RoyaltyZeroShare
+        );
+
+        assert_err!(
+            CustomPallet::ensure_valid_royalty_parts(&[Wallet {
+                address_id: 10,
+                share: Perbill::from_percent(99),
+            }]),
+            Error::<Test, _>::RoyaltySharesNotFull
+        );
+
+        assert_err!(
+            CustomPallet::ensure_valid_royalty_parts(&[]),
+            Error::<Test, _>::RoyaltySharesNotFull
+        );
+
+        // Invalid splits are rejected on write
+        add_authority_access_for_test(0, 0, None);
+        assert_err!(
+            CustomPallet::add_new_entity(
+                0,
+                IPEntityKind::Track,
+                0,
+                vec![4, 5, 6].try_into().unwrap(),
+                MetadataStandard::M25,
+                MetadataFeatures::default(),
+                None,
+                Some(
+                    vec![Wallet {
+                        address_id: 10,
+                        share: Perbill::from_percent(50),
+                    }]
+                    .try_into()
+                    .unwrap()
+                ),
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test, _>::RoyaltySharesNotFull
+        );
+    });
+}
+
+#[test]
+fn test_distribute_royalties() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let payer: u64 = 1;
+        Balances::make_free_balance_be(&payer, 1_000);
+
+        add_entity_for_test(
+            0,
+            0,
+            Some(
+                vec![
+                    Wallet {
+                        address_id: 10,
+                        share: Perbill::from_percent(75),
+                    },
+                    Wallet {
+                        address_id: 11,
+                        share: Perbill::from_percent(25),
+                    },
+                ]
+                .try_into()
+                .unwrap(),
+            ),
+        );
+        add_entity_for_test(1, 0, None);
+
+        assert_ok!(CustomPallet::pay_royalties(
+            RuntimeOrigin::signed(payer),
+            0,
+            401,
+            PaymentCurrency::Native
+        ));
+
+        assert_eq!(Balances::free_balance(payer), 599);
+        assert_eq!(Balances::free_balance(10), 301);
+        assert_eq!(Balances::free_balance(11), 100);
+
+        System::assert_has_event(
+            Event::<Test>::RoyaltyPaid {
+                entity_id: 0,
+                payer,
+                recipient: 10,
+                amount: 301,
+                currency: PaymentCurrency::Native,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::<Test>::RoyaltyPaid {
+                entity_id: 0,
+                payer,
+                recipient: 11,
+                amount: 100,
+                currency: PaymentCurrency::Native,
+            }
+            .into(),
+        );
+
+        // A share below the minimum balance of a new account is carried over to the first wallet
+        let asset = PaymentCurrency::Asset(NativeOrWithId::WithId(6));
+        assert_ok!(<Assets as fungibles::Create<u64>>::create(
+            6, payer, true, 10
+        ));
+        assert_ok!(<Assets as fungibles::Mutate<u64>>::mint_into(
+            6, &payer, 1_000
+        ));
+        assert_ok!(CustomPallet::pay_royalties(
+            RuntimeOrigin::signed(payer),
+            0,
+            30,
+            asset.clone()
+        ));
+        assert_eq!(<Assets as fungibles::Inspect<u64>>::balance(6, &10), 30);
+        assert_eq!(<Assets as fungibles::Inspect<u64>>::balance(6, &11), 0);
+        System::assert_last_event(
+            Event::<Test>::RoyaltyPaid {
+                entity_id: 0,
+                payer,
+                recipient: 10,
+                amount: 30,
+                currency: asset.clone(),
+            }
+            .into(),
+        );
+
+        // Once the account exists, it receives its share
+        assert_ok!(CustomPallet::pay_royalties(
+            RuntimeOrigin::signed(payer),
+            0,
+            60,
+            asset
+        ));
+        assert_eq!(<Assets as fungibles::Inspect<u64>>::balance(6, &10), 75);
+        assert_eq!(<Assets as fungibles::Inspect<u64>>::balance(6, &11), 15);
+
+        assert_err!(
+            CustomPallet::distribute_royalties(&payer, 0, &PaymentCurrency::Native, 0),
+            Error::<Test, _>::ZeroAmount
+        );
+        assert_err!(
+            CustomPallet::distribute_royalties(&payer, 1, &PaymentCurrency::Native, 100),
+            Error::<Test, _>::EntityRoyaltyPartsNotFound
+        );
+        assert_err!(
+            CustomPallet::distribute_royalties(&payer, 2, &PaymentCurrency::Native, 100),
+            Error::<Test, _>::EntityNotFound
+        );
+    });
+}
+
+#[test]
+fn test_relations() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        add_authority_access_for_test(0, 0, None);
+        add_entity_for_test(0, 0, None);
+        add_entity_for_test(1, 0, None);
+        NextEntityId::<Test>::set(Some(2));
+
+        // Entity 2 samples entity 0 and is a stem of entity 1
+        assert_ok!(add_entity_with_relations_for_test(vec![
+            relation_for_test(RelationKind::SampleOf, 0),
+            relation_for_test(RelationKind::StemOf, 1),
+        ]));
+        // Entity 3 is a remix of entity 2
+        assert_ok!(add_entity_with_relations_for_test(vec![relation_for_test(
+            RelationKind::RemixOf,
+            2
+        )]));
+
+        assert_eq!(
+            CustomPallet::get_derivatives(0),
+            vec![(2, RelationKind::SampleOf)]
+        );
+        assert_eq!(
+            CustomPallet::get_derivatives(2),
+            vec![(3, RelationKind::RemixOf)]
+        );
+        assert_eq!(CustomPallet::get_derivatives(3), vec![]);
+
+        assert_err!(
+            add_entity_with_relations_for_test(vec![relation_for_test(RelationKind::CoverOf, 9)]),
+            Error::<Test, _>::EntityRelatedEntityNotFound
+        );
+        assert_err!(
+            add_entity_with_relations_for_test(vec![
+                relation_for_test(RelationKind::CoverOf, 0),
+                relation_for_test(RelationKind::RemasterOf, 0),
+            ]),
+            Error::<Test, _>::EntityRelationDuplicate
+        );
+
+        assert_err!(
+            set_entity_relations_for_test(2, vec![relation_for_test(RelationKind::RemixOf, 2)]),
+            Error::<Test, _>::EntityRelationToSelf
+        );
+        // 0 <- 2 <- 3, entity 0 can not derive from entity 3
+        assert_err!(
+            set_entity_relations_for_test(0, vec![relation_for_test(RelationKind::RemixOf, 3)]),
+            Error::<Test, _>::EntityRelationCycle
+        );
+
+        // Replacing the relations updates the reverse index
+        assert_ok!(set_entity_relations_for_test(
+            2,
+            vec![relation_for_test(RelationKind::GeneratedFrom, 1)]
+        ));
+        assert_eq!(CustomPallet::get_derivatives(0), vec![]);
+        assert_eq!(
+            CustomPallet::get_derivatives(1),
+            vec![(2, RelationKind::GeneratedFrom)]
+        );
+
+        // Entity 0 no longer is an ancestor of entity 3
+        assert_ok!(set_entity_relations_for_test(
+            0,
+            vec![relation_for_test(RelationKind::RemixOf, 3)]
+        ));
+
+        // 1 <- 2 <- 3 <- 0 <- 4 <- 5 <- 6, the lineage of entity 6 exceeds `MaxLineageLookups`
+        for source_id in [0, 4, 5] {
+            assert_ok!(add_entity_with_relations_for_test(vec![relation_for_test(
+                RelationKind::RemixOf,
+                source_id
+            )]));
+        }
+        assert_err!(
+            set_entity_relations_for_test(1, vec![relation_for_test(RelationKind::RemixOf, 6)]),
+            Error::<Test, _>::EntityLineageTooLarge
+        );
+    });
+}
+
+#[test]
+fn test_reverse_indexes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let name: BoundedVec<u8, MaxShortStringLength> = vec![1, 2, 3].try_into().unwrap();
+
+        assert_ok!(CustomPallet::add_new_author(1, name.clone(), None, None));
+        assert_ok!(CustomPallet::add_new_author(1, name.clone(), None, Some(2)));
+        assert_ok!(CustomPallet::claim_author_owner(2, 1));
+        assert_ok!(CustomPallet::add_new_authority(
+            1,
+            name.clone(),
+            AuthorityKind::Label,
+            None
+        ));
+        assert_ok!(CustomPallet::add_new_authority(
+            2,
+            name.clone(),
+            AuthorityKind::Musician,
+            None
+        ));
+        assert_ok!(CustomPallet::add_access(
+            1,
+            0,
+            2,
+            AuthorityAccessSettings::none(),
+            None
+        ));
+
+        assert_ok!(CustomPallet::add_new_entity(
+            1,
+            IPEntityKind::Track,
+            0,
+            vec![4, 5, 6].try_into().unwrap(),
+            MetadataStandard::M25,
+            MetadataFeatures::default(),
+            Some(vec![0, 1].try_into().unwrap()),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        assert_eq!(CustomPallet::get_entities_of_authority(0), vec![0]);
+        assert_eq!(CustomPallet::get_entities_of_author(0), vec![0]);
+        assert_eq!(CustomPallet::get_entities_of_author(1), vec![0]);
+
+        assert_eq!(
+            CustomPallet::get_account_overview(1),
+            AccountOverview {
+                authors: vec![0],
+                authorities: vec![(0, AuthorityAccessSettings::all())],
+            }
+        );
+
+        let mut overview = CustomPallet::get_account_overview(2);
+        overview
+            .authorities
+            .sort_by_key(|(authority_id, _)| *authority_id);
+        assert_eq!(
+            overview,
+            AccountOverview {
+                authors: vec![1],
+                authorities: vec![
+                    (0, AuthorityAccessSettings::none()),
+                    (1, AuthorityAccessSettings::all())
+                ],
+            }
+        );
+
+        // Changing the owner and the authors of an entity moves it between the indexes
+        assert_ok!(CustomPallet::set_entity(
+            1,
+            0,
+            None,
+            None,
+            None,
+            Some(1),
+            Some(vec![1].try_into().unwrap()),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        assert_eq!(CustomPallet::get_entities_of_authority(0), vec![]);
+        assert_eq!(CustomPallet::get_entities_of_authority(1), vec![0]);
+        assert_eq!(CustomPallet::get_entities_of_author(0), vec![]);
+        assert_eq!(CustomPallet::get_entities_of_author(1), vec![0]);
+
+        // Changing the owner of an author moves it between the accounts
+        assert_ok!(CustomPallet::set_author(1, 0, None, Some(3)));
+
+        assert_eq!(CustomPallet::get_account_overview(1).authors, vec![]);
+        assert_eq!(CustomPallet::get_account_overview(3).authors, vec![0]);
+    });
+}
+
+#[test]
+fn test_remove_author() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        add_authority_access_for_test(1, 0, None);
+        assert_ok!(CustomPallet::add_new_author(
+            1,
+            vec![1].try_into().unwrap(),
+            None,
+            None
+        ));
+        assert_ok!(CustomPallet::add_new_entity(
+            1,
+            IPEntityKind::Track,
+            0,
+            vec![4, 5, 6].try_into().unwrap(),
+            MetadataStandard::M25,
+            MetadataFeatures::default(),
+            Some(vec![0].try_into().unwrap()),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        assert_err!(
+            CustomPallet::delete_author(2, 0),
+            Error::<Test, _>::NoPermission
+        );
+        assert_err!(
+            CustomPallet::delete_author(1, 0),
+            Error::<Test, _>::AuthorHasEntities
+        );
+
+        assert_ok!(CustomPallet::delete_entity(1, 0, 0));
+        assert_ok!(CustomPallet::delete_author(1, 0));
+        System::assert_last_event(Event::<Test>::AuthorRemoved { author_id: 0 }.into());
+
+        assert!(!Authors::<Test>::contains_key(0));
+        assert_eq!(CustomPallet::get_account_overview(1).authors, vec![]);
+
+        assert_err!(
+            CustomPallet::delete_author(1, 0),
+            Error::<Test, _>::AuthorNotFound
+        );
+    });
+}
+
+#[test]
+fn test_remove_authority() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CustomPallet::add_new_authority(
+            1,
+            vec![1].try_into().unwrap(),
+            AuthorityKind::Label,
+            None
+        ));
+        assert_ok!(CustomPallet::add_access(
+            1,
+            0,
+            2,
+            AuthorityAccessSettings::none(),
+            None
+        ));
+        add_entity_for_test(0, 0, None);
+        EntitiesByAuthority::<Test>::insert(0, 0, ());
+
+        assert_err!(
+            CustomPallet::delete_authority(2, 0, 2),
+            Error::<Test, _>::NotAuthorized
+        );
+        assert_err!(
+            CustomPallet::delete_authority(1, 0, 2),
+            Error::<Test, _>::AuthorityHasEntities
+        );
+
+        assert_ok!(CustomPallet::delete_entity(1, 0, 0));
+        assert_err!(
+            CustomPallet::delete_authority(1, 0, 1),
+            Error::<Test, _>::BadWitness
+        );
+
+        assert_ok!(CustomPallet::delete_authority(1, 0, 2));
+        System::assert_last_event(Event::<Test>::AuthorityRemoved { authority_id: 0 }.into());
+
+        assert!(!Authorities::<Test>::contains_key(0));
+        assert!(!AuthoritiesAccess::<Test>::contains_key(0, 1));
+        assert!(!AuthoritiesAccess::<Test>::contains_key(0, 2));
+        assert_eq!(CustomPallet::get_account_overview(1).authorities, vec![]);
+        assert_eq!(CustomPallet::get_account_overview(2).authorities, vec![]);
+        assert_eq!(EditAccessHolders::<Test>::get(0), 0);
+
+        // An authority with an NFT collection can not be removed
+        add_authority_access_for_test(1, 1, Some(0));
+        assert_err!(
+            CustomPallet::delete_authority(1, 1, 1),
+            Error::<Test, _>::AuthorityHasCollection
+        );
+    });
+}
+
+#[test]
+fn test_remove_entity() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        add_authority_access_for_test(0, 0, None);
+        add_entity_for_test(0, 0, None);
+        NextEntityId::<Test>::set(Some(1));
+        assert_ok!(add_entity_with_relations_for_test(vec![relation_for_test(
+            RelationKind::SampleOf,
+            0
+        )]));
+        assert_ok!(CustomPallet::add_license_offer(
+            RuntimeOrigin::signed(0),
+            1,
+            license_offer_for_test(LicenseKind::Sync, 0, None)
+        ));
+
+        assert_err!(
+            CustomPallet::delete_entity(1, 1, 0),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+        assert_err!(
+            CustomPallet::delete_entity(0, 0, 0),
+            Error::<Test, _>::EntityHasDerivatives
+        );
+
+        assert_ok!(CustomPallet::delete_entity(0, 1, 0));
+        System::assert_last_event(Event::<Test>::EntityRemoved { entity_id: 1 }.into());
+
+        assert!(!Entities::<Test>::contains_key(1));
+        assert!(!LicenseOffers::<Test>::contains_key(1));
+        assert!(!NextLicenseOfferId::<Test>::contains_key(1));
+        assert_eq!(CustomPallet::get_derivatives(0), vec![]);
+        assert_eq!(CustomPallet::get_entities_of_authority(0), vec![]);
+
+        // The source entity has no derivatives anymore
+        assert_ok!(CustomPallet::delete_entity(0, 0, 0));
+        assert_err!(
+            CustomPallet::delete_entity(0, 0, 0),
+            Error::<Test, _>::EntityNotFound
+        );
+    });
+}
+
+#[test]
+fn test_license_offers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let account_id: u64 = 0;
+        let authority_id: u32 = 0;
+
+        add_authority_access_for_test(account_id, authority_id, None);
+        add_entity_for_test(0, authority_id, None);
+
+        // A paid offer can not be bought without royalty recipients, a free one can
+        assert_err!(
+            CustomPallet::add_license_offer(
+                RuntimeOrigin::signed(account_id),
+                0,
+                license_offer_for_test(LicenseKind::Sync, 100, None)
+            ),
+            Error::<Test, _>::EntityRoyaltyPartsNotFound
+        );
+        assert_ok!(CustomPallet::add_license_offer(
+            RuntimeOrigin::signed(account_id),
+            0,
+            license_offer_for_test(LicenseKind::Sync, 0, None)
+        ));
+        assert_ok!(CustomPallet::remove_license_offer(
+            RuntimeOrigin::signed(account_id),
+            0,
+            0
+        ));
+
+        add_entity_for_test(
+            0,
+            authority_id,
+            Some(
+                vec![Wallet {
+                    address_id: 10,
+                    share: Perbill::from_percent(100),
+                }]
+                .try_into()
+                .unwrap(),
+            ),
+        );
+
+        assert_ok!(CustomPallet::add_license_offer(
+            RuntimeOrigin::signed(account_id),
+            0,
+            license_offer_for_test(LicenseKind::Sync, 100, None)
+        ));
+        assert_ok!(CustomPallet::add_license_offer(
+            RuntimeOrigin::signed(account_id),
+            0,
+            license_offer_for_test(LicenseKind::Streaming, 10, Some(5))
+        ));
+        System::assert_last_event(
+            Event::<Test>::LicenseOfferAdded {
+                entity_id: 0,
+                offer_id: 2,
+            }
+            .into(),
+        );
+        assert_eq!(LicenseOffers::<Test>::get(0).len(), 2);
+
+        assert_err!(
+            CustomPallet::add_license_offer(
+                RuntimeOrigin::signed(1),
+                0,
+                license_offer_for_test(LicenseKind::Sync, 100, None)
+            ),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+        assert_err!(
+            CustomPallet::add_license_offer(
+                RuntimeOrigin::signed(account_id),
+                1,
+                license_offer_for_test(LicenseKind::Sync, 100, None)
+            ),
+            Error::<Test, _>::EntityNotFound
+        );
+
+        for _ in 2..MaxLicenseOffers::get() {
+            assert_ok!(CustomPallet::add_license_offer(
+                RuntimeOrigin::signed(account_id),
+                0,
+                license_offer_for_test(LicenseKind::AiTraining, 1, None)
+            ));
+        }
+        assert_err!(
+            CustomPallet::add_license_offer(
+                RuntimeOrigin::signed(account_id),
+                0,
+                license_offer_for_test(LicenseKind::AiTraining, 1, None)
+            ),
+            Error::<Test, _>::LimitExceeded
+        );
+
+        // Removing an offer keeps the ids of the others
+        assert_ok!(CustomPallet::remove_license_offer(
+            RuntimeOrigin::signed(account_id),
+            0,
+            1
+        ));
+        System::assert_last_event(
+            Event::<Test>::LicenseOfferRemoved {
+                entity_id: 0,
+                offer_id: 1,
+            }
+            .into(),
+        );
+        assert_eq!(LicenseOffers::<Test>::get(0)[0].0, 2);
+        assert_eq!(
+            LicenseOffers::<Test>::get(0)[0].1.kind,
+            LicenseKind::Streaming
+        );
+
+        assert_err!(
+            CustomPallet::remove_license_offer(RuntimeOrigin::signed(account_id), 0, 1),
+            Error::<Test, _>::LicenseOfferNotFound
+        );
+
+        // Ids are not reused
+        assert_ok!(CustomPallet::add_license_offer(
+            RuntimeOrigin::signed(account_id),
+            0,
+            license_offer_for_test(LicenseKind::Sync, 100, None)
+        ));
+        System::assert_last_event(
+            Event::<Test>::LicenseOfferAdded {
+                entity_id: 0,
+                offer_id: MaxLicenseOffers::get() + 1,
+            }
+            .into(),
+        );
+
+        for (offer_id, _) in LicenseOffers::<Test>::get(0) {
+            assert_ok!(CustomPallet::remove_license_offer(
+                RuntimeOrigin::signed(account_id),
+                0,
+                offer_id
+            ));
+        }
+        assert!(!LicenseOffers::<Test>::contains_key(0));
+    });
+}
+
+#[test]
+fn test_purchase_license() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let account_id: u64 = 0;
+        let authority_id: u32 = 0;
+        let buyer: u64 = 1;
+        Balances::make_free_balance_be(&buyer, 1_000);
+
+        add_authority_access_for_test(account_id, authority_id, None);
+        add_entity_for_test(
+            0,
+            authority_id,
+            Some(
+                vec![
+                    Wallet {
+                        address_id: 10,
+                        share: Perbill::from_percent(50),
+                    },
+                    Wallet {
+                        address_id: 11,
+                        share: Perbill::from_percent(50),
+                    },
+                ]
+                .try_into()
+                .unwrap(),
+            ),
+        );
+
+        assert_ok!(CustomPallet::add_license_offer(
+            RuntimeOrigin::signed(account_id),
+            0,
+            license_offer_for_test(LicenseKind::Sync, 100, None)
+        ));
+        assert_ok!(CustomPallet::add_license_offer(
+            RuntimeOrigin::signed(account_id),
+            0,
+            license_offer_for_test(LicenseKind::Streaming, 10, Some(5))
+        ));
+
+        assert_err!(
+            CustomPallet::purchase_license(
+                RuntimeOrigin::signed(buyer),
+                0,
+                0,
+                99,
+                PaymentCurrency::Native
+            ),
+            Error::<Test, _>::LicensePriceTooHigh
+        );
+        assert_err!(
+            CustomPallet::purchase_license(
+                RuntimeOrigin::signed(buyer),
+                0,
+                2,
+                1_000,
+                PaymentCurrency::Native
+            ),
+            Error::<Test, _>::LicenseOfferNotFound
+        );
+        assert_err!(
+            CustomPallet::purchase_license(
+                RuntimeOrigin::signed(buyer),
+                1,
+                0,
+                1_000,
+                PaymentCurrency::Native
+            ),
+            Error::<Test, _>::LicenseOfferNotFound
+        );
+
+        assert_ok!(CustomPallet::purchase_license(
+            RuntimeOrigin::signed(buyer),
+            0,
+            0,
+            100,
+            PaymentCurrency::Native
+        ));
+        System::assert_last_event(
+            Event::<Test>::LicensePurchased {
+                entity_id: 0,
+                buyer,
+                kind: LicenseKind::Sync,
+                price: 100,
+                currency: PaymentCurrency::Native,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(buyer), 900);
+        assert_eq!(Balances::free_balance(10), 50);
+        assert_eq!(Balances::free_balance(11), 50);
+
+        assert_ok!(CustomPallet::purchase_license(
+            RuntimeOrigin::signed(buyer),
+            0,
+            1,
+            10,
+            PaymentCurrency::Native
+        ));
+
+        assert!(CustomPallet::is_licensed(buyer, 0, LicenseKind::Sync));
+        assert!(CustomPallet::is_licensed(buyer, 0, LicenseKind::Streaming));
+        assert!(!CustomPallet::is_licensed(buyer, 0, LicenseKind::SampleUse));
+        assert!(!CustomPallet::is_licensed(account_id, 0, LicenseKind::Sync));
+
+        System::set_block_number(6);
+
+        assert!(CustomPallet::is_licensed(buyer, 0, LicenseKind::Sync));
+        assert!(!CustomPallet::is_licensed(buyer, 0, LicenseKind::Streaming));
+
+        // Expired licenses are pruned on the next purchase.
+        assert_ok!(CustomPallet::purchase_license(
+            RuntimeOrigin::signed(buyer),
+            0,
+            0,
+            100,
+            PaymentCurrency::Native
+        ));
+        assert_eq!(Licenses::<Test>::get(buyer, 0).len(), 2);
+    });
+}
+
+#[test]
+fn test_license_deposits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        LicenseDeposit::set(5);
+
+        let account_id: u64 = 0;
+        let authority_id: u32 = 0;
+        let buyer: u64 = 1;
+        Balances::make_free_balance_be(&buyer, 100);
+
+        add_authority_access_for_test(account_id, authority_id, None);
+        add_entity_for_test(0, authority_id, None);
+
+        assert_ok!(CustomPallet::add_license_offer(
+            RuntimeOrigin::signed(account_id),
+            0,
+            license_offer_for_test(LicenseKind::Sync, 0, None)
+        ));
+        assert_ok!(CustomPallet::add_license_offer(
+            RuntimeOrigin::signed(account_id),
+            0,
+            license_offer_for_test(LicenseKind::Streaming, 0, Some(5))
+        ));
+
+        // Free licenses hold a deposit per license
+        assert_ok!(CustomPallet::purchase_license(
+            RuntimeOrigin::signed(buyer),
+            0,
+            0,
+            0,
+            PaymentCurrency::Native
+        ));
+        assert_eq!(Balances::total_balance_on_hold(&buyer), 5);
+        assert_ok!(CustomPallet::purchase_license(
+            RuntimeOrigin::signed(buyer),
+            0,
+            1,
+            0,
+            PaymentCurrency::Native
+        ));
+        assert_eq!(Balances::total_balance_on_hold(&buyer), 10);
+
+        // A buyer that can not afford the deposit can not buy a free license
+        Balances::make_free_balance_be(&2, 3);
+        assert!(CustomPallet::purchase_license(
+            RuntimeOrigin::signed(2),
+            0,
+            0,
+            0,
+            PaymentCurrency::Native
+        )
+        .is_err());
+        assert!(Licenses::<Test>::get(2, 0).is_empty());
+
+        assert_err!(
+            CustomPallet::prune_licenses(RuntimeOrigin::signed(buyer), 0),
+            Error::<Test, _>::LicenseNotFound
+        );
+
+        // Pruning the expired license releases its deposit
+        System::set_block_number(6);
+        assert_ok!(CustomPallet::prune_licenses(
+            RuntimeOrigin::signed(buyer),
+            0
+        ));
+        System::assert_last_event(
+            Event::<Test>::LicensesPruned {
+                entity_id: 0,
+                holder: buyer,
+                pruned: 1,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::total_balance_on_hold(&buyer), 5);
+        assert_eq!(Licenses::<Test>::get(buyer, 0).len(), 1);
+        assert!(CustomPallet::is_licensed(buyer, 0, LicenseKind::Sync));
+        assert_eq!(LicenseDeposits::<Test>::get(buyer, 0).unwrap().amount, 5);
+    });
+}
+
+#[test]
+fn test_metadata_standards() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        add_authority_access_for_test(0, 0, None);
+
+        let ern = MetadataStandard::DdexErn(MetadataVersion::new(4, 3));
+        let musicbrainz = MetadataStandard::MusicBrainz(MetadataVersion::new(2, 0));
+        let json_ld = MetadataStandard::JsonLd(MetadataVersion::new(1, 1));
+
+        assert_ok!(add_entity_with_metadata_for_test(
+            b"https://example.com/ern.xml",
+            ern.clone()
+        ));
+        assert_eq!(Entities::<Test>::get(0).unwrap().metadata.standard, ern);
+
+        assert_err!(
+            add_entity_with_metadata_for_test(
+                b"https://example.com/ern.xml",
+                MetadataStandard::DdexErn(MetadataVersion::new(5, 0))
+            ),
+            Error::<Test, _>::MetadataStandardNotSupported
+        );
+        assert_err!(
+            add_entity_with_metadata_for_test(b"ipfs://QmHash", musicbrainz.clone()),
+            Error::<Test, _>::MetadataUrlInvalid
+        );
+        assert_err!(
+            add_entity_with_metadata_for_test(b"ar://", json_ld.clone()),
+            Error::<Test, _>::MetadataUrlInvalid
+        );
+        assert_err!(
+            add_entity_with_metadata_for_test(b"example.com/doc.jsonld", json_ld.clone()),
+            Error::<Test, _>::MetadataUrlInvalid
+        );
+        assert_ok!(add_entity_with_metadata_for_test(
+            b"ar://TxId",
+            json_ld.clone()
+        ));
+
+        // M25 keeps accepting any url
+        assert_ok!(add_entity_with_metadata_for_test(
+            &[4, 5, 6],
+            MetadataStandard::M25
+        ));
+
+        // A new standard is checked against the current url and a new url against the standard
+        assert_ok!(CustomPallet::set_entity(
+            0,
+            0,
+            None,
+            Some(musicbrainz.clone()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+        assert_err!(
+            CustomPallet::set_entity(
+                0,
+                0,
+                Some(b"ipfs://QmHash".to_vec().try_into().unwrap()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test, _>::MetadataUrlInvalid
+        );
+        assert_err!(
+            CustomPallet::set_entity(
+                0,
+                2,
+                None,
+                Some(musicbrainz.clone()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test, _>::MetadataUrlInvalid
+        );
+
+        let standards = CustomPallet::get_metadata_standards();
+        assert_eq!(standards.len(), MetadataStandard::SUPPORTED.len());
+        assert!(standards.contains(&MetadataStandard::M25));
+        assert!(standards.contains(&ern));
+        assert!(standards.contains(&musicbrainz));
+        assert!(standards.contains(&json_ld));
+    });
+}
+
+#[test]
+fn test_entity_content() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        add_authority_access_for_test(0, 0, None);
+        add_entity_for_test(0, 0, None);
+        add_entity_for_test(1, 0, None);
+
+        let content = content_for_test(1);
+        let content_hash = content.content_hash.clone();
+
+        assert_err!(
+            CustomPallet::set_content(1, 0, Some(content.clone())),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+
+        // The content hash must be a multihash with a digest of the declared length
+        let mut invalid = content.clone();
+        invalid.content_hash = vec![0x12, 0x20, 1, 2, 3].try_into().unwrap();
+        assert_err!(
+            CustomPallet::set_content(0, 0, Some(invalid)),
+            Error::<Test, _>::EntityContentHashInvalid
+        );
+
+        assert_ok!(CustomPallet::set_content(0, 0, Some(content.clone())));
+        System::assert_last_event(
+            Event::EntityContentAnchored {
+                entity_id: 0,
+                content_hash: content_hash.clone(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            Entities::<Test>::get(0).unwrap().content,
+            Some(content.clone())
+        );
+        assert_eq!(
+            CustomPallet::get_entity_by_content_hash(content_hash.to_vec()),
+            Some(0)
+        );
+
+        // The same content can not be anchored twice
+        assert_err!(
+            CustomPallet::set_content(0, 1, Some(content.clone())),
+            Error::<Test, _>::EntityContentAlreadyAnchored
+        );
+        assert_ok!(CustomPallet::set_content(0, 0, Some(content.clone())));
+
+        // Replacing the content releases the old content hash
+        assert_ok!(CustomPallet::set_content(0, 0, Some(content_for_test(2))));
+        assert_eq!(
+            CustomPallet::get_entity_by_content_hash(content_hash.to_vec()),
+            None
+        );
+        assert_ok!(CustomPallet::set_content(0, 1, Some(content.clone())));
+        assert_eq!(
+            CustomPallet::get_entity_by_content_hash(content_hash.to_vec()),
+            Some(1)
+        );
+
+        assert_ok!(CustomPallet::set_content(0, 0, None));
+        System::assert_last_event(Event::EntityContentCleared { entity_id: 0 }.into());
+        assert_eq!(Entities::<Test>::get(0).unwrap().content, None);
+
+        // The content of an immutable entity is anchored once
+        Entities::<Test>::mutate(0, |entity| {
+            entity
+                .as_mut()
+                .unwrap()
+                .metadata
+                .features
+                .add_feature(MetadataFeature::Immutable)
+        });
+        assert_ok!(CustomPallet::set_content(0, 0, Some(content_for_test(3))));
+        assert_err!(
+            CustomPallet::set_content(0, 0, None),
+            Error::<Test, _>::EntityContentImmutable
+        );
+
+        // Removing the entity releases its content hash
+        assert_ok!(CustomPallet::delete_entity(0, 1, 0));
+        assert_eq!(
+            CustomPallet::get_entity_by_content_hash(content_hash.to_vec()),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_industry_identifiers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert!(Isrc(*b"USS1Z9900001").is_valid());
+        assert!(!Isrc(*b"us-s1z-99-01").is_valid());
+        assert!(Iswc(*b"T0345246801").is_valid());
+        assert!(!Iswc(*b"T0345246802").is_valid());
+        assert!(Upc(*b"0036000291452").is_valid());
+        assert!(!Upc(*b"0036000291453").is_valid());
+        assert!(Ipi(*b"00052210040").is_valid());
+        assert!(!Ipi(*b"I-000522100").is_valid());
+
+        add_authority_access_for_test(0, 0, None);
+        add_entity_for_test(0, 0, None);
+        add_entity_for_test(1, 0, None);
+
+        let isrc = IndustryIdentifier::Isrc(Isrc(*b"USS1Z9900001"));
+        let identifiers = EntityIdentifiers {
+            isrc: Some(Isrc(*b"USS1Z9900001")),
+            iswc: Some(Iswc(*b"T0345246801")),
+            upc: None,
+        };
+
+        assert_err!(
+            CustomPallet::set_identifiers(
+                0,
+                0,
+                EntityIdentifiers {
+                    upc: Some(Upc(*b"0036000291453")),
+                    ..Default::default()
+                }
+            ),
+            Error::<Test, _>::IdentifierInvalid
+        );
+
+        assert_ok!(CustomPallet::set_identifiers(0, 0, identifiers.clone()));
+        System::assert_last_event(Event::EntityIdentifiersSet { entity_id: 0 }.into());
+        assert_eq!(Entities::<Test>::get(0).unwrap().identifiers, identifiers);
+        assert_eq!(CustomPallet::get_entity_by_identifier(isrc), Some(0));
+        assert_eq!(
+            CustomPallet::get_entity_by_identifier(IndustryIdentifier::Iswc(Iswc(*b"T0345246801"))),
+            Some(0)
+        );
+
+        // An identifier is registered by one entity only
+        assert_err!(
+            CustomPallet::set_identifiers(
+                0,
+                1,
+                EntityIdentifiers {
+                    isrc: Some(Isrc(*b"USS1Z9900001")),
+                    ..Default::default()
+                }
+            ),
+            Error::<Test, _>::IdentifierAlreadyRegistered
+        );
+
+        // Replacing the identifiers releases the old ones
+        assert_ok!(CustomPallet::set_identifiers(
+            0,
+            0,
+            EntityIdentifiers {
+                iswc: Some(Iswc(*b"T0345246801")),
+                ..Default::default()
+            }
+        ));
+        assert_eq!(CustomPallet::get_entity_by_identifier(isrc), None);
+        assert_ok!(CustomPallet::set_identifiers(
+            0,
+            1,
+            EntityIdentifiers {
+                isrc: Some(Isrc(*b"USS1Z9900001")),
+                ..Default::default()
+            }
+        ));
+        assert_eq!(CustomPallet::get_entity_by_identifier(isrc), Some(1));
+
+        assert_ok!(CustomPallet::delete_entity(0, 1, 0));
+        assert_eq!(CustomPallet::get_entity_by_identifier(isrc), None);
+
+        // IPI name numbers of authors
+        let ipi = Ipi(*b"00052210040");
+        add_author_for_test(0, 0);
+        add_author_for_test(1, 1);
+        Authors::<Test>::mutate(0, |author| {
+            author.as_mut().unwrap().status = AuthorStatus::Verified
+        });
+
+        assert_err!(
+            CustomPallet::set_ipi(1, 0, Some(ipi)),
+            Error::<Test, _>::NoPermission
+        );
+        assert_err!(
+            CustomPallet::set_ipi(0, 0, Some(Ipi(*b"I-000522100"))),
+            Error::<Test, _>::IdentifierInvalid
+        );
+
+        assert_ok!(CustomPallet::set_ipi(0, 0, Some(ipi)));
+        System::assert_last_event(
+            Event::AuthorIpiSet {
+                author_id: 0,
+                ipi: Some(ipi),
+            }
+            .into(),
+        );
+        let author = Authors::<Test>::get(0).unwrap();
+        assert_eq!(author.ipi, Some(ipi));
+        // A new IPI name number drops the verification
+        assert_eq!(author.status, AuthorStatus::Claimed);
+        assert_eq!(CustomPallet::get_author_by_ipi(ipi), Some(0));
+
+        assert_err!(
+            CustomPallet::set_ipi(1, 1, Some(ipi)),
+            Error::<Test, _>::IdentifierAlreadyRegistered
+        );
+
+        assert_ok!(CustomPallet::set_ipi(0, 0, None));
+        assert_eq!(CustomPallet::get_author_by_ipi(ipi), None);
+        assert_ok!(CustomPallet::set_ipi(1, 1, Some(ipi)));
+
+        assert_ok!(CustomPallet::delete_author(1, 1));
+        assert_eq!(CustomPallet::get_author_by_ipi(ipi), None);
+    });
+}
+
+#[test]
+fn test_timestamps_and_revisions() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CustomPallet::add_new_authority(
+            0,
+            vec![1, 2, 3].try_into().unwrap(),
+            AuthorityKind::Label,
+            None
+        ));
+        assert_ok!(CustomPallet::add_new_author(
+            0,
+            vec![1].try_into().unwrap(),
+            None,
+            None
+        ));
+        assert_ok!(add_entity_with_metadata_for_test(
+            &[4, 5, 6],
+            MetadataStandard::M25
+        ));
+
+        let entity = Entities::<Test>::get(0).unwrap();
+        assert_eq!((entity.created_at, entity.updated_at), (1, 1));
+        assert_eq!(entity.revision, 0);
+        assert_eq!(
+            CustomPallet::get_entity_revision(0, 0),
+            Some(EntityRevision {
+                editor: 0,
+                details: entity,
+            })
+        );
+
+        System::set_block_number(2);
+        assert_ok!(CustomPallet::set_authority(
+            0,
+            0,
+            None,
+            Some(AuthorityKind::Musician),
+            None
+        ));
+        assert_ok!(CustomPallet::set_author(
+            0,
+            0,
+            Some(vec![2].try_into().unwrap()),
+            None
+        ));
+
+        let authority = Authorities::<Test>::get(0).unwrap();
+        assert_eq!((authority.created_at, authority.updated_at), (1, 2));
+        let author = Authors::<Test>::get(0).unwrap();
+        assert_eq!((author.created_at, author.updated_at), (1, 2));
+
+        // Every edit of an entity is a new revision with its editor
+        let royalty_parts: BoundedVec<_, MaxRoyaltyParts> = vec![Wallet {
+            address_id: 10,
+            share: Perbill::one(),
+        }]
+        .try_into()
+        .unwrap();
+        CustomPallet::write_access(0, &1, Some(AuthorityAccessSettings::all())).unwrap();
+        assert_ok!(CustomPallet::set_entity(
+            1,
+            0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(royalty_parts.clone()),
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        let entity = Entities::<Test>::get(0).unwrap();
+        assert_eq!((entity.created_at, entity.updated_at), (1, 2));
+        assert_eq!(entity.revision, 1);
+        let revision = CustomPallet::get_entity_revision(0, 1).unwrap();
+        assert_eq!(revision.editor, 1);
+        assert_eq!(revision.details.royalty_parts, Some(royalty_parts));
+        assert_eq!(
+            CustomPallet::get_entity_revision(0, 0)
+                .unwrap()
+                .details
+                .royalty_parts,
+            None
+        );
+
+        System::set_block_number(3);
+        assert_ok!(CustomPallet::set_identifiers(
+            0,
+            0,
+            EntityIdentifiers {
+                isrc: Some(Isrc(*b"USS1Z9900001")),
+                ..Default::default()
+            }
+        ));
+        assert_ok!(CustomPallet::set_content(0, 0, Some(content_for_test(1))));
+
+        let entity = Entities::<Test>::get(0).unwrap();
+        assert_eq!(entity.updated_at, 3);
+        assert_eq!(entity.revision, 3);
+        assert_eq!(
+            CustomPallet::get_entity_revision(0, 3).unwrap().details,
+            entity
+        );
+
+        // Only the latest `MaxEntityRevisions` revisions are kept
+        assert_eq!(CustomPallet::get_entity_revision(0, 0), None);
+        assert!(CustomPallet::get_entity_revision(0, 1).is_some());
+
+        assert_ok!(CustomPallet::delete_entity(0, 0, 0));
+        assert_eq!(EntityRevisions::<Test>::iter_prefix(0).count(), 0);
+    });
+}
+
+#[test]
+fn test_disputes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        DisputeBond::set(10);
+        Balances::make_free_balance_be(&2, 1_000);
+
+        add_authority_access_for_test(0, 0, None);
+        add_authority_access_for_test(1, 1, None);
+        add_entity_for_test(0, 0, None);
+        add_entity_for_test(1, 0, None);
+        let evidence_url: BoundedVec<u8, MaxLongStringLength> = vec![1, 2].try_into().unwrap();
+        assert_ok!(CustomPallet::add_new_license_offer(
+            0,
+            0,
+            license_offer_for_test(LicenseKind::Sync, 0, None)
+        ));
+
+        assert_err!(
+            CustomPallet::add_dispute(2, 5, evidence_url.clone()),
+            Error::<Test, _>::EntityNotFound
+        );
+
+        assert_ok!(CustomPallet::open_dispute(
+            RuntimeOrigin::signed(2),
+            0,
+            evidence_url.clone()
+        ));
+        System::assert_last_event(
+            Event::DisputeOpened {
+                entity_id: 0,
+                claimant: 2,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::total_balance_on_hold(&2), 10);
+        assert_eq!(
+            CustomPallet::get_dispute(0).unwrap().evidence_url,
+            evidence_url
+        );
+
+        assert_err!(
+            CustomPallet::add_dispute(2, 0, evidence_url.clone()),
+            Error::<Test, _>::DisputeAlreadyOpen
+        );
+
+        // The disputed entity is frozen
+        assert_err!(
+            CustomPallet::set_entity(
+                0,
+                0,
+                Some(vec![7].try_into().unwrap()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(1),
+                Some(0),
+                None,
+            ),
+            Error::<Test, _>::EntityFrozen
+        );
+        assert_err!(
+            CustomPallet::set_content(0, 0, Some(content_for_test(1))),
+            Error::<Test, _>::EntityFrozen
+        );
+        assert_err!(
+            CustomPallet::offer_transfer(0, 0, 1, 0, PaymentCurrency::Native),
+            Error::<Test, _>::EntityFrozen
+        );
+        assert_err!(
+            CustomPallet::delete_entity(0, 0, 0),
+            Error::<Test, _>::EntityFrozen
+        );
+        assert_err!(
+            CustomPallet::buy_license(2, 0, 0, 0, PaymentCurrency::Native),
+            Error::<Test, _>::EntityFrozen
+        );
+
+        // Only the arbiter resolves disputes
+        assert_err!(
+            CustomPallet::resolve_dispute(RuntimeOrigin::signed(2), 0, DisputeResolution::Reject),
+            DispatchError::BadOrigin
+        );
+
+        // A rejected claim slashes the bond and unfreezes the entity
+        assert_ok!(CustomPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            0,
+            DisputeResolution::Reject
+        ));
+        System::assert_last_event(
+            Event::DisputeResolved {
+                entity_id: 0,
+                resolution: DisputeResolution::Reject,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::total_balance_on_hold(&2), 0);
+        assert_eq!(Balances::free_balance(&2), 990);
+        assert_eq!(CustomPallet::get_dispute(0), None);
+        assert_ok!(CustomPallet::set_content(0, 0, Some(content_for_test(1))));
+
+        assert_err!(
+            CustomPallet::settle_dispute(0, DisputeResolution::Reject),
+            Error::<Test, _>::DisputeNotFound
+        );
+
+        // An upheld claim refunds the bond and takes the entity down
+        assert_ok!(CustomPallet::add_dispute(2, 0, evidence_url.clone()));
+        assert_ok!(CustomPallet::settle_dispute(0, DisputeResolution::Uphold));
+        assert_eq!(Balances::total_balance_on_hold(&2), 0);
+        assert_eq!(Balances::free_balance(&2), 990);
+        assert!(CustomPallet::is_taken_down(0));
+        assert_err!(
+            CustomPallet::set_content(0, 0, None),
+            Error::<Test, _>::EntityFrozen
+        );
+        assert_err!(
+            CustomPallet::add_dispute(2, 0, evidence_url.clone()),
+            Error::<Test, _>::EntityFrozen
+        );
+
+        // A taken down entity stops earning and keeps its content hash reserved
+        assert_err!(
+            CustomPallet::add_new_license_offer(
+                0,
+                0,
+                license_offer_for_test(LicenseKind::Sync, 0, None)
+            ),
+            Error::<Test, _>::EntityFrozen
+        );
+        assert_err!(
+            CustomPallet::pay_royalties(RuntimeOrigin::signed(2), 0, 100, PaymentCurrency::Native),
+            Error::<Test, _>::EntityFrozen
+        );
+        assert_err!(
+            CustomPallet::delete_entity(0, 0, 0),
+            Error::<Test, _>::EntityFrozen
+        );
+        assert!(CustomPallet::is_taken_down(0));
+        assert_eq!(
+            EntitiesByContentHash::<Test>::get(content_for_test(1).content_hash),
+            Some(0)
+        );
+
+        // The arbiter can hand the entity over to another authority
+        assert_ok!(CustomPallet::add_dispute(2, 1, evidence_url));
+        assert_ok!(CustomPallet::settle_dispute(
+            1,
+            DisputeResolution::TransferOwnership(1)
+        ));
+        assert_eq!(Balances::free_balance(&2), 990);
+        assert_eq!(Entities::<Test>::get(1).unwrap().owner, 1);
+        assert_eq!(CustomPallet::get_entities_of_authority(1), vec![1]);
+        assert_eq!(CustomPallet::get_entities_of_authority(0), vec![0]);
+        assert_eq!(CustomPallet::get_entity_revision(1, 1).unwrap().editor, 2);
+        assert!(!CustomPallet::is_taken_down(1));
+
+        // Only the arbiter can reinstate a taken down entity
+        assert_err!(
+            CustomPallet::reinstate_entity(RuntimeOrigin::signed(0), 0),
+            DispatchError::BadOrigin
+        );
+        assert_err!(
+            CustomPallet::reinstate_entity(RuntimeOrigin::root(), 1),
+            Error::<Test, _>::EntityNotTakenDown
+        );
+        assert_ok!(CustomPallet::reinstate_entity(RuntimeOrigin::root(), 0));
+        System::assert_last_event(Event::EntityReinstated { entity_id: 0 }.into());
+        assert!(!CustomPallet::is_taken_down(0));
+        assert_ok!(CustomPallet::add_new_license_offer(
+            0,
+            0,
+            license_offer_for_test(LicenseKind::Sync, 0, None)
+        ));
+    });
+}
+
+#[test]
+fn test_authority_policies() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        add_authority_access_for_test(0, 0, None);
+        add_authority_access_for_test(1, 0, None);
+        add_authority_access_for_test(2, 0, None);
+        add_authority_access_for_test(5, 1, None);
+        add_entity_for_test(0, 0, None);
+        let policy = AuthorityPolicy {
+            threshold: 2,
+            actions: GovernedActions::all(),
+        };
+
+        // The threshold needs at least two and at most the current `EditAccess` holders
+        assert_err!(
+            CustomPallet::set_authority_policy(
+                RuntimeOrigin::signed(0),
+                0,
+                AuthorityPolicy {
+                    threshold: 1,
+                    ..policy
+                }
+            ),
+            Error::<Test, _>::PolicyThresholdInvalid
+        );
+        assert_err!(
+            CustomPallet::set_authority_policy(
+                RuntimeOrigin::signed(0),
+                0,
+                AuthorityPolicy {
+                    threshold: 4,
+                    ..policy
+                }
+            ),
+            Error::<Test, _>::PolicyThresholdInvalid
+        );
+        assert_err!(
+            CustomPallet::set_authority_policy(RuntimeOrigin::signed(5), 0, policy),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+
+        // Access changes are always governed, otherwise a single holder could grant itself the other actions
+        assert_err!(
+            CustomPallet::set_authority_policy(
+                RuntimeOrigin::signed(0),
+                0,
+                AuthorityPolicy {
+                    actions: GovernedActions(
+                        GovernedAction::EntityTransfer | GovernedAction::RoyaltyEdit
+                    ),
+                    ..policy
+                }
+            ),
+            Error::<Test, _>::PolicyActionsInvalid
+        );
+        assert_err!(
+            CustomPallet::set_authority_policy(
+                RuntimeOrigin::signed(0),
+                0,
+                AuthorityPolicy {
+                    actions: GovernedActions::none(),
+                    ..policy
+                }
+            ),
+            Error::<Test, _>::PolicyActionsInvalid
+        );
+
+        assert_ok!(CustomPallet::set_authority_policy(
+            RuntimeOrigin::signed(0),
+            0,
+            policy
+        ));
+        System::assert_last_event(
+            Event::AuthorityPolicySet {
+                authority_id: 0,
+                policy: Some(policy),
+            }
+            .into(),
+        );
+        assert_eq!(CustomPallet::get_authority_policy(0), Some(policy));
+
+        // The policy is only changed by a proposal
+        assert_err!(
+            CustomPallet::set_authority_policy(RuntimeOrigin::signed(0), 0, policy),
+            Error::<Test, _>::ProposalRequired
+        );
+
+        // Single accounts can not act alone on governed actions
+        assert_err!(
+            CustomPallet::create_account_access(
+                RuntimeOrigin::signed(0),
+                0,
+                3,
+                AuthorityAccessSettings::all(),
+                None
+            ),
+            Error::<Test, _>::ProposalRequired
+        );
+        assert_err!(
+            CustomPallet::offer_entity_transfer(
+                RuntimeOrigin::signed(0),
+                0,
+                1,
+                0,
+                PaymentCurrency::Native
+            ),
+            Error::<Test, _>::ProposalRequired
+        );
+        let royalty_parts: BoundedVec<Wallet<u64>, MaxRoyaltyParts> = vec![Wallet {
+            address_id: 10,
+            share: Perbill::one(),
+        }]
+        .try_into()
+        .unwrap();
+        assert_err!(
+            CustomPallet::edit_entity(
+                RuntimeOrigin::signed(0),
+                0,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(royalty_parts.clone()),
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test, _>::ProposalRequired
+        );
+
+        // An access change executes once the second holder approves
+        assert_ok!(CustomPallet::propose_authority_action(
+            RuntimeOrigin::signed(0),
+            0,
+            AuthorityAction::SetAccess {
+                account_id: 3,
+                access: Some(AuthorityAccessSettings::all()),
+                expires_at: None,
+            }
+        ));
+        System::assert_last_event(
+            Event::ProposalCreated {
+                authority_id: 0,
+                proposal_id: 0,
+                proposer: 0,
+            }
+            .into(),
+        );
+        assert_err!(
+            CustomPallet::approve_authority_proposal(RuntimeOrigin::signed(0), 0, 0),
+            Error::<Test, _>::ProposalAlreadyApproved
+        );
+        assert_err!(
+            CustomPallet::approve_authority_proposal(RuntimeOrigin::signed(5), 0, 0),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+        assert_ok!(CustomPallet::approve_authority_proposal(
+            RuntimeOrigin::signed(1),
+            0,
+            0
+        ));
+        System::assert_last_event(
+            Event::ProposalExecuted {
+                authority_id: 0,
+                proposal_id: 0,
+            }
+            .into(),
+        );
+        assert!(CustomPallet::get_proposal(0, 0).is_none());
+        assert!(AuthoritiesAccess::<Test>::contains_key(0, 3));
+
+        // A royalty edit executes with the proposer as the editor of the revision
+        assert_ok!(CustomPallet::propose_authority_action(
+            RuntimeOrigin::signed(1),
+            0,
+            AuthorityAction::SetRoyaltyParts {
+                entity_id: 0,
+                royalty_parts: royalty_parts.clone(),
+            }
+        ));
+        assert_ok!(CustomPallet::approve_authority_proposal(
+            RuntimeOrigin::signed(2),
+            0,
+            1
+        ));
+        let entity = Entities::<Test>::get(0).unwrap();
+        assert_eq!(entity.royalty_parts, Some(royalty_parts));
+        assert_eq!(
+            CustomPallet::get_entity_revision(0, entity.revision)
+                .unwrap()
+                .editor,
+            1
+        );
+
+        // A transfer offer pays the proposer
+        assert_ok!(CustomPallet::propose_authority_action(
+            RuntimeOrigin::signed(2),
+            0,
+            AuthorityAction::OfferTransfer {
+                entity_id: 0,
+                to_authority: 1,
+                price: 0,
+                currency: PaymentCurrency::Native,
+            }
+        ));
+        assert_ok!(CustomPallet::approve_authority_proposal(
+            RuntimeOrigin::signed(3),
+            0,
+            2
+        ));
+        assert_eq!(EntityTransfers::<Test>::get(0).unwrap().beneficiary, 2);
+
+        // Holders can not drop below the threshold
+        assert_ok!(CustomPallet::propose_authority_action(
+            RuntimeOrigin::signed(0),
+            0,
+            AuthorityAction::SetPolicy(Some(AuthorityPolicy {
+                threshold: 4,
+                ..policy
+            }))
+        ));
+        assert_ok!(CustomPallet::approve_authority_proposal(
+            RuntimeOrigin::signed(1),
+            0,
+            3
+        ));
+        assert_eq!(CustomPallet::get_authority_policy(0).unwrap().threshold, 4);
+        assert_err!(
+            CustomPallet::write_access(0, &3, None),
+            Error::<Test, _>::PolicyThresholdUnreachable
+        );
+
+        // Only the proposer cancels, the authority can not be removed with pending proposals
+        assert_ok!(CustomPallet::propose_authority_action(
+            RuntimeOrigin::signed(0),
+            0,
+            AuthorityAction::SetPolicy(None)
+        ));
+        assert_err!(
+            CustomPallet::cancel_authority_proposal(RuntimeOrigin::signed(1), 0, 4),
+            Error::<Test, _>::NotAuthorized
+        );
+        assert_err!(
+            CustomPallet::delete_authority(0, 0, 4),
+            Error::<Test, _>::AuthorityHasProposals
+        );
+        assert_ok!(CustomPallet::cancel_authority_proposal(
+            RuntimeOrigin::signed(0),
+            0,
+            4
+        ));
+        System::assert_last_event(
+            Event::ProposalCancelled {
+                authority_id: 0,
+                proposal_id: 4,
+            }
+            .into(),
+        );
+        assert_err!(
+            CustomPallet::approve_authority_proposal(RuntimeOrigin::signed(1), 0, 4),
+            Error::<Test, _>::ProposalNotFound
+        );
+
+        // Pending proposals hold a deposit and are capped per authority
+        ProposalDeposit::set(5);
+        Balances::make_free_balance_be(&0, 100);
+        for _ in 0..MaxAuthorityProposals::get() {
+            assert_ok!(CustomPallet::propose_authority_action(
+                RuntimeOrigin::signed(0),
+                0,
+                AuthorityAction::SetPolicy(None)
+            ));
+        }
+        assert_eq!(Balances::total_balance_on_hold(&0), 15);
+        assert_err!(
+            CustomPallet::propose_authority_action(
+                RuntimeOrigin::signed(0),
+                0,
+                AuthorityAction::SetPolicy(None)
+            ),
+            Error::<Test, _>::LimitExceeded
+        );
+
+        // The deposit is released once the proposal is cancelled or executed
+        assert_ok!(CustomPallet::cancel_authority_proposal(
+            RuntimeOrigin::signed(0),
+            0,
+            5
+        ));
+        assert_eq!(Balances::total_balance_on_hold(&0), 10);
+        for approver in [1, 2, 3] {
+            assert_ok!(CustomPallet::approve_authority_proposal(
+                RuntimeOrigin::signed(approver),
+                0,
+                6
+            ));
+        }
+        assert_eq!(CustomPallet::get_authority_policy(0), None);
+        assert_eq!(Balances::total_balance_on_hold(&0), 5);
+    });
+}
+
+#[test]
+fn test_access_expiry_and_invitations() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        AccessDeposit::set(5);
+        Balances::make_free_balance_be(&0, 1_000);
+
+        add_authority_access_for_test(0, 0, None);
+        let edit_entity = AuthorityAccessSettings(AuthorityAccessSetting::EditEntity.into());
+
+        // The `EditAccess` right never expires and the expiry is in the future
+        assert_err!(
+            CustomPallet::add_access(0, 0, 1, AuthorityAccessSettings::all(), Some(10)),
+            Error::<Test, _>::EditAccessCannotExpire
+        );
+        assert_err!(
+            CustomPallet::add_access(0, 0, 1, edit_entity, Some(1)),
+            Error::<Test, _>::AccessExpiryInvalid
+        );
+
+        // An expired access is treated as absent
+        assert_ok!(CustomPallet::add_access(0, 0, 1, edit_entity, Some(10)));
+        assert_ok!(CustomPallet::ensure_access_right(
+            &1,
+            &0,
+            AuthorityAccessSetting::EditEntity.into()
+        ));
+        System::set_block_number(10);
+        assert_err!(
+            CustomPallet::ensure_access_right(&1, &0, AuthorityAccessSetting::EditEntity.into()),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+        assert!(CustomPallet::get_account_overview(1).authorities.is_empty());
+
+        // and is lazily pruned by anyone
+        assert_err!(
+            CustomPallet::prune_expired_account_access(RuntimeOrigin::signed(5), 0, 0),
+            Error::<Test, _>::AccessNotExpired
+        );
+        assert_eq!(Balances::total_balance_on_hold(&0), 5);
+        assert_ok!(CustomPallet::prune_expired_account_access(
+            RuntimeOrigin::signed(5),
+            0,
+            1
+        ));
+        System::assert_last_event(
+            Event::AuthoritiesAccessExpired {
+                authority_id: 0,
+                account_id: 1,
+            }
+            .into(),
+        );
+        assert!(!AuthoritiesAccess::<Test>::contains_key(0, 1));
+        assert!(!AccessExpiries::<Test>::contains_key(0, 1));
+        assert_eq!(Balances::total_balance_on_hold(&0), 0);
+
+        // An invited account is granted the access once it accepts it
+        assert_ok!(CustomPallet::invite_account_access(
+            RuntimeOrigin::signed(0),
+            0,
+            2,
+            edit_entity,
+            Some(20)
+        ));
+        System::assert_last_event(
+            Event::AccessInvited {
+                authority_id: 0,
+                account_id: 2,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::total_balance_on_hold(&0), 5);
+        assert_err!(
+            CustomPallet::ensure_access_right(&2, &0, AuthorityAccessSetting::EditEntity.into()),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+        assert_err!(
+            CustomPallet::add_access(0, 0, 2, edit_entity, None),
+            Error::<Test, _>::AccessInvitationPending
+        );
+        assert_err!(
+            CustomPallet::accept_account_access(RuntimeOrigin::signed(3), 0),
+            Error::<Test, _>::AccessInvitationNotFound
+        );
+
+        assert_ok!(CustomPallet::accept_account_access(
+            RuntimeOrigin::signed(2),
+            0
+        ));
+        assert!(CustomPallet::get_access_invitation(0, &2).is_none());
+        assert_ok!(CustomPallet::ensure_access_right(
+            &2,
+            &0,
+            AuthorityAccessSetting::EditEntity.into()
+        ));
+        assert_eq!(AccessExpiries::<Test>::get(0, 2), Some(20));
+        assert_eq!(Balances::total_balance_on_hold(&0), 5);
+
+        // An invitation is declined by the invited account or cancelled by an access editor
+        assert_ok!(CustomPallet::invite_account_access(
+            RuntimeOrigin::signed(0),
+            0,
+            3,
+            edit_entity,
+            None
+        ));
+        assert_err!(
+            CustomPallet::cancel_account_access_invitation(RuntimeOrigin::signed(2), 0, 3),
+            Error::<Test, _>::NotAuthorized
+        );
+        assert_err!(
+            CustomPallet::delete_authority(0, 0, 2),
+            Error::<Test, _>::AuthorityHasInvitations
+        );
+        assert_ok!(CustomPallet::cancel_account_access_invitation(
+            RuntimeOrigin::signed(3),
+            0,
+            3
+        ));
+        System::assert_last_event(
+            Event::AccessInvitationCancelled {
+                authority_id: 0,
+                account_id: 3,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::total_balance_on_hold(&0), 5);
+    });
+}
+
+#[test]
+fn test_access_delegation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        AccessDeposit::set(5);
+        Balances::make_free_balance_be(&0, 1_000);
+        Balances::make_free_balance_be(&1, 1_000);
+
+        add_authority_access_for_test(0, 0, None);
+        let create_entity = AuthorityAccessSettings(AuthorityAccessSetting::CreateEntity.into());
+        let edit_entity = AuthorityAccessSettings(AuthorityAccessSetting::EditEntity.into());
+        let entity_access = AuthorityAccessSettings(
+            AuthorityAccessSetting::CreateEntity | AuthorityAccessSetting::EditEntity,
+        );
+        assert_ok!(CustomPallet::add_access(0, 0, 1, entity_access, Some(50)));
+
+        // Only a non-empty strict subset without the `EditAccess` right can be delegated
+        for access in [
+            entity_access,
+            AuthorityAccessSettings::none(),
+            AuthorityAccessSettings(AuthorityAccessSetting::EditAuthority.into()),
+        ] {
+            assert_err!(
+                CustomPallet::delegate_account_access(RuntimeOrigin::signed(1), 0, 2, access),
+                Error::<Test, _>::DelegationInvalid
+            );
+        }
+        assert_err!(
+            CustomPallet::delegate_account_access(
+                RuntimeOrigin::signed(0),
+                0,
+                2,
+                AuthorityAccessSettings(AuthorityAccessSetting::EditAccess.into())
+            ),
+            Error::<Test, _>::DelegationInvalid
+        );
+        assert_err!(
+            CustomPallet::delegate_account_access(RuntimeOrigin::signed(3), 0, 2, edit_entity),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+
+        assert_ok!(CustomPallet::delegate_account_access(
+            RuntimeOrigin::signed(1),
+            0,
+            2,
+            edit_entity
+        ));
+        System::assert_last_event(
+            Event::AccessDelegated {
+                authority_id: 0,
+                delegator: 1,
+                account_id: 2,
+            }
+            .into(),
+        );
+        assert_ok!(CustomPallet::ensure_access_right(
+            &2,
+            &0,
+            AuthorityAccessSetting::EditEntity.into()
+        ));
+        assert_eq!(CustomPallet::get_access_delegator(0, &2), Some(1));
+        assert_eq!(CustomPallet::get_access_delegates(0, &1), vec![2]);
+        assert_eq!(Balances::total_balance_on_hold(&1), 5);
+
+        // A delegated access is not delegated further nor edited, and the delegates are bounded
+        assert_err!(
+            CustomPallet::delegate_account_access(RuntimeOrigin::signed(2), 0, 3, edit_entity),
+            Error::<Test, _>::DelegationInvalid
+        );
+        assert_err!(
+            CustomPallet::edit_account_access(RuntimeOrigin::signed(0), 0, 2, entity_access, None),
+            Error::<Test, _>::AccessDelegated
+        );
+        assert_ok!(CustomPallet::delegate_account_access(
+            RuntimeOrigin::signed(1),
+            0,
+            3,
+            create_entity
+        ));
+        assert_err!(
+            CustomPallet::delegate_account_access(RuntimeOrigin::signed(1), 0, 4, create_entity),
+            Error::<Test, _>::LimitExceeded
+        );
+
+        // Only the delegator revokes its delegations
+        assert_err!(
+            CustomPallet::revoke_delegated_access(RuntimeOrigin::signed(0), 0, 3),
+            Error::<Test, _>::NotAuthorized
+        );
+        assert_err!(
+            CustomPallet::revoke_delegated_access(RuntimeOrigin::signed(0), 0, 1),
+            Error::<Test, _>::DelegationNotFound
+        );
+        assert_ok!(CustomPallet::revoke_delegated_access(
+            RuntimeOrigin::signed(1),
+            0,
+            3
+        ));
+        System::assert_last_event(
+            Event::AccessDelegationRevoked {
+                authority_id: 0,
+                account_id: 3,
+            }
+            .into(),
+        );
+        assert!(!AuthoritiesAccess::<Test>::contains_key(0, 3));
+        assert_eq!(CustomPallet::get_access_delegates(0, &1), vec![2]);
+        assert_eq!(Balances::total_balance_on_hold(&1), 5);
+
+        // Reducing the access of the delegator revokes the delegations it no longer covers
+        assert_ok!(CustomPallet::edit_account_access(
+            RuntimeOrigin::signed(0),
+            0,
+            1,
+            AuthorityAccessSettings(
+                AuthorityAccessSetting::CreateEntity | AuthorityAccessSetting::EditAuthority,
+            ),
+            Some(50)
+        ));
+        assert!(!AuthoritiesAccess::<Test>::contains_key(0, 2));
+        assert!(CustomPallet::get_access_delegator(0, &2).is_none());
+        assert!(!AccessDelegates::<Test>::contains_key(0, 1));
+        assert_eq!(Balances::total_balance_on_hold(&1), 0);
+
+        // A delegated access lapses with the access of the delegator
+        assert_ok!(CustomPallet::delegate_account_access(
+            RuntimeOrigin::signed(1),
+            0,
+            2,
+            create_entity
+        ));
+        System::set_block_number(50);
+        assert!(CustomPallet::get_access(0, &2).is_none());
+        assert_err!(
+            CustomPallet::ensure_access_right(&2, &0, AuthorityAccessSetting::CreateEntity.into()),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+
+        // and is removed once the access of the delegator is pruned
+        assert_ok!(CustomPallet::prune_expired_account_access(
+            RuntimeOrigin::signed(5),
+            0,
+            1
+        ));
+        assert!(!AuthoritiesAccess::<Test>::contains_key(0, 2));
+        assert!(!AccessDelegators::<Test>::contains_key(0, 2));
+        assert!(!AccessDelegates::<Test>::contains_key(0, 1));
+        assert_eq!(Balances::total_balance_on_hold(&0), 0);
+        assert_eq!(Balances::total_balance_on_hold(&1), 0);
+    });
+}
+
+#[test]
+fn test_create_release() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        NftsSupport::<Test>::set(Some(true));
+
+        add_authority_access_for_test(0, 0, Some(0));
+        add_author_for_test(0, 0);
+        let url: BoundedVec<u8, MaxLongStringLength> = vec![1, 2, 3].try_into().unwrap();
+        let royalty_parts: BoundedVec<Wallet<u64>, MaxRoyaltyParts> = vec![Wallet {
+            address_id: 10,
+            share: Perbill::from_percent(100),
+        }]
+        .try_into()
+        .unwrap();
+
+        // A release has a release kind, has tracks and none of them is a release
+        assert_err!(
+            CustomPallet::create_release(
+                RuntimeOrigin::signed(0),
+                IPEntityKind::Track,
+                0,
+                url.clone(),
+                MetadataStandard::M25,
+                MetadataFeatures::default(),
+                None,
+                None,
+                vec![release_track_for_test(IPEntityKind::Track, None)]
+                    .try_into()
+                    .unwrap(),
+                None,
+                None,
+                None
+            ),
+            Error::<Test, _>::ReleaseKindInvalid
+        );
+        assert_err!(
+            CustomPallet::create_release(
+                RuntimeOrigin::signed(0),
+                IPEntityKind::Release,
+                0,
+                url.clone(),
+                MetadataStandard::M25,
+                MetadataFeatures::default(),
+                None,
+                None,
+                Default::default(),
+                None,
+                None,
+                None
+            ),
+            Error::<Test, _>::ReleaseTracksNotFound
+        );
+        assert_err!(
+            CustomPallet::create_release(
+                RuntimeOrigin::signed(0),
+                IPEntityKind::Release,
+                0,
+                url.clone(),
+                MetadataStandard::M25,
+                MetadataFeatures::default(),
+                None,
+                None,
+                vec![release_track_for_test(IPEntityKind::StemPack, None)]
+                    .try_into()
+                    .unwrap(),
+                None,
+                None,
+                None
+            ),
+            Error::<Test, _>::ReleaseTrackInvalid
+        );
+
+        // A failing track leaves no part of the release behind
+        let mut broken_track = release_track_for_test(IPEntityKind::Track, None);
+        broken_track.authors = Some(vec![7].try_into().unwrap());
+        assert_err!(
+            CustomPallet::create_release(
+                RuntimeOrigin::signed(0),
+                IPEntityKind::Release,
+                0,
+                url.clone(),
+                MetadataStandard::M25,
+                MetadataFeatures::default(),
+                None,
+                None,
+                vec![
+                    release_track_for_test(IPEntityKind::Track, None),
+                    broken_track
+                ]
+                .try_into()
+                .unwrap(),
+                None,
+                None,
+                None
+            ),
+            Error::<Test, _>::EntityAuthorNotFound
+        );
+        assert!(!Entities::<Test>::contains_key(0));
+        assert!(NextEntityId::<Test>::get().is_none());
+
+        // The tracks share the authors and royalty parts of the release unless they set their own
+        let mut sample = release_track_for_test(IPEntityKind::Sample, None);
+        sample.royalty_parts = Some(
+            vec![Wallet {
+                address_id: 11,
+                share: Perbill::from_percent(100),
+            }]
+            .try_into()
+            .unwrap(),
+        );
+        assert_ok!(CustomPallet::create_release(
+            RuntimeOrigin::signed(0),
+            IPEntityKind::Ep,
+            0,
+            url.clone(),
+            MetadataStandard::M25,
+            MetadataFeatures::default(),
+            Some(vec![0].try_into().unwrap()),
+            Some(royalty_parts.clone()),
+            vec![release_track_for_test(IPEntityKind::Track, Some(1)), sample]
+                .try_into()
+                .unwrap(),
+            Some(0),
+            Some(0),
+            None
+        ));
+        System::assert_last_event(
+            Event::ReleaseAdded {
+                entity_id: 0,
+                track_ids: vec![1, 2].try_into().unwrap(),
+            }
+            .into(),
+        );
+
+        let release = Entities::<Test>::get(0).unwrap();
+        assert_eq!(release.entity_kind, IPEntityKind::Ep);
+        assert_eq!(release.item_id, Some(0));
+        let track = Entities::<Test>::get(1).unwrap();
+        assert_eq!(track.authors, Some(vec![0].try_into().unwrap()));
+        assert_eq!(track.royalty_parts, Some(royalty_parts));
+        assert_eq!((track.collection_id, track.item_id), (Some(0), Some(1)));
+        let sample = Entities::<Test>::get(2).unwrap();
+        assert_eq!(sample.authors, Some(vec![0].try_into().unwrap()));
+        assert_eq!(sample.royalty_parts.unwrap()[0].address_id, 11);
+        assert_eq!(sample.item_id, None);
+
+        assert_eq!(
+            CustomPallet::get_release_tracks(0),
+            vec![
+                ReleaseMember {
+                    entity_id: 1,
+                    track_number: 1
+                },
+                ReleaseMember {
+                    entity_id: 2,
+                    track_number: 2
+                }
+            ]
+        );
+        assert_eq!(CustomPallet::get_track_releases(2), vec![0]);
+
+        // A release is removed after its tracks
+        assert_err!(
+            CustomPallet::delete_entity(0, 0, 0),
+            Error::<Test, _>::ReleaseHasTracks
+        );
+        assert_err!(
+            CustomPallet::delete_entity(0, 1, 0),
+            Error::<Test, _>::EntityInRelease
+        );
+        assert_ok!(CustomPallet::withdraw_release_track(
+            RuntimeOrigin::signed(0),
+            0,
+            1
+        ));
+        assert_ok!(CustomPallet::delete_entity(0, 1, 0));
+        assert!(CustomPallet::get_track_releases(1).is_empty());
+        assert_ok!(CustomPallet::withdraw_release_track(
+            RuntimeOrigin::signed(0),
+            0,
+            2
+        ));
+        assert!(!ReleaseTracks::<Test>::contains_key(0));
+        assert_ok!(CustomPallet::delete_entity(0, 0, 0));
+    });
+}
+
+#[test]
+fn test_release_tracks() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        add_authority_access_for_test(0, 0, None);
+        add_authority_access_for_test(1, 1, None);
+        for (entity_id, owner, entity_kind) in [
+            (0, 0, IPEntityKind::Compilation),
+            (1, 0, IPEntityKind::Track),
+            (2, 1, IPEntityKind::Track),
+            (3, 0, IPEntityKind::Release),
+        ] {
+            add_entity_for_test(entity_id, owner, None);
+            Entities::<Test>::mutate(entity_id, |entity| {
+                entity.as_mut().unwrap().entity_kind = entity_kind
+            });
+        }
+        let member = |entity_id, track_number| ReleaseMember {
+            entity_id,
+            track_number,
+        };
+        let set_tracks = |origin, release_id, tracks: Vec<ReleaseMember<u32>>| {
+            CustomPallet::set_release_tracks(
+                RuntimeOrigin::signed(origin),
+                release_id,
+                tracks.try_into().unwrap(),
+            )
+        };
+
+        // The tracks of a release exist, appear once, are ordered by track number and are not releases
+        assert_err!(
+            set_tracks(0, 1, vec![member(2, 1)]),
+            Error::<Test, _>::ReleaseNotFound
+        );
+        assert_err!(
+            set_tracks(0, 0, vec![member(1, 0)]),
+            Error::<Test, _>::ReleaseTrackNumberInvalid
+        );
+        assert_err!(
+            set_tracks(0, 0, vec![member(1, 2), member(2, 1)]),
+            Error::<Test, _>::ReleaseTrackNumberInvalid
+        );
+        assert_err!(
+            set_tracks(0, 0, vec![member(1, 1), member(1, 2)]),
+            Error::<Test, _>::ReleaseTrackDuplicate
+        );
+        assert_err!(
+            set_tracks(0, 0, vec![member(9, 1)]),
+            Error::<Test, _>::EntityNotFound
+        );
+        for release_id in [0, 3] {
+            assert_err!(
+                set_tracks(0, 0, vec![member(release_id, 1)]),
+                Error::<Test, _>::ReleaseTrackInvalid
+            );
+        }
+
+        // A track of another authority needs its consent
+        assert_err!(
+            set_tracks(0, 0, vec![member(1, 1), member(2, 3)]),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+        assert_ok!(CustomPallet::add_access(
+            1,
+            1,
+            0,
+            AuthorityAccessSettings(AuthorityAccessSetting::EditEntity.into()),
+            Some(10)
+        ));
+        assert_ok!(set_tracks(0, 0, vec![member(1, 1), member(2, 3)]));
+        System::assert_last_event(Event::ReleaseTracksSet { entity_id: 0 }.into());
+        assert_eq!(CustomPallet::get_track_releases(2), vec![0]);
+
+        // which is not asked again to reorder the tracks
+        System::set_block_number(10);
+        assert_ok!(set_tracks(0, 0, vec![member(2, 1), member(1, 2)]));
+        let (release, tracks) = CustomPallet::get_release(0).unwrap();
+        assert_eq!(release.entity_kind, IPEntityKind::Compilation);
+        assert_eq!(
+            tracks
+                .iter()
+                .map(|(track_number, track_id, track)| (*track_number, *track_id, track.owner))
+                .collect::<Vec<_>>(),
+            vec![(1, 2, 1), (2, 1, 0)]
+        );
+        assert_err!(
+            CustomPallet::get_release(1),
+            Error::<Test, _>::ReleaseNotFound
+        );
+
+        // The owner of a track withdraws it from the release at any time
+        assert_err!(
+            CustomPallet::withdraw_release_track(RuntimeOrigin::signed(5), 0, 2),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+        assert_ok!(CustomPallet::withdraw_release_track(
+            RuntimeOrigin::signed(1),
+            0,
+            2
+        ));
+        System::assert_last_event(
+            Event::ReleaseTrackWithdrawn {
+                entity_id: 0,
+                track_id: 2,
+            }
+            .into(),
+        );
+        assert_eq!(CustomPallet::get_release_tracks(0), vec![member(1, 2)]);
+        assert!(CustomPallet::get_track_releases(2).is_empty());
+        assert_err!(
+            CustomPallet::withdraw_release_track(RuntimeOrigin::signed(1), 0, 2),
+            Error::<Test, _>::ReleaseTrackNotFound
+        );
+
+        // An empty list clears the release
+        assert_ok!(set_tracks(0, 0, vec![]));
+        assert!(!ReleaseTracks::<Test>::contains_key(0));
+        assert!(CustomPallet::get_track_releases(1).is_empty());
+    });
+}
+
+#[test]
+fn test_entity_nft_mirror() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        NftsSupport::<Test>::set(Some(true));
+        ByteDeposit::set(1);
+        Balances::make_free_balance_be(&0, 1_000);
+
+        add_authority_access_for_test(0, 0, Some(0));
+        add_authority_access_for_test(0, 1, Some(1));
+        add_authority_access_for_test(0, 2, Some(2));
+
+        // Minting mirrors the url, the ID, the kind and the owner of the entity into the NFT
+        let mut item_config = ItemConfig::default();
+        item_config.disable_setting(ItemSetting::UnlockedAttributes);
+        assert_ok!(CustomPallet::add_new_entity(
+            0,
+            IPEntityKind::Track,
+            0,
+            vec![4, 5, 6].try_into().unwrap(),
+            MetadataStandard::M25,
+            MetadataFeatures::default(),
+            None,
+            None,
+            None,
+            Some(7),
+            Some(0),
+            Some(item_config),
+        ));
+        assert_eq!(NftItemMetadata::get().get(&(0, 7)), Some(&vec![4, 5, 6]));
+        assert_eq!(
+            NftsMock::system_attribute(&0, Some(&7), NFT_ATTRIBUTE_ENTITY_ID),
+            Some(0u32.encode())
+        );
+        assert_eq!(
+            NftsMock::system_attribute(&0, Some(&7), NFT_ATTRIBUTE_ENTITY_KIND),
+            Some(IPEntityKind::Track.encode())
+        );
+        assert_eq!(
+            NftsMock::system_attribute(&0, Some(&7), NFT_ATTRIBUTE_AUTHORITY_ID),
+            Some(0u32.encode())
+        );
+        assert!(NftItemConfigs::get()[&(0, 7)].is_setting_enabled(ItemSetting::UnlockedMetadata));
+
+        // The deposit of the entity covers the mirrored bytes
+        let entity = Entities::<Test>::get(0).unwrap();
+        assert_eq!(
+            CustomPallet::entity_nft_bytes(&entity),
+            3 + 9 + 11 + 12 + 4 + 1 + 4
+        );
+        assert_eq!(Balances::total_balance_on_hold(&0), 3 + 44);
+
+        // A new url or owner is mirrored again
+        assert_ok!(CustomPallet::set_entity(
+            0,
+            0,
+            Some(vec![7, 8].try_into().unwrap()),
+            None,
+            None,
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+        assert_eq!(NftItemMetadata::get().get(&(0, 7)), Some(&vec![7, 8]));
+        assert_eq!(
+            NftsMock::system_attribute(&0, Some(&7), NFT_ATTRIBUTE_AUTHORITY_ID),
+            Some(1u32.encode())
+        );
+        assert_eq!(Balances::total_balance_on_hold(&0), 2 + 43);
+
+        // A transfer moves the mirrored data with the NFT
+        assert_ok!(CustomPallet::offer_entity_transfer(
+            RuntimeOrigin::signed(0),
+            0,
+            2,
+            0,
+            PaymentCurrency::Native
+        ));
+        assert_ok!(CustomPallet::accept_entity_transfer(
+            RuntimeOrigin::signed(0),
+            0,
+            0,
+            PaymentCurrency::Native
+        ));
+        assert_eq!(NftItemMetadata::get().get(&(0, 7)), None);
+        assert_eq!(
+            NftsMock::system_attribute(&0, Some(&7), NFT_ATTRIBUTE_ENTITY_ID),
+            None
+        );
+        assert_eq!(NftItemMetadata::get().get(&(2, 7)), Some(&vec![7, 8]));
+        assert_eq!(NftItemConfigs::get()[&(2, 7)], item_config);
+        assert_eq!(
+            NftsMock::system_attribute(&2, Some(&7), NFT_ATTRIBUTE_ENTITY_ID),
+            Some(0u32.encode())
+        );
+        assert_eq!(
+            NftsMock::system_attribute(&2, Some(&7), NFT_ATTRIBUTE_AUTHORITY_ID),
+            Some(2u32.encode())
+        );
+
+        // The item metadata of an immutable entity is locked
+        let mut features = MetadataFeatures::default();
+        features.add_feature(MetadataFeature::Immutable);
+        assert_ok!(CustomPallet::add_new_entity(
+            0,
+            IPEntityKind::Sample,
+            0,
+            vec![9].try_into().unwrap(),
+            MetadataStandard::M25,
+            features,
+            None,
+            None,
+            None,
+            Some(8),
+            Some(0),
+            None,
+        ));
+        assert_eq!(NftItemMetadata::get().get(&(0, 8)), Some(&vec![9]));
+        assert!(NftItemConfigs::get()[&(0, 8)].has_disabled_setting(ItemSetting::UnlockedMetadata));
+
+        // An entity without NFT mirrors nothing
+        assert_ok!(CustomPallet::add_new_entity(
+            0,
+            IPEntityKind::Track,
+            0,
+            vec![1].try_into().unwrap(),
+            MetadataStandard::M25,
+            MetadataFeatures::default(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+        assert_eq!(
+            CustomPallet::entity_nft_bytes(&Entities::<Test>::get(2).unwrap()),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_entity_nft_detach_and_burn() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        NftsSupport::<Test>::set(Some(true));
+
+        add_authority_access_for_test(0, 0, Some(0));
+
+        // The NFT is indexed and its holder is read from the NFTs pallet
+        assert_ok!(add_entity_with_nft_for_test(7, 2));
+        assert_eq!(CustomPallet::get_nft_entity(0, 7), Some(0));
+        assert_eq!(CustomPallet::get_entity_nft_holder(0), Ok(Some(2)));
+
+        // The holder follows transfers made directly in the NFTs pallet
+        NftItemOwners::mutate(|owners| owners.insert((0, 7), Some(3)));
+        assert_eq!(CustomPallet::get_entity_nft_holder(0), Ok(Some(3)));
+
+        // An NFT burned directly in the NFTs pallet stays linked until the link is dropped
+        NftItemOwners::mutate(|owners| owners.insert((0, 7), None));
+        assert_eq!(CustomPallet::get_entity_nft_holder(0), Ok(None));
+        assert_err!(
+            add_entity_with_nft_for_test(7, 2),
+            Error::<Test, _>::EntityNftAlreadyLinked
+        );
+
+        // Anyone can drop the link of a burned NFT
+        assert_ok!(CustomPallet::burn_entity_nft(RuntimeOrigin::signed(5), 0));
+        System::assert_last_event(
+            Event::EntityNftBurned {
+                entity_id: 0,
+                collection_id: 0,
+                item_id: 7,
+            }
+            .into(),
+        );
+        let entity = Entities::<Test>::get(0).unwrap();
+        assert_eq!((entity.collection_id, entity.item_id), (None, None));
+        assert_eq!(entity.revision, 1);
+        assert_eq!(CustomPallet::get_nft_entity(0, 7), None);
+        assert_err!(
+            CustomPallet::burn_entity_nft(RuntimeOrigin::signed(5), 0),
+            Error::<Test, _>::EntityNftNotFound
+        );
+
+        // Only the holder burns a live NFT, the mirrored data is cleared first
+        assert_ok!(add_entity_with_nft_for_test(7, 2));
+        assert_eq!(CustomPallet::get_nft_entity(0, 7), Some(1));
+        assert_err!(
+            CustomPallet::burn_entity_nft(RuntimeOrigin::signed(0), 1),
+            Error::<Test, _>::EntityNftHolderInvalid
+        );
+        assert_ok!(CustomPallet::burn_entity_nft(RuntimeOrigin::signed(2), 1));
+        assert_eq!(NftsMock::owner(&0, &7), None);
+        assert_eq!(NftItemMetadata::get().get(&(0, 7)), None);
+        assert_eq!(
+            NftsMock::system_attribute(&0, Some(&7), NFT_ATTRIBUTE_ENTITY_ID),
+            None
+        );
+        assert_eq!(CustomPallet::get_nft_entity(0, 7), None);
+
+        // Detaching keeps the NFT with its holder
+        assert_ok!(add_entity_with_nft_for_test(8, 2));
+        assert_err!(
+            CustomPallet::detach_entity_nft(RuntimeOrigin::signed(3), 2),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+        assert_ok!(CustomPallet::detach_entity_nft(RuntimeOrigin::signed(0), 2));
+        System::assert_last_event(
+            Event::EntityNftDetached {
+                entity_id: 2,
+                collection_id: 0,
+                item_id: 8,
+            }
+            .into(),
+        );
+        assert_eq!(NftsMock::owner(&0, &8), Some(2));
+        assert_eq!(NftItemMetadata::get().get(&(0, 8)), None);
+        assert_eq!(CustomPallet::get_nft_entity(0, 8), None);
+        assert_eq!(CustomPallet::get_entity_nft_holder(2), Ok(None));
+        assert_err!(
+            CustomPallet::detach_entity_nft(RuntimeOrigin::signed(0), 2),
+            Error::<Test, _>::EntityNftNotFound
+        );
+
+        // A detached entity can mint a new NFT
+        assert_ok!(CustomPallet::set_entity(
+            0,
+            2,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(9),
+            Some(2),
+            None,
+        ));
+        assert_eq!(CustomPallet::get_nft_entity(0, 9), Some(2));
+        assert_eq!(NftItemMetadata::get().get(&(0, 9)), Some(&vec![4, 5, 6]));
+
+        // Removing the entity keeps its NFT, the mirrored data and the link are dropped
+        assert_ok!(CustomPallet::delete_entity(0, 2, 0));
+        assert_eq!(NftsMock::owner(&0, &9), Some(2));
+        assert_eq!(NftItemMetadata::get().get(&(0, 9)), None);
+        assert_eq!(CustomPallet::get_nft_entity(0, 9), None);
+    });
+}
+
+#[test]
+fn test_entity_nft_resale_royalties() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        NftsSupport::<Test>::set(Some(true));
+        Balances::make_free_balance_be(&3, 2_000);
+        Balances::make_free_balance_be(&4, 100);
+
+        add_authority_access_for_test(0, 0, Some(0));
+        assert_ok!(add_entity_with_nft_for_test(7, 2));
+        Entities::<Test>::mutate(0, |entity| {
+            entity.as_mut().unwrap().royalty_parts = Some(
+                vec![
+                    Wallet {
+                        address_id: 10,
+                        share: Perbill::from_percent(75),
+                    },
+                    Wallet {
+                        address_id: 11,
+                        share: Perbill::from_percent(25),
+                    },
+                ]
+                .try_into()
+                .unwrap(),
+            );
+        });
+
+        // Entity NFTs can not be traded in the NFTs pallet directly
+        assert!(CustomPallet::is_entity_nft_locked(&0, &7));
+        assert_err!(NftsMock::transfer(&0, &7, &3), TokenError::Frozen);
+
+        // The royalty of the entity overrides the royalty of its authority
+        assert_ok!(CustomPallet::set_resale_royalty(
+            RuntimeOrigin::signed(0),
+            0,
+            None,
+            Some(Perbill::from_percent(10))
+        ));
+        assert_eq!(
+            CustomPallet::get_resale_royalty(0),
+            Ok(Perbill::from_percent(10))
+        );
+        assert_ok!(CustomPallet::set_resale_royalty(
+            RuntimeOrigin::signed(0),
+            0,
+            Some(0),
+            Some(Perbill::from_percent(20))
+        ));
+        System::assert_last_event(
+            Event::ResaleRoyaltySet {
+                authority_id: 0,
+                entity_id: Some(0),
+                royalty: Some(Perbill::from_percent(20)),
+            }
+            .into(),
+        );
+        assert_eq!(
+            CustomPallet::get_resale_royalty(0),
+            Ok(Perbill::from_percent(20))
+        );
+        assert_err!(
+            CustomPallet::set_resale_royalty(
+                RuntimeOrigin::signed(0),
+                0,
+                Some(0),
+                Some(Perbill::from_percent(60))
+            ),
+            Error::<Test, _>::ResaleRoyaltyTooHigh
+        );
+        assert_err!(
+            CustomPallet::set_resale_royalty(RuntimeOrigin::signed(5), 0, None, None),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+
+        // Only the holder lists the NFT
+        assert_err!(
+            CustomPallet::list_entity_nft(
+                RuntimeOrigin::signed(3),
+                0,
+                1_000,
+                PaymentCurrency::Native,
+                None
+            ),
+            Error::<Test, _>::EntityNftHolderInvalid
+        );
+        assert_ok!(CustomPallet::list_entity_nft(
+            RuntimeOrigin::signed(2),
+            0,
+            1_000,
+            PaymentCurrency::Native,
+            None
+        ));
+        assert_err!(
+            CustomPallet::buy_entity_nft(RuntimeOrigin::signed(3), 0, 999, PaymentCurrency::Native),
+            Error::<Test, _>::EntityNftPriceTooHigh
+        );
+        assert_err!(
+            CustomPallet::buy_entity_nft(
+                RuntimeOrigin::signed(3),
+                0,
+                1_000,
+                PaymentCurrency::Asset(NativeOrWithId::WithId(5))
+            ),
+            Error::<Test, _>::PaymentCurrencyMismatch
+        );
+
+        // The resale royalty is paid through the royalty split, the seller receives the rest
+        assert_ok!(CustomPallet::buy_entity_nft(
+            RuntimeOrigin::signed(3),
+            0,
+            1_000,
+            PaymentCurrency::Native
+        ));
+        System::assert_last_event(
+            Event::EntityNftSold {
+                entity_id: 0,
+                seller: 2,
+                buyer: 3,
+                price: 1_000,
+                royalty: 200,
+                currency: PaymentCurrency::Native,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(3), 1_000);
+        assert_eq!(Balances::free_balance(10), 150);
+        assert_eq!(Balances::free_balance(11), 50);
+        assert_eq!(Balances::free_balance(2), 800);
+        assert_eq!(CustomPallet::get_entity_nft_holder(0), Ok(Some(3)));
+        assert!(CustomPallet::is_entity_nft_locked(&0, &7));
+        assert_eq!(CustomPallet::get_nft_listing(0), None);
+
+        // A listing can be reserved for a single buyer, free listings would bypass the royalty
+        assert_err!(
+            CustomPallet::list_entity_nft(
+                RuntimeOrigin::signed(3),
+                0,
+                0,
+                PaymentCurrency::Native,
+                Some(4)
+            ),
+            Error::<Test, _>::ZeroAmount
+        );
+        assert_ok!(CustomPallet::list_entity_nft(
+            RuntimeOrigin::signed(3),
+            0,
+            10,
+            PaymentCurrency::Native,
+            Some(4)
+        ));
+        assert_err!(
+            CustomPallet::buy_entity_nft(RuntimeOrigin::signed(5), 0, 10, PaymentCurrency::Native),
+            Error::<Test, _>::NotAuthorized
+        );
+        assert_ok!(CustomPallet::buy_entity_nft(
+            RuntimeOrigin::signed(4),
+            0,
+            10,
+            PaymentCurrency::Native
+        ));
+        assert_eq!(CustomPallet::get_entity_nft_holder(0), Ok(Some(4)));
+
+        // Only the seller delists the NFT while it holds it
+        assert_ok!(CustomPallet::list_entity_nft(
+            RuntimeOrigin::signed(4),
+            0,
+            100,
+            PaymentCurrency::Native,
+            None
+        ));
+        assert_err!(
+            CustomPallet::delist_entity_nft(RuntimeOrigin::signed(3), 0),
+            Error::<Test, _>::NotAuthorized
+        );
+        assert_ok!(CustomPallet::delist_entity_nft(RuntimeOrigin::signed(4), 0));
+        assert_err!(
+            CustomPallet::buy_entity_nft(RuntimeOrigin::signed(3), 0, 100, PaymentCurrency::Native),
+            Error::<Test, _>::EntityNftListingNotFound
+        );
+
+        // The holder gives the NFT back to the owning authority without a royalty, it stays locked
+        // and its listing is dropped. A gift to any other account could hide a sale.
+        assert_ok!(CustomPallet::list_entity_nft(
+            RuntimeOrigin::signed(4),
+            0,
+            100,
+            PaymentCurrency::Native,
+            None
+        ));
+        assert_err!(
+            CustomPallet::gift_entity_nft(RuntimeOrigin::signed(3), 0, 0),
+            Error::<Test, _>::EntityNftHolderInvalid
+        );
+        assert_err!(
+            CustomPallet::gift_entity_nft(RuntimeOrigin::signed(4), 0, 5),
+            Error::<Test, _>::EntityNftRecipientInvalid
+        );
+        let royalty_balance = Balances::free_balance(10);
+        assert_ok!(CustomPallet::gift_entity_nft(
+            RuntimeOrigin::signed(4),
+            0,
+            0
+        ));
+        System::assert_last_event(
+            Event::EntityNftGifted {
+                entity_id: 0,
+                from: 4,
+                to: 0,
+            }
+            .into(),
+        );
+        assert_eq!(CustomPallet::get_entity_nft_holder(0), Ok(Some(0)));
+        assert_eq!(Balances::free_balance(10), royalty_balance);
+        assert_eq!(CustomPallet::get_nft_listing(0), None);
+        assert!(CustomPallet::is_entity_nft_locked(&0, &7));
+
+        // A detached NFT is unlocked and its listing is dropped
+        assert_ok!(CustomPallet::list_entity_nft(
+            RuntimeOrigin::signed(0),
+            0,
+            100,
+            PaymentCurrency::Native,
+            None
+        ));
+        assert_ok!(CustomPallet::detach_entity_nft(RuntimeOrigin::signed(0), 0));
+        assert_eq!(CustomPallet::get_nft_listing(0), None);
+        assert!(!CustomPallet::is_entity_nft_locked(&0, &7));
+        assert_ok!(NftsMock::transfer(&0, &7, &3));
+    });
+}
+
+#[test]
+fn test_royalty_shares() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let payer: u64 = 9;
+        Balances::make_free_balance_be(&payer, 2_000);
+        for account_id in [1, 2, 3, 4] {
+            Balances::make_free_balance_be(&account_id, 10);
+        }
+        RoyaltySharesDeposit::set(5);
+
+        add_authority_access_for_test(1, 0, None);
+        add_entity_for_test(
+            0,
+            0,
+            Some(
+                vec![Wallet {
+                    address_id: 10,
+                    share: Perbill::from_percent(100),
+                }]
+                .try_into()
+                .unwrap(),
+            ),
+        );
+
+        assert_err!(
+            CustomPallet::issue_royalty_shares(RuntimeOrigin::signed(1), 0, 0, 100),
+            Error::<Test, _>::ZeroAmount
+        );
+        assert_err!(
+            CustomPallet::issue_royalty_shares(RuntimeOrigin::signed(1), 0, 300, 0),
+            Error::<Test, _>::RoyaltySharesMinBalanceTooLow
+        );
+        // The holders of the minimum balance could exceed `MaxRoyaltyShareHolders`
+        assert_err!(
+            CustomPallet::issue_royalty_shares(RuntimeOrigin::signed(1), 0, 301, 100),
+            Error::<Test, _>::RoyaltySharesMinBalanceTooLow
+        );
+        assert_err!(
+            CustomPallet::issue_royalty_shares(RuntimeOrigin::signed(2), 0, 300, 100),
+            Error::<Test, _>::AuthoritiesAccessNotFound
+        );
+
+        // The asset takes the first ID of the reserved range and the issuer posts the deposit
+        assert_ok!(CustomPallet::issue_royalty_shares(
+            RuntimeOrigin::signed(1),
+            0,
+            300,
+            100
+        ));
+        System::assert_last_event(
+            Event::<Test>::RoyaltySharesIssued {
+                entity_id: 0,
+                asset_id: 7,
+                supply: 300,
+            }
+            .into(),
+        );
+        assert_eq!(<Assets as fungibles::Inspect<u64>>::balance(7, &1), 300);
+        assert_eq!(Balances::total_balance_on_hold(&1), 5);
+        assert_eq!(NextRoyaltySharesAssetId::<Test>::get(), Some(8));
+        assert_err!(
+            CustomPallet::issue_royalty_shares(RuntimeOrigin::signed(1), 0, 300, 100),
+            Error::<Test, _>::RoyaltySharesAlreadyIssued
+        );
+
+        // The next asset ID of the range is taken
+        add_entity_for_test(1, 0, None);
+        assert_ok!(<Assets as fungibles::Create<u64>>::create(8, 5, true, 1));
+        assert_err!(
+            CustomPallet::issue_royalty_shares(RuntimeOrigin::signed(1), 1, 300, 100),
+            Error::<Test, _>::RoyaltySharesAssetAlreadyExists
+        );
+        assert_eq!(Balances::total_balance_on_hold(&1), 5);
+
+        for holder in [2, 3] {
+            assert_ok!(<Assets as fungibles::Mutate<u64>>::transfer(
+                7,
+                &1,
+                &holder,
+                100,
+                Preservation::Expendable
+            ));
+        }
+
+        assert_err!(
+            CustomPallet::register_royalty_share_holder(RuntimeOrigin::signed(1), 0),
+            Error::<Test, _>::RoyaltyShareHolderAlreadyRegistered
+        );
+        assert_err!(
+            CustomPallet::register_royalty_share_holder(RuntimeOrigin::signed(4), 0),
+            Error::<Test, _>::RoyaltyShareBalanceTooLow
+        );
+        assert_err!(
+            CustomPallet::register_royalty_share_holder(RuntimeOrigin::signed(2), 1),
+            Error::<Test, _>::RoyaltySharesNotFound
+        );
+        assert_ok!(CustomPallet::register_royalty_share_holder(
+            RuntimeOrigin::signed(2),
+            0
+        ));
+        System::assert_last_event(
+            Event::<Test>::RoyaltyShareHolderRegistered {
+                entity_id: 0,
+                account_id: 2,
+            }
+            .into(),
+        );
+        assert_err!(
+            CustomPallet::register_royalty_share_holder(RuntimeOrigin::signed(2), 0),
+            Error::<Test, _>::RoyaltyShareHolderAlreadyRegistered
+        );
+
+        // Royalties go to the registered holders instead of the royalty split, pro-rata to the balances
+        // of the registered holders, the unregistered holder receives nothing
+        assert_ok!(CustomPallet::pay_royalties(
+            RuntimeOrigin::signed(payer),
+            0,
+            1_000,
+            PaymentCurrency::Native
+        ));
+        assert_eq!(Balances::free_balance(10), 0);
+        assert_eq!(Balances::free_balance(1), 505);
+        assert_eq!(Balances::free_balance(2), 510);
+        assert_eq!(Balances::free_balance(3), 10);
+        assert_eq!(Balances::free_balance(CustomPallet::pallet_account()), 0);
+
+        // A holder who was paid and sold its shares to a new account is dropped once another holder registers
+        assert_ok!(<Assets as fungibles::Mutate<u64>>::transfer(
+            7,
+            &2,
+            &4,
+            100,
+            Preservation::Expendable
+        ));
+        assert_ok!(CustomPallet::register_royalty_share_holder(
+            RuntimeOrigin::signed(3),
+            0
+        ));
+        assert_eq!(
+            CustomPallet::get_royalty_shares(0)
+                .unwrap()
+                .holders
+                .into_inner(),
+            vec![3]
+        );
+
+        // The new account registers the shares again without being paid the royalties paid before
+        assert_ok!(CustomPallet::register_royalty_share_holder(
+            RuntimeOrigin::signed(4),
+            0
+        ));
+        System::assert_last_event(
+            Event::<Test>::RoyaltyShareHolderRegistered {
+                entity_id: 0,
+                account_id: 4,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(3), 10);
+        assert_eq!(Balances::free_balance(4), 10);
+
+        // Every share is paid once per payment
+        assert_ok!(CustomPallet::pay_royalties(
+            RuntimeOrigin::signed(payer),
+            0,
+            900,
+            PaymentCurrency::Native
+        ));
+        assert_eq!(Balances::free_balance(1), 805);
+        assert_eq!(Balances::free_balance(2), 510);
+        assert_eq!(Balances::free_balance(3), 310);
+        assert_eq!(Balances::free_balance(4), 310);
+        assert_eq!(Balances::free_balance(CustomPallet::pallet_account()), 0);
+
+        // The asset of the shares is administered by the pallet, the entity can not be removed
+        assert_err!(
+            CustomPallet::remove_entity(RuntimeOrigin::signed(1), 0, 0),
+            Error::<Test, _>::EntityHasRoyaltyShares
+        );
+        assert!(CustomPallet::get_royalty_shares(0).is_ok());
+        assert_eq!(<Assets as fungibles::Inspect<u64>>::balance(7, &3), 100);
+    });
+}
+
+#[test]
+fn test_asset_payments() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let seller: u64 = 0;
+        let buyer: u64 = 1;
+        let asset = PaymentCurrency::Asset(NativeOrWithId::WithId(5));
+        Balances::make_free_balance_be(&buyer, 1_000);
+        assert_ok!(<Assets as fungibles::Create<u64>>::create(
+            5, seller, true, 1
+        ));
+        assert_ok!(<Assets as fungibles::Mutate<u64>>::mint_into(
+            5, &buyer, 2_000
+        ));
+
+        add_authority_access_for_test(seller, 0, None);
+        add_authority_access_for_test(buyer, 1, None);
+        add_entity_for_test(
+            0,
+            0,
+            Some(
+                vec![
+                    Wallet {
+                        address_id: 10,
+                        share: Perbill::from_percent(75),
+                    },
+                    Wallet {
+                        address_id: 11,
+                        share: Perbill::from_percent(25),
+                    },
+                ]
+                .try_into()
+                .unwrap(),
+            ),
+        );
+
+        // Royalties are paid in the asset and accounted per currency
+        assert_ok!(CustomPallet::pay_royalties(
+            RuntimeOrigin::signed(buyer),
+            0,
+            400,
+            asset.clone()
+        ));
+        System::assert_last_event(
+            Event::<Test>::RoyaltyPaid {
+                entity_id: 0,
+                payer: buyer,
+                recipient: 11,
+                amount: 100,
+                currency: asset.clone(),
+            }
+            .into(),
+        );
+        assert_eq!(<Assets as fungibles::Inspect<u64>>::balance(5, &10), 300);
+        assert_eq!(<Assets as fungibles::Inspect<u64>>::balance(5, &11), 100);
+        assert_eq!(Balances::free_balance(buyer), 1_000);
+        assert_eq!(CustomPallet::get_royalties_received(0, asset.clone()), 400);
+        assert_eq!(
+            CustomPallet::get_royalties_received(0, PaymentCurrency::Native),
+            0
+        );
+
+        assert!(CustomPallet::pay_royalties(
+            RuntimeOrigin::signed(buyer),
+            0,
+            400,
+            PaymentCurrency::Asset(NativeOrWithId::WithId(6))
+        )
+        .is_err());
+
+        // The native currency is only accepted as `PaymentCurrency::Native`
+        assert_err!(
+            CustomPallet::pay_royalties(
+                RuntimeOrigin::signed(buyer),
+                0,
+                400,
+                PaymentCurrency::Asset(NativeOrWithId::Native)
+            ),
+            Error::<Test, _>::PaymentCurrencyNotSupported
+        );
+        assert_err!(
+            CustomPallet::add_license_offer(
+                RuntimeOrigin::signed(seller),
+                0,
+                LicenseOffer {
+                    currency: PaymentCurrency::Asset(NativeOrWithId::Native),
+                    ..license_offer_for_test(LicenseKind::Sync, 100, None)
+                }
+            ),
+            Error::<Test, _>::PaymentCurrencyNotSupported
+        );
+
+        // A license priced in the asset
+        assert_ok!(CustomPallet::add_license_offer(
+            RuntimeOrigin::signed(seller),
+            0,
+            LicenseOffer {
+                currency: asset.clone(),
+                ..license_offer_for_test(LicenseKind::Sync, 100, None)
+            }
+        ));
+        assert_err!(
+            CustomPallet::purchase_license(
+                RuntimeOrigin::signed(buyer),
+                0,
+                0,
+                100,
+                PaymentCurrency::Native
+            ),
+            Error::<Test, _>::PaymentCurrencyMismatch
+        );
+        assert_ok!(CustomPallet::purchase_license(
+            RuntimeOrigin::signed(buyer),
+            0,
+            0,
+            100,
+            asset.clone()
+        ));
+        System::assert_last_event(
+            Event::<Test>::LicensePurchased {
+                entity_id: 0,
+                buyer,
+                kind: LicenseKind::Sync,
+                price: 100,
+                currency: asset.clone(),
+            }
+            .into(),
+        );
+        assert_eq!(<Assets as fungibles::Inspect<u64>>::balance(5, &10), 375);
+        assert_eq!(CustomPallet::get_royalties_received(0, asset.clone()), 500);
+        assert_eq!(Licenses::<Test>::get(buyer, 0)[0].currency, asset.clone());
+
+        // An entity transfer paid in the asset
+        assert_ok!(CustomPallet::offer_transfer(
+            seller,
+            0,
+            1,
+            200,
+            asset.clone()
+        ));
+        assert_err!(
+            CustomPallet::accept_transfer(buyer, 0, 200, PaymentCurrency::Native),
+            Error::<Test, _>::PaymentCurrencyMismatch
+        );
+        assert_ok!(CustomPallet::accept_transfer(buyer, 0, 200, asset.clone()));
+        System::assert_last_event(
+            Event::EntityTransferred {
+                entity_id: 0,
+                from_authority: 0,
+                to_authority: 1,
+                price: 200,
+                currency: asset.clone(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            <Assets as fungibles::Inspect<u64>>::balance(5, &buyer),
+            1_300
+        );
+        assert_eq!(
+            <Assets as fungibles::Inspect<u64>>::balance(5, &seller),
+            200
+        );
+        assert_eq!(Balances::free_balance(buyer), 1_000);
+
+        // The received royalties are cleared with the entity
+        assert_err!(
+            CustomPallet::delete_entity(buyer, 0, 0),
+            Error::<Test, _>::BadWitness
+        );
+        assert_ok!(CustomPallet::delete_entity(buyer, 0, 1));
+        assert_eq!(CustomPallet::get_royalties_received(0, asset), 0);
+    });
+}
+
+#[test]
+fn test_storage_deposits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        AuthorityDeposit::set(10);
+        AuthorDeposit::set(5);
+        EntityDeposit::set(7);
+        RevisionDeposit::set(1);
+        AccessDeposit::set(2);
+        ByteDeposit::set(1);
+        Balances::make_free_balance_be(&1, 1_000);
+        Balances::make_free_balance_be(&2, 1_000);
+
+        // The authority holds the base deposit, one deposit per byte of its name and the first access row
+        assert_ok!(CustomPallet::add_new_authority(
+            1,
+            vec![1, 2, 3].try_into().unwrap(),
+            AuthorityKind::Label,
+            None
+        ));
+        assert_eq!(Balances::total_balance_on_hold(&1), 15);
+
+        assert_ok!(CustomPallet::add_access(
+            1,
+            0,
+            2,
+            AuthorityAccessSettings::all(),
+            None
+        ));
+        assert_eq!(Balances::total_balance_on_hold(&1), 17);
+
+        assert_ok!(CustomPallet::add_new_author(
+            1,
+            vec![1].try_into().unwrap(),
+            Some(vec![1, 2].try_into().unwrap()),
+            None
+        ));
+        assert_eq!(Balances::total_balance_on_hold(&1), 25);
+
+        assert_ok!(CustomPallet::add_new_entity(
+            1,
+            IPEntityKind::Track,
+            0,
+            vec![4, 5, 6].try_into().unwrap(),
+            MetadataStandard::M25,
+            MetadataFeatures::default(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+        // The entity also holds one deposit per revision kept in its history
+        assert_eq!(Balances::total_balance_on_hold(&1), 38);
+
+        // A new url moves the deposit of the entity to the editor
+        assert_ok!(CustomPallet::set_entity(
+            2,
+            0,
+            Some(vec![4, 5, 6, 7].try_into().unwrap()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+        assert_eq!(Balances::total_balance_on_hold(&1), 25);
+        assert_eq!(Balances::total_balance_on_hold(&2), 14);
+
+        // Removals release the deposits to their depositors
+        assert_ok!(CustomPallet::remove_access(1, 0, 2));
+        assert_eq!(Balances::total_balance_on_hold(&1), 23);
+
+        assert_ok!(CustomPallet::delete_entity(1, 0, 0));
+        assert_eq!(Balances::total_balance_on_hold(&2), 0);
+
+        assert_ok!(CustomPallet::delete_author(1, 0));
+        assert_eq!(Balances::total_balance_on_hold(&1), 15);
+
+        assert_ok!(CustomPallet::delete_authority(1, 0, 1));
+        assert_eq!(Balances::total_balance_on_hold(&1), 0);
+        assert_eq!(Balances::free_balance(&1), 1_000);
+
+        // An account that can not afford the deposit can not create records
+        assert_err!(
+            CustomPallet::create_author(
+                RuntimeOrigin::signed(3),
+                vec![1].try_into().unwrap(),
+                None,
+                None
+            ),
+            TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn test_permissionless_mode() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Whitelist::set(Some(vec![1]));
+
+        assert_err!(
+            CustomPallet::create_author(
+                RuntimeOrigin::signed(2),
+                vec![1].try_into().unwrap(),
+                None,
+                None
+            ),
+            Error::<Test, _>::NotWhitelisted
+        );
+
+        assert_err!(
+            CustomPallet::set_permissionless_mode(RuntimeOrigin::signed(1), true),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(CustomPallet::set_permissionless_mode(
+            RuntimeOrigin::root(),
+            true
+        ));
+        assert!(PermissionlessMode::<Test>::get());
+
+        assert_ok!(CustomPallet::create_author(
+            RuntimeOrigin::signed(2),
+            vec![1].try_into().unwrap(),
+            None,
+            None
+        ));
+
+        assert_ok!(CustomPallet::set_permissionless_mode(
+            RuntimeOrigin::root(),
+            false
+        ));
+        assert_err!(
+            CustomPallet::create_author(
+                RuntimeOrigin::signed(2),
+                vec![1].try_into().unwrap(),
+                None,
+                None
+            ),
+            Error::<Test, _>::NotWhitelisted
+        );
+    });
+}
+
+#[test]
+fn test_migrate_v1_to_v2() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(7);
+        StorageVersion::new(1).put::<CustomPallet>();
+
+        let old_entity = |entity_kind: IPEntityKind,
+                          royalty_parts: Option<Vec<migrations::v2::v1::Wallet<u64>>>,
+                          related_to: Option<Vec<u32>>| {
+            migrations::v2::v1::EntityDetailsFor::<Test> {
+                entity_kind,
+                owner: 0,
+                authors: None,
+                royalty_parts: royalty_parts.map(|parts| parts.try_into().unwrap()),
+                related_to: related_to.map(|related_to| related_to.try_into().unwrap()),
+                metadata: Metadata {
+                    url: vec![4, 5, 6].try_into().unwrap(),
+                    standard: MetadataStandard::M25,
+                    features: Default::default(),
+                },
+                collection_id: None,
+                item_id: None,
+            }
+        };
+        let old_wallet =
+            |address_id: u64, weight: u32| migrations::v2::v1::Wallet { address_id, weight };
+
+        migrations::v2::v1::Entities::<Test, ()>::insert(
+            0,
+            old_entity(
+                IPEntityKind::Sample,
+                Some(vec![
+                    old_wallet(10, 3),
+                    old_wallet(11, 1),
+                    old_wallet(12, 0),
+                    old_wallet(10, 1),
+                ]),
+                None,
+            ),
+        );
+        migrations::v2::v1::Entities::<Test, ()>::insert(
+            1,
+            old_entity(
+                IPEntityKind::Track,
+                Some(vec![old_wallet(10, 1), old_wallet(11, 2)]),
+                Some(vec![0, 1, 0]),
+            ),
+        );
+        migrations::v2::v1::Entities::<Test, ()>::insert(
+            2,
+            old_entity(
+                IPEntityKind::GenerativeTrack,
+                Some(vec![old_wallet(10, 0)]),
+                Some(vec![0, 1]),
+            ),
+        );
+        migrations::v2::v1::Entities::<Test, ()>::insert(
+            3,
+            old_entity(IPEntityKind::Track, None, None),
+        );
+
+        assert_eq!(migrate_v1_to_v2_for_test(Weight::MAX), 1);
+
+        assert_eq!(StorageVersion::get::<CustomPallet>(), 2);
+
+        // Duplicates are merged and zero weights are dropped
+        assert_eq!(
+            Entities::<Test>::get(0).unwrap().royalty_parts.unwrap(),
+            vec![
+                Wallet {
+                    address_id: 10,
+                    share: Perbill::from_percent(80),
+                },
+                Wallet {
+                    address_id: 11,
+                    share: Perbill::from_percent(20),
+                },
+            ]
+        );
+
+        // The rounding remainder goes to the first wallet
+        assert_eq!(
+            Entities::<Test>::get(1).unwrap().royalty_parts.unwrap(),
+            vec![
+                Wallet {
+                    address_id: 10,
+                    share: Perbill::from_parts(333_333_334),
+                },
+                Wallet {
+                    address_id: 11,
+                    share: Perbill::from_parts(666_666_666),
+                },
+            ]
+        );
+
+        assert_eq!(Entities::<Test>::get(2).unwrap().royalty_parts, None);
+        assert_eq!(Entities::<Test>::get(3).unwrap().royalty_parts, None);
+
+        // Self-references and duplicates are dropped
+        assert_eq!(Entities::<Test>::get(0).unwrap().related_to, None);
+        assert_eq!(
+            Entities::<Test>::get(1).unwrap().related_to.unwrap(),
+            vec![relation_for_test(RelationKind::RemixOf, 0)]
+        );
+        assert_eq!(
+            Entities::<Test>::get(2).unwrap().related_to.unwrap(),
+            vec![
+                relation_for_test(RelationKind::GeneratedFrom, 0),
+                relation_for_test(RelationKind::GeneratedFrom, 1),
+            ]
+        );
+
+        assert_eq!(Derivatives::<Test>::get(0, 1), Some(RelationKind::RemixOf));
+        assert_eq!(
+            Derivatives::<Test>::get(0, 2),
+            Some(RelationKind::GeneratedFrom)
+        );
+        assert_eq!(
+            CustomPallet::get_derivatives(1),
+            vec![(2, RelationKind::GeneratedFrom)]
+        );
+
+        let entity = Entities::<Test>::get(3).unwrap();
+        assert_eq!(entity.content, None);
+        assert_eq!(entity.identifiers, EntityIdentifiers::default());
+        assert_eq!((entity.created_at, entity.updated_at), (0, 7));
+        assert_eq!(entity.revision, 0);
+        assert_eq!(CustomPallet::get_entity_revision(3, 0), None);
+    });
+}
+
+#[test]
+fn test_migrate_v1_to_v2_indexes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(7);
+        StorageVersion::new(1).put::<CustomPallet>();
+
+        migrations::v2::v1::Authorities::<Test, ()>::insert(
+            0,
+            migrations::v2::v1::AuthorityDetails {
+                authority_kind: AuthorityKind::Label,
+                name: vec![1].try_into().unwrap(),
+                collection_id: None,
+            },
+        );
+        AuthoritiesAccess::<Test>::insert(0, 1, AuthorityAccessSettings::all());
+        AuthoritiesAccess::<Test>::insert(0, 2, AuthorityAccessSettings::all());
+        AuthoritiesAccess::<Test>::insert(0, 3, AuthorityAccessSettings::none());
+        AuthoritiesAccess::<Test>::insert(1, 1, AuthorityAccessSettings::all());
+
+        migrations::v2::v1::Authors::<Test, ()>::insert(
+            0,
+            migrations::v2::v1::AuthorDetails {
+                nickname: vec![1].try_into().unwrap(),
+                real_name: Some(vec![2].try_into().unwrap()),
+                owner: 1,
+            },
+        );
+
+        let old_entity = |collection_id: Option<u32>, item_id: Option<u32>| {
+            migrations::v2::v1::EntityDetailsFor::<Test> {
+                entity_kind: IPEntityKind::Track,
+                owner: 0,
+                authors: Some(vec![0].try_into().unwrap()),
+                royalty_parts: None,
+                related_to: None,
+                metadata: Metadata {
+                    url: vec![4, 5, 6].try_into().unwrap(),
+                    standard: MetadataStandard::M25,
+                    features: Default::default(),
+                },
+                collection_id,
+                item_id,
+            }
+        };
+        migrations::v2::v1::Entities::<Test, ()>::insert(0, old_entity(Some(2), Some(3)));
+        migrations::v2::v1::Entities::<Test, ()>::insert(1, old_entity(Some(2), Some(4)));
+        migrations::v2::v1::Entities::<Test, ()>::insert(2, old_entity(None, None));
+        NftItemOwners::mutate(|owners| owners.insert((2, 4), None));
+
+        assert_eq!(CustomPallet::get_entities_of_authority(0), vec![]);
+
+        // Every step migrates as many records as its weight allows, the storage version is set at the end
+        let entity_weight = <() as WeightInfo>::migrate_v2_entity(
+            MaxEntityAuthors::get(),
+            MaxRelatedEntities::get(),
+        );
+        assert_eq!(
+            migrations::v2::MigrateV1ToV2::<Test>::step(
+                None,
+                &mut WeightMeter::with_limit(Weight::zero())
+            ),
+            Err(SteppedMigrationError::InsufficientWeight {
+                required: <() as WeightInfo>::migrate_v2_authority()
+            })
+        );
+        assert!(migrate_v1_to_v2_for_test(entity_weight) > 1);
+
+        assert_eq!(StorageVersion::get::<CustomPallet>(), 2);
+        assert_eq!(
+            migrations::v2::MigrateV1ToV2::<Test>::step(None, &mut WeightMeter::new()),
+            Ok(None)
+        );
+
+        let authority = Authorities::<Test>::get(0).unwrap();
+        assert_eq!(authority.name.to_vec(), vec![1]);
+        assert_eq!((authority.created_at, authority.updated_at), (0, 7));
+
+        // Nothing proves who registered the existing authors, their owners claim them again
+        let author = Authors::<Test>::get(0).unwrap();
+        assert_eq!(author.status, AuthorStatus::Unclaimed);
+        assert_eq!(author.ipi, None);
+        assert_eq!((author.created_at, author.updated_at), (0, 7));
+        assert_err!(
+            CustomPallet::set_author(1, 0, None, None),
+            Error::<Test, _>::AuthorNotClaimed
+        );
+        assert_ok!(CustomPallet::claim_author_owner(1, 0));
+
+        let mut entities = CustomPallet::get_entities_of_authority(0);
+        entities.sort();
+        assert_eq!(entities, vec![0, 1, 2]);
+        let mut entities = CustomPallet::get_entities_of_author(0);
+        entities.sort();
+        assert_eq!(entities, vec![0, 1, 2]);
+        assert_eq!(CustomPallet::get_account_overview(1).authors, vec![0]);
+        assert_eq!(
+            CustomPallet::get_account_overview(2),
+            AccountOverview {
+                authors: vec![],
+                authorities: vec![(0, AuthorityAccessSettings::all())],
+            }
+        );
+        assert_eq!(
+            CustomPallet::get_account_overview(3).authorities,
+            vec![(0, AuthorityAccessSettings::none())]
+        );
+
+        assert_eq!(EditAccessHolders::<Test>::get(0), 2);
+        assert_eq!(EditAccessHolders::<Test>::get(1), 1);
+        assert_eq!(EditAccessHolders::<Test>::get(2), 0);
+
+        assert_eq!(CustomPallet::get_nft_entity(2, 3), Some(0));
+        assert_eq!(CustomPallet::get_nft_entity(2, 4), Some(1));
+        assert_eq!(EntitiesByNft::<Test>::iter().count(), 2);
+
+        // The existing NFTs are locked, the burned one is skipped
+        assert!(CustomPallet::is_entity_nft_locked(&2, &3));
+        assert!(!CustomPallet::is_entity_nft_locked(&2, &4));
+    });
+}
+
+/// Runs the migration to storage version 2 to the end with the given weight per step and returns the number of steps.
+fn migrate_v1_to_v2_for_test(limit: Weight) -> u32 {
+    let mut cursor = None;
+    let mut steps = 0;
+    loop {
+        steps += 1;
+        cursor = migrations::v2::MigrateV1ToV2::<Test>::step(
+            cursor,
+            &mut WeightMeter::with_limit(limit),
+        )
+        .unwrap();
+        if cursor.is_none() {
+            return steps;
+        }
+        assert_eq!(StorageVersion::get::<CustomPallet>(), 1);
+    }
+}
+
+fn add_authority_access_for_test(
+    account_id: <Test as frame_system::Config>::AccountId,
+    authority_id: <Test as Config>::AuthorityId,
+    collection_id: Option<<Test as Config>::CollectionId>,
+) {
+    Authorities::<Test>::insert(
+        authority_id,
+        AuthorityDetails {
+            authority_kind: AuthorityKind::Label,
+            name: vec![1, 2, 3].try_into().unwrap(),
+            collection_id,
+            created_at: 0,
+            updated_at: 0,
+        },
+    );
+
+    CustomPallet::write_access(
+        authority_id,
+        &account_id,
+        Some(AuthorityAccessSettings::all()),
+    )
+    .unwrap();
+}
+
+fn add_author_for_test(
+    owner: <Test as frame_system::Config>::AccountId,
+    author_id: <Test as Config>::AuthorId,
+) {
+    Authors::<Test>::insert(
+        author_id,
+        AuthorDetails {
+            nickname: vec![0].try_into().unwrap(),
+            real_name: Some(vec![1].try_into().unwrap()),
+            owner,
+            status: AuthorStatus::Claimed,
+            ipi: None,
+            created_at: 0,
+            updated_at: 0,
+        },
+    );
+}
+
+fn add_entity_for_test(
+    entity_id: <Test as Config>::EntityId,
+    owner: <Test as Config>::AuthorityId,
+    royalty_parts: Option<BoundedVec<Wallet<u64>, MaxRoyaltyParts>>,
+) {
+    Entities::<Test>::insert(
+        entity_id,
+        EntityDetails {
+            entity_kind: IPEntityKind::Track,
+            owner,
+            authors: None,
+            royalty_parts,
+            related_to: None,
+            metadata: Metadata {
+                url: vec![4, 5, 6].try_into().unwrap(),
+                standard: MetadataStandard::M25,
+                features: Default::default(),
+            },
+            collection_id: None,
+            item_id: None,
+            content: None,
+            identifiers: Default::default(),
+            created_at: 0,
+            updated_at: 0,
+            revision: 0,
+        },
+    );
+}
+
+fn content_for_test(seed: u8) -> ContentCommitmentFor<Test> {
+    let mut content_hash = vec![0x12, 0x20];
+    content_hash.extend([seed; 32]);
+
+    ContentCommitment {
+        content_hash: content_hash.try_into().unwrap(),
+        cid: vec![seed; 4].try_into().unwrap(),
+        fingerprint: None,
+    }
+}
+
+fn license_offer_for_test(
+    kind: LicenseKind,
+    price: u64,
+    duration: Option<u64>,
+) -> LicenseOfferFor<Test> {
+    LicenseOffer {
+        kind,
+        price,
+        currency: PaymentCurrency::Native,
+        duration,
+        territory: Territory::Worldwide,
+    }
+}
+
+fn release_track_for_test(
+    entity_kind: IPEntityKind,
+    nft_item_id: Option<<Test as Config>::ItemId>,
+) -> ReleaseTrackFor<Test> {
+    ReleaseTrack {
+        entity_kind,
+        url: vec![4, 5, 6].try_into().unwrap(),
+        metadata_standard: MetadataStandard::M25,
+        metadata_features: MetadataFeatures::default(),
+        authors: None,
+        royalty_parts: None,
+        related_to: None,
+        nft_item_id,
+    }
+}
+
+fn relation_for_test(kind: RelationKind, entity_id: <Test as Config>::EntityId) -> Relation<u32> {
+    Relation { kind, entity_id }
+}
+
+fn add_entity_with_relations_for_test(relations: Vec<Relation<u32>>) -> DispatchResult {
+    CustomPallet::add_new_entity(
+        0,
+        IPEntityKind::Track,
+        0,
+        vec![4, 5, 6].try_into().unwrap(),
+        MetadataStandard::M25,
+        MetadataFeatures::default(),
+        None,
+        None,
+        Some(relations.try_into().unwrap()),
+        None,
+        None,
+        None,
+    )
+    .map(|_| ())
+}
+
+fn add_entity_with_metadata_for_test(url: &[u8], standard: MetadataStandard) -> DispatchResult {
+    CustomPallet::add_new_entity(
+        0,
+        IPEntityKind::Track,
+        0,
+        url.to_vec().try_into().unwrap(),
+        standard,
+        MetadataFeatures::default(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .map(|_| ())
+}
+
+fn add_entity_with_nft_for_test(
+    item_id: <Test as Config>::ItemId,
+    holder: <Test as frame_system::Config>::AccountId,
+) -> DispatchResult {
+    CustomPallet::add_new_entity(
+        0,
+        IPEntityKind::Track,
+        0,
+        vec![4, 5, 6].try_into().unwrap(),
+        MetadataStandard::M25,
+        MetadataFeatures::default(),
+        None,
+        None,
+        None,
+        Some(item_id),
+        Some(holder),
+        None,
+    )
+    .map(|_| ())
+}
+
+fn set_entity_relations_for_test(
+    entity_id: <Test as Config>::EntityId,
+    relations: Vec<Relation<u32>>,
+) -> DispatchResult {
+    CustomPallet::set_entity(
+        0,
+        entity_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(relations.try_into().unwrap()),
+        None,
+        None,
+        None,
+    )
 }
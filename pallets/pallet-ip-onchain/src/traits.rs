@@ -0,0 +1,153 @@
+use crate::*;
+
+use scale_codec::Codec;
+
+/// Entry point for other pallets to pay royalties for an IP entity.
+///
+/// The payment is split across the entity `royalty_parts`, or the holders of its
+/// royalty shares, the same way as the `pay_royalties` call does it.
+pub trait RoyaltyDistributor<AccountId, EntityId, Balance, Currency> {
+    /// Transfers `amount` of `currency` from `payer` to the royalty wallets of `entity_id`.
+    fn pay_royalties(
+        payer: &AccountId,
+        entity_id: EntityId,
+        amount: Balance,
+        currency: Currency,
+    ) -> DispatchResult;
+}
+
+impl<T: Config<I>, I: 'static>
+    RoyaltyDistributor<T::AccountId, T::EntityId, BalanceOf<T, I>, PaymentCurrencyFor<T, I>>
+    for Pallet<T, I>
+{
+    fn pay_royalties(
+        payer: &T::AccountId,
+        entity_id: T::EntityId,
+        amount: BalanceOf<T, I>,
+        currency: PaymentCurrencyFor<T, I>,
+    ) -> DispatchResult {
+        Self::distribute_royalties(payer, entity_id, &currency, amount)
+    }
+}
+
+/// Layout a record had in the storage version 1.
+///
+/// The runtime API returned the details of entities, authors and authorities in these layouts before
+/// its version 2. Clients of older runtimes decode them through the associated `V1` type and convert
+/// them to the current layout with `from_v1`.
+pub trait LayoutV1: Sized {
+    /// Identifier of the record.
+    type Id;
+    /// The record in the layout of storage version 1.
+    type V1: Codec;
+
+    /// Converts a record of storage version 1 to the current layout, the same way the storage
+    /// migration does it. The blocks the record was created and updated in are the unknown `0`.
+    fn from_v1(id: &Self::Id, v1: Self::V1) -> Self;
+}
+
+impl<
+        AuthorityId: Codec,
+        AuthorId: Codec,
+        EntityId: Codec + PartialEq,
+        AccountId: Codec + PartialEq,
+        LongStringLimit: Get<u32>,
+        Content,
+        MaxEntityAuthors: Get<u32>,
+        MaxRoyaltyParts: Get<u32>,
+        MaxRelatedEntities: Get<u32>,
+        CollectionId: Codec,
+        ItemId: Codec,
+        BlockNumber: Zero,
+    > LayoutV1
+    for EntityDetails<
+        AuthorityId,
+        AuthorId,
+        Relation<EntityId>,
+        Wallet<AccountId>,
+        Metadata<LongStringLimit>,
+        Content,
+        MaxEntityAuthors,
+        MaxRoyaltyParts,
+        MaxRelatedEntities,
+        CollectionId,
+        ItemId,
+        BlockNumber,
+    >
+{
+    type Id = EntityId;
+    type V1 = migrations::v2::v1::EntityDetails<
+        AuthorityId,
+        AuthorId,
+        EntityId,
+        migrations::v2::v1::Wallet<AccountId>,
+        Metadata<LongStringLimit>,
+        MaxEntityAuthors,
+        MaxRoyaltyParts,
+        MaxRelatedEntities,
+        CollectionId,
+        ItemId,
+    >;
+
+    fn from_v1(entity_id: &EntityId, v1: Self::V1) -> Self {
+        EntityDetails {
+            related_to: v1.related_to.map(|related_to| {
+                migrations::v2::migrate_relations(entity_id, &v1.entity_kind, related_to)
+            }),
+            entity_kind: v1.entity_kind,
+            owner: v1.owner,
+            authors: v1.authors,
+            royalty_parts: v1
+                .royalty_parts
+                .and_then(migrations::v2::normalize_royalty_parts),
+            metadata: v1.metadata,
+            collection_id: v1.collection_id,
+            item_id: v1.item_id,
+            content: None,
+            identifiers: Default::default(),
+            created_at: Zero::zero(),
+            updated_at: Zero::zero(),
+            revision: 0,
+        }
+    }
+}
+
+impl<
+        AccountId: Codec,
+        ShortStringLimit: Get<u32>,
+        LongStringLimit: Get<u32>,
+        BlockNumber: Zero,
+    > LayoutV1 for AuthorDetails<AccountId, ShortStringLimit, LongStringLimit, BlockNumber>
+{
+    type Id = ();
+    type V1 = migrations::v2::v1::AuthorDetails<AccountId, ShortStringLimit, LongStringLimit>;
+
+    fn from_v1(_: &(), v1: Self::V1) -> Self {
+        AuthorDetails {
+            nickname: v1.nickname,
+            real_name: v1.real_name,
+            owner: v1.owner,
+            status: AuthorStatus::Unclaimed,
+            ipi: None,
+            created_at: Zero::zero(),
+            updated_at: Zero::zero(),
+        }
+    }
+}
+
+impl<ShortStringLimit: Get<u32>, CollectionId: Codec, BlockNumber: Zero> LayoutV1
+    for AuthorityDetails<ShortStringLimit, CollectionId, BlockNumber>
+{
+    type Id = ();
+    type V1 = migrations::v2::v1::AuthorityDetails<ShortStringLimit, CollectionId>;
+
+    fn from_v1(_: &(), v1: Self::V1) -> Self {
+        AuthorityDetails {
+            authority_kind: v1.authority_kind,
+            name: v1.name,
+            collection_id: v1.collection_id,
+            created_at: Zero::zero(),
+            updated_at: Zero::zero(),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 extern crate alloc;
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 
 use super::*;
 use external_nfts_macros::*;
@@ -13,14 +13,55 @@ use scale_codec::EncodeLike;
 use scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::{build::Fields, meta_type, Path, Type, TypeInfo, TypeParameter};
 
-use frame::traits::Currency;
+use frame::arithmetic::Perbill;
+use frame::traits::{fungible, fungibles};
 
-pub type BalanceOf<T, I = ()> =
-    <<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type BalanceOf<T, I = ()> = <<T as Config<I>>::Currency as fungible::Inspect<
+    <T as frame_system::Config>::AccountId,
+>>::Balance;
+
+pub type AssetIdOf<T, I = ()> = <<T as Config<I>>::Assets as fungibles::Inspect<
+    <T as frame_system::Config>::AccountId,
+>>::AssetId;
+
+pub type AssetBalanceOf<T, I = ()> = <<T as Config<I>>::Assets as fungibles::Inspect<
+    <T as frame_system::Config>::AccountId,
+>>::Balance;
+
+pub type PaymentAssetOf<T, I = ()> = <<T as Config<I>>::Payments as fungibles::Inspect<
+    <T as frame_system::Config>::AccountId,
+>>::AssetId;
+
+/// Payment currency
+pub type PaymentCurrencyFor<T, I = ()> = PaymentCurrency<PaymentAssetOf<T, I>>;
+
+/// Currency a royalty, a license or an entity transfer is paid in.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PaymentCurrency<AssetId> {
+    /// The native currency of the chain.
+    Native,
+    /// An asset of the `Payments` fungibles accepted by `PaymentAssets`, e.g. a stablecoin or a foreign asset.
+    Asset(AssetId),
+}
+
+/// Deposit
+pub type DepositFor<T, I = ()> = Deposit<<T as frame_system::Config>::AccountId, BalanceOf<T, I>>;
+
+/// Deposit held for a record, released to the depositor when the record is removed.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Deposit<AccountId, Balance> {
+    pub depositor: AccountId,
+    pub amount: Balance,
+}
 
 /// Authority
-pub type AuthorityDetailsFor<T, I = ()> =
-    AuthorityDetails<<T as Config<I>>::MaxShortStringLength, <T as Config<I>>::CollectionId>;
+pub type AuthorityDetailsFor<T, I = ()> = AuthorityDetails<
+    <T as Config<I>>::MaxShortStringLength,
+    <T as Config<I>>::CollectionId,
+    BlockNumberFor<T>,
+>;
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -32,10 +73,14 @@ pub enum AuthorityKind {
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(skip_type_params(ShortStringLimit))]
-pub struct AuthorityDetails<ShortStringLimit: Get<u32>, CollectionId> {
+pub struct AuthorityDetails<ShortStringLimit: Get<u32>, CollectionId, BlockNumber> {
     pub authority_kind: AuthorityKind,
     pub name: BoundedVec<u8, ShortStringLimit>,
     pub collection_id: Option<CollectionId>,
+
+    /// Block the record was created in, `0` for the records migrated from before the blocks were kept.
+    pub created_at: BlockNumber,
+    pub updated_at: BlockNumber,
 }
 
 /// Author
@@ -43,29 +88,55 @@ pub type AuthorFor<T, I = ()> = AuthorDetails<
     <T as frame_system::Config>::AccountId,
     <T as Config<I>>::MaxShortStringLength,
     <T as Config<I>>::MaxLongStringLength,
+    BlockNumberFor<T>,
 >;
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(skip_type_params(ShortStringLimit, LongStringLimit))]
-pub struct AuthorDetails<AccountId, ShortStringLimit: Get<u32>, LongStringLimit: Get<u32>> {
+pub struct AuthorDetails<
+    AccountId,
+    ShortStringLimit: Get<u32>,
+    LongStringLimit: Get<u32>,
+    BlockNumber,
+> {
     pub nickname: BoundedVec<u8, ShortStringLimit>,
     pub real_name: Option<BoundedVec<u8, LongStringLimit>>,
     pub owner: AccountId,
+    pub status: AuthorStatus,
+    pub ipi: Option<Ipi>,
+
+    /// Block the record was created in, `0` for the records migrated from before the blocks were kept.
+    pub created_at: BlockNumber,
+    pub updated_at: BlockNumber,
+}
+
+/// How far the details of an author are proven.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AuthorStatus {
+    /// Created on behalf of the owner, the owner has not claimed the author yet.
+    Unclaimed,
+    /// The owner signed for the author.
+    Claimed,
+    /// The identity of the claimed author is attested by the verifier origin.
+    Verified,
 }
 
 /// Entity
 pub type EntityDetailsFor<T, I = ()> = EntityDetails<
     <T as Config<I>>::AuthorityId,
     <T as Config<I>>::AuthorId,
-    <T as Config<I>>::EntityId,
+    Relation<<T as Config<I>>::EntityId>,
     Wallet<<T as frame_system::Config>::AccountId>,
     Metadata<<T as Config<I>>::MaxLongStringLength>,
+    ContentCommitmentFor<T, I>,
     <T as Config<I>>::MaxEntityAuthors,
     <T as Config<I>>::MaxRoyaltyParts,
     <T as Config<I>>::MaxRelatedEntities,
     <T as Config<I>>::CollectionId,
     <T as Config<I>>::ItemId,
+    BlockNumberFor<T>,
 >;
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -74,26 +145,49 @@ pub type EntityDetailsFor<T, I = ()> = EntityDetails<
 pub struct EntityDetails<
     AuthorityId,
     AuthorId,
-    EntityId,
+    Relation,
     Wallet,
     Metadata,
+    Content,
     MaxEntityAuthors: Get<u32>,
     MaxRoyaltyParts: Get<u32>,
     MaxRelatedEntities: Get<u32>,
     CollectionId,
     ItemId,
+    BlockNumber,
 > {
     pub entity_kind: IPEntityKind,
     pub owner: AuthorityId,
 
     pub authors: Option<BoundedVec<AuthorId, MaxEntityAuthors>>,
     pub royalty_parts: Option<BoundedVec<Wallet, MaxRoyaltyParts>>,
-    pub related_to: Option<BoundedVec<EntityId, MaxRelatedEntities>>,
+    pub related_to: Option<BoundedVec<Relation, MaxRelatedEntities>>,
 
     pub metadata: Metadata,
 
     pub collection_id: Option<CollectionId>,
     pub item_id: Option<ItemId>,
+
+    pub content: Option<Content>,
+    pub identifiers: EntityIdentifiers,
+
+    /// Block the record was created in, `0` for the records migrated from before the blocks were kept.
+    pub created_at: BlockNumber,
+    pub updated_at: BlockNumber,
+    /// Number of the current revision, `0` for the details the entity was created with.
+    pub revision: u32,
+}
+
+/// Entity revision
+pub type EntityRevisionFor<T, I = ()> =
+    EntityRevision<<T as frame_system::Config>::AccountId, EntityDetailsFor<T, I>>;
+
+/// A revision of the details of an entity and the account that made it.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EntityRevision<AccountId, EntityDetails> {
+    pub editor: AccountId,
+    pub details: EntityDetails,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -103,12 +197,153 @@ pub enum IPEntityKind {
     Track,
     GenerativeTrack,
     GenerativeSample,
+    /// An album, its ordered tracks are kept in `ReleaseTracks` like the tracks of the other release kinds.
+    Release,
+    Ep,
+    Compilation,
+    StemPack,
+}
+
+impl IPEntityKind {
+    /// Whether the entity groups an ordered list of tracks.
+    pub fn is_release(&self) -> bool {
+        matches!(
+            self,
+            Self::Release | Self::Ep | Self::Compilation | Self::StemPack
+        )
+    }
+}
+
+/// The NFT attribute holding the SCALE encoded ID of the entity linked to the item.
+pub const NFT_ATTRIBUTE_ENTITY_ID: &[u8] = b"entity_id";
+/// The NFT attribute holding the SCALE encoded `IPEntityKind` of the entity linked to the item.
+pub const NFT_ATTRIBUTE_ENTITY_KIND: &[u8] = b"entity_kind";
+/// The NFT attribute holding the SCALE encoded ID of the authority owning the entity linked to the item.
+pub const NFT_ATTRIBUTE_AUTHORITY_ID: &[u8] = b"authority_id";
+
+/// Release
+pub type ReleaseTrackFor<T, I = ()> = ReleaseTrack<
+    <T as Config<I>>::AuthorId,
+    Relation<<T as Config<I>>::EntityId>,
+    Wallet<<T as frame_system::Config>::AccountId>,
+    <T as Config<I>>::MaxLongStringLength,
+    <T as Config<I>>::MaxEntityAuthors,
+    <T as Config<I>>::MaxRoyaltyParts,
+    <T as Config<I>>::MaxRelatedEntities,
+    <T as Config<I>>::ItemId,
+>;
+
+/// A track created with its release, the authors and royalty parts of the release are used when omitted.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[scale_info(skip_type_params(
+    LongStringLimit,
+    MaxEntityAuthors,
+    MaxRoyaltyParts,
+    MaxRelatedEntities
+))]
+pub struct ReleaseTrack<
+    AuthorId,
+    Relation,
+    Wallet,
+    LongStringLimit: Get<u32>,
+    MaxEntityAuthors: Get<u32>,
+    MaxRoyaltyParts: Get<u32>,
+    MaxRelatedEntities: Get<u32>,
+    ItemId,
+> {
+    pub entity_kind: IPEntityKind,
+    pub url: BoundedVec<u8, LongStringLimit>,
+    pub metadata_standard: MetadataStandard,
+    pub metadata_features: MetadataFeatures,
+    pub authors: Option<BoundedVec<AuthorId, MaxEntityAuthors>>,
+    pub royalty_parts: Option<BoundedVec<Wallet, MaxRoyaltyParts>>,
+    pub related_to: Option<BoundedVec<Relation, MaxRelatedEntities>>,
+    pub nft_item_id: Option<ItemId>,
+}
+
+/// A track of a release and its track number, the tracks of a release are ordered by track number.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ReleaseMember<EntityId> {
+    pub entity_id: EntityId,
+    pub track_number: u32,
 }
 
+/// Metadata standard
+///
+/// The schema of the document behind `Metadata.url`. `M25` is the original schema of the pallet
+/// and accepts any url, the other standards carry the version of their schema.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum MetadataStandard {
     M25,
+    /// DDEX Electronic Release Notification.
+    DdexErn(MetadataVersion),
+    /// MusicBrainz-compatible profile.
+    MusicBrainz(MetadataVersion),
+    /// Generic JSON-LD profile.
+    JsonLd(MetadataVersion),
+}
+
+impl MetadataStandard {
+    /// The standards, with their versions, an entity can declare.
+    pub const SUPPORTED: [MetadataStandard; 8] = [
+        MetadataStandard::M25,
+        MetadataStandard::DdexErn(MetadataVersion::new(3, 8)),
+        MetadataStandard::DdexErn(MetadataVersion::new(4, 1)),
+        MetadataStandard::DdexErn(MetadataVersion::new(4, 2)),
+        MetadataStandard::DdexErn(MetadataVersion::new(4, 3)),
+        MetadataStandard::MusicBrainz(MetadataVersion::new(2, 0)),
+        MetadataStandard::JsonLd(MetadataVersion::new(1, 0)),
+        MetadataStandard::JsonLd(MetadataVersion::new(1, 1)),
+    ];
+
+    /// The url schemes accepted for the standard, empty if any url is accepted.
+    pub fn url_schemes(&self) -> &'static [&'static [u8]] {
+        match self {
+            MetadataStandard::M25 => &[],
+            MetadataStandard::DdexErn(_) | MetadataStandard::JsonLd(_) => {
+                &[b"ar://", b"ipfs://", b"https://"]
+            }
+            MetadataStandard::MusicBrainz(_) => &[b"https://"],
+        }
+    }
+}
+
+/// Version of a metadata standard, e.g. `4.3` for DDEX ERN 4.3.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MetadataVersion {
+    pub major: u16,
+    pub minor: u16,
+}
+
+impl MetadataVersion {
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+}
+
+/// Relation
+///
+/// Describes how an entity derives from the entity with `entity_id`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Relation<EntityId> {
+    pub kind: RelationKind,
+    pub entity_id: EntityId,
+}
+
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RelationKind {
+    RemixOf,
+    SampleOf,
+    StemOf,
+    CoverOf,
+    GeneratedFrom,
+    RemasterOf,
 }
 
 /// Metadata
@@ -121,13 +356,404 @@ pub struct Metadata<LongStringLimit: Get<u32>> {
     pub features: MetadataFeatures,
 }
 
+/// Content hash
+pub type ContentHashFor<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxContentHashLength>;
+
+/// Content commitment
+pub type ContentCommitmentFor<T, I = ()> =
+    ContentCommitment<<T as Config<I>>::MaxContentHashLength>;
+
+/// Binds an entity to the bytes of its audio, unlike the mutable `Metadata.url`.
+///
+/// No two entities can anchor the same `content_hash`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(HashLimit))]
+pub struct ContentCommitment<HashLimit: Get<u32>> {
+    /// Multihash of the audio bytes.
+    pub content_hash: BoundedVec<u8, HashLimit>,
+    /// CID of the audio file.
+    pub cid: BoundedVec<u8, HashLimit>,
+    /// Digest of an acoustic fingerprint of the audio.
+    pub fingerprint: Option<BoundedVec<u8, HashLimit>>,
+}
+
+impl<HashLimit: Get<u32>> ContentCommitment<HashLimit> {
+    /// Number of bytes stored by the commitment.
+    pub fn byte_len(&self) -> usize {
+        self.content_hash.len()
+            + self.cid.len()
+            + self
+                .fingerprint
+                .as_ref()
+                .map_or(0, |fingerprint| fingerprint.len())
+    }
+}
+
+/// International Standard Recording Code of a recording, e.g. `USS1Z9900001`.
+///
+/// A country code, a registrant code, the year of reference and a designation code, without hyphens.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Isrc(pub [u8; 12]);
+
+impl Isrc {
+    pub fn is_valid(&self) -> bool {
+        let (country, rest) = self.0.split_at(2);
+        let (registrant, rest) = rest.split_at(3);
+
+        country.iter().all(u8::is_ascii_uppercase)
+            && registrant
+                .iter()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            && rest.iter().all(u8::is_ascii_digit)
+    }
+}
+
+/// International Standard Musical Work Code of a composition, e.g. `T0345246801`.
+///
+/// The prefix `T`, nine digits and a check digit, without separators.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Iswc(pub [u8; 11]);
+
+impl Iswc {
+    pub fn is_valid(&self) -> bool {
+        let (prefix, digits) = self.0.split_at(1);
+        if prefix != b"T" || !digits.iter().all(u8::is_ascii_digit) {
+            return false;
+        }
+
+        let sum = digits[..9].iter().enumerate().fold(1u32, |sum, (i, d)| {
+            sum + (i as u32 + 1) * u32::from(d - b'0')
+        });
+        u32::from(digits[9] - b'0') == (10 - sum % 10) % 10
+    }
+}
+
+/// Universal Product Code of a release as a 13 digit GTIN, a UPC-A is prefixed with `0`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Upc(pub [u8; 13]);
+
+impl Upc {
+    pub fn is_valid(&self) -> bool {
+        if !self.0.iter().all(u8::is_ascii_digit) {
+            return false;
+        }
+
+        let sum = self.0[..12].iter().enumerate().fold(0u32, |sum, (i, d)| {
+            let weight = if i % 2 == 0 { 1 } else { 3 };
+            sum + weight * u32::from(d - b'0')
+        });
+        u32::from(self.0[12] - b'0') == (10 - sum % 10) % 10
+    }
+}
+
+/// Interested Party Information name number of an author, 11 digits.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Ipi(pub [u8; 11]);
+
+impl Ipi {
+    pub fn is_valid(&self) -> bool {
+        self.0.iter().all(u8::is_ascii_digit)
+    }
+}
+
+/// Industry identifiers of an entity.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EntityIdentifiers {
+    pub isrc: Option<Isrc>,
+    pub iswc: Option<Iswc>,
+    pub upc: Option<Upc>,
+}
+
+impl EntityIdentifiers {
+    /// The identifiers that are set, in the form used by the `EntitiesByIdentifier` index.
+    pub fn iter(&self) -> impl Iterator<Item = IndustryIdentifier> {
+        self.isrc
+            .map(IndustryIdentifier::Isrc)
+            .into_iter()
+            .chain(self.iswc.map(IndustryIdentifier::Iswc))
+            .chain(self.upc.map(IndustryIdentifier::Upc))
+    }
+}
+
+/// An industry identifier of an entity, the key of the `EntitiesByIdentifier` index.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum IndustryIdentifier {
+    Isrc(Isrc),
+    Iswc(Iswc),
+    Upc(Upc),
+}
+
+impl IndustryIdentifier {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            IndustryIdentifier::Isrc(isrc) => isrc.is_valid(),
+            IndustryIdentifier::Iswc(iswc) => iswc.is_valid(),
+            IndustryIdentifier::Upc(upc) => upc.is_valid(),
+        }
+    }
+}
+
 /// Wallet
+///
+/// The shares of all wallets of a royalty split must sum up to 100%.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Wallet<AccountId> {
     // pub name: Option<BoundedVec<u8, ShortStringLimit>>,
     pub address_id: AccountId,
-    pub weight: u32,
+    pub share: Perbill,
+}
+
+/// Royalty shares
+pub type RoyaltySharesFor<T, I = ()> = RoyaltyShares<
+    <T as frame_system::Config>::AccountId,
+    AssetIdOf<T, I>,
+    <T as Config<I>>::MaxRoyaltyShareHolders,
+>;
+
+/// Asset the royalty income of an entity is shared in.
+///
+/// The royalties of the entity are paid to the issuer and the registered holders pro-rata to their balance
+/// of the asset, instead of the wallets of its royalty split. Unregistered holders receive nothing until they
+/// register.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[scale_info(skip_type_params(MaxHolders))]
+pub struct RoyaltyShares<AccountId, AssetId, MaxHolders: Get<u32>> {
+    pub asset_id: AssetId,
+    /// Account the shares were issued to, it receives the royalties of its own balance without registering.
+    pub issuer: AccountId,
+    pub holders: BoundedVec<AccountId, MaxHolders>,
+}
+
+/// License
+pub type LicenseOfferFor<T, I = ()> =
+    LicenseOffer<BalanceOf<T, I>, BlockNumberFor<T>, PaymentCurrencyFor<T, I>>;
+pub type LicenseFor<T, I = ()> =
+    License<BalanceOf<T, I>, BlockNumberFor<T>, PaymentCurrencyFor<T, I>>;
+
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum LicenseKind {
+    Sync,
+    Streaming,
+    SampleUse,
+    AiTraining,
+}
+
+/// Territory where a license is valid, countries are ISO 3166-1 alpha-2 codes.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Territory {
+    Worldwide,
+    Country([u8; 2]),
+}
+
+/// License offer attached to an entity by its authority.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct LicenseOffer<Balance, BlockNumber, Currency> {
+    pub kind: LicenseKind,
+    pub price: Balance,
+    pub currency: Currency,
+    /// Number of blocks the license is valid for, `None` for a perpetual license.
+    pub duration: Option<BlockNumber>,
+    pub territory: Territory,
+}
+
+/// License purchased by an account.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct License<Balance, BlockNumber, Currency> {
+    pub kind: LicenseKind,
+    pub price: Balance,
+    pub currency: Currency,
+    pub territory: Territory,
+    pub purchased_at: BlockNumber,
+    /// Block at which the license expires, `None` for a perpetual license.
+    pub expires_at: Option<BlockNumber>,
+}
+
+impl<Balance, BlockNumber: PartialOrd, Currency> License<Balance, BlockNumber, Currency> {
+    pub fn is_active(&self, now: &BlockNumber) -> bool {
+        self.expires_at
+            .as_ref()
+            .map_or(true, |expires_at| expires_at > now)
+    }
+}
+
+/// Entity transfer
+pub type EntityTransferFor<T, I = ()> = EntityTransfer<
+    <T as Config<I>>::AuthorityId,
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T, I>,
+    PaymentCurrencyFor<T, I>,
+>;
+
+/// Pending offer to transfer an entity to another authority.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EntityTransfer<AuthorityId, AccountId, Balance, Currency> {
+    pub to_authority: AuthorityId,
+    /// Price paid by the receiving authority, zero for a free transfer.
+    pub price: Balance,
+    /// Account receiving the price once the transfer is accepted.
+    pub beneficiary: AccountId,
+    /// Currency the price is paid in.
+    pub currency: Currency,
+}
+
+/// Entity NFT listing
+pub type NftListingFor<T, I = ()> =
+    NftListing<<T as frame_system::Config>::AccountId, BalanceOf<T, I>, PaymentCurrencyFor<T, I>>;
+
+/// Entity NFT listed for sale by its holder, the resale royalty of the entity is taken from the price.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct NftListing<AccountId, Balance, Currency> {
+    /// Holder of the NFT when it was listed, it receives the price minus the resale royalty.
+    pub seller: AccountId,
+    pub price: Balance,
+    /// Currency the price and the resale royalty are paid in.
+    pub currency: Currency,
+    /// Only account allowed to buy the NFT, anyone if `None`.
+    pub buyer: Option<AccountId>,
+}
+
+/// Access invitation
+pub type AccessInvitationFor<T> = AccessInvitation<BlockNumberFor<T>>;
+
+/// Access granted to an account once it accepts the invitation.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AccessInvitation<BlockNumber> {
+    pub access: AuthorityAccessSettings,
+    /// Block from which the accepted access is treated as absent, `None` for a permanent access.
+    pub expires_at: Option<BlockNumber>,
+}
+
+/// Dispute
+pub type DisputeFor<T, I = ()> = Dispute<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T, I>,
+    <T as Config<I>>::MaxLongStringLength,
+    BlockNumberFor<T>,
+>;
+
+/// Open claim against the registration of an entity.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[scale_info(skip_type_params(LongStringLimit))]
+pub struct Dispute<AccountId, Balance, LongStringLimit: Get<u32>, BlockNumber> {
+    pub claimant: AccountId,
+    /// Bond held from the claimant, refunded if the claim is upheld and slashed if it is rejected.
+    pub bond: Balance,
+    pub evidence_url: BoundedVec<u8, LongStringLimit>,
+    pub opened_at: BlockNumber,
+}
+
+/// How the arbiter resolves a dispute.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DisputeResolution<AuthorityId> {
+    /// The claim is right, the entity is taken down and stays frozen.
+    Uphold,
+    /// The claim is wrong, the entity is unfrozen.
+    Reject,
+    /// The claim is right, the entity moves to the given authority.
+    TransferOwnership(AuthorityId),
+}
+
+/// Authority governance
+pub type AuthorityActionFor<T, I = ()> = AuthorityAction<
+    <T as frame_system::Config>::AccountId,
+    <T as Config<I>>::AuthorityId,
+    <T as Config<I>>::EntityId,
+    BalanceOf<T, I>,
+    BoundedVec<Wallet<<T as frame_system::Config>::AccountId>, <T as Config<I>>::MaxRoyaltyParts>,
+    BlockNumberFor<T>,
+    PaymentCurrencyFor<T, I>,
+>;
+
+pub type AuthorityProposalFor<T, I = ()> = AuthorityProposal<
+    <T as frame_system::Config>::AccountId,
+    AuthorityActionFor<T, I>,
+    <T as Config<I>>::MaxPolicyThreshold,
+>;
+
+/// Threshold policy of an authority: the governed actions need the approval of `threshold`
+/// accounts holding the `EditAccess` right. Access changes are always governed.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AuthorityPolicy {
+    pub threshold: u32,
+    pub actions: GovernedActions,
+}
+
+/// An action of an authority executed once its proposal is approved.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AuthorityAction<
+    AccountId,
+    AuthorityId,
+    EntityId,
+    Balance,
+    RoyaltyParts,
+    BlockNumber,
+    Currency,
+> {
+    /// Adds, changes or removes (`None`) the access of an account.
+    SetAccess {
+        account_id: AccountId,
+        access: Option<AuthorityAccessSettings>,
+        expires_at: Option<BlockNumber>,
+    },
+    /// Offers an entity of the authority to another authority.
+    OfferTransfer {
+        entity_id: EntityId,
+        to_authority: AuthorityId,
+        price: Balance,
+        currency: Currency,
+    },
+    /// Replaces the royalty split of an entity of the authority.
+    SetRoyaltyParts {
+        entity_id: EntityId,
+        royalty_parts: RoyaltyParts,
+    },
+    /// Replaces or removes the policy of the authority.
+    SetPolicy(Option<AuthorityPolicy>),
+    /// Sets or removes (`None`) the resale royalty of the authority, or of one of its entities.
+    SetResaleRoyalty {
+        entity_id: Option<EntityId>,
+        royalty: Option<Perbill>,
+    },
+}
+
+/// Pending action of an authority collecting approvals.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[scale_info(skip_type_params(MaxApprovals))]
+pub struct AuthorityProposal<AccountId, Action, MaxApprovals: Get<u32>> {
+    pub proposer: AccountId,
+    pub action: Action,
+    pub approvals: BoundedVec<AccountId, MaxApprovals>,
+}
+
+/// Account overview
+///
+/// Everything an account controls, as returned by the `account_overview` runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AccountOverview<AuthorId, AuthorityId> {
+    pub authors: Vec<AuthorId>,
+    pub authorities: Vec<(AuthorityId, AuthorityAccessSettings)>,
 }
 
 /// Flags
@@ -196,6 +822,34 @@ impl MetadataFeatures {
 }
 impl_codec_bitflags!(MetadataFeatures, u64, MetadataFeature);
 
+/// GovernedAction - actions of an authority that need the approval of its policy
+#[bitflags]
+#[repr(u64)]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum GovernedAction {
+    AccessChange,
+    EntityTransfer,
+    RoyaltyEdit,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GovernedActions(pub BitFlags<GovernedAction>);
+
+impl GovernedActions {
+    pub fn none() -> Self {
+        Self(BitFlags::EMPTY)
+    }
+    pub fn all() -> Self {
+        Self(BitFlags::ALL)
+    }
+    pub fn has_action(&self, f: GovernedAction) -> bool {
+        self.0.contains(f)
+    }
+}
+impl_codec_bitflags!(GovernedActions, u64, GovernedAction);
+
 #[cfg(test)]
 mod tests {
     use super::*;
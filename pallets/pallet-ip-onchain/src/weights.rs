@@ -1,13 +1,11 @@
-
-//! Autogenerated weights for `pallet_ip_onchain`
+//! Placeholder weights for `pallet_ip_onchain`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.1.0
-//! DATE: 2025-07-27, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! CPU: `13th Gen Intel(R) Core(TM) i7-13700HX`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! THESE WEIGHTS ARE NOT BENCHMARKED. They are estimated by hand from the storage reads and writes
+//! of every call, on top of the weights generated on 2025-07-27 for the calls that existed then.
+//! Regenerate this file from `benchmarking.rs` with the command below before a production release,
+//! it replaces the whole file.
 
-// Executed Command:
+// Regenerate with:
 // frame-omni-bencher
 // v1
 // benchmark
@@ -44,327 +42,710 @@ pub trait WeightInfo {
 	fn create_account_access() -> Weight;
 	fn edit_account_access() -> Weight;
 	fn call_toggle_nfts_support() -> Weight;
+	fn pay_royalties(p: u32, ) -> Weight;
+	fn add_license_offer() -> Weight;
+	fn remove_license_offer() -> Weight;
+	fn purchase_license(p: u32, ) -> Weight;
+	fn remove_author() -> Weight;
+	fn remove_authority(a: u32, ) -> Weight;
+	fn remove_entity(a: u32, r: u32, ) -> Weight;
+	fn remove_account_access() -> Weight;
+	fn offer_entity_transfer() -> Weight;
+	fn cancel_entity_transfer() -> Weight;
+	fn accept_entity_transfer() -> Weight;
+	fn claim_author() -> Weight;
+	fn verify_author() -> Weight;
+	fn set_permissionless_mode() -> Weight;
+	fn set_entity_content() -> Weight;
+	fn set_entity_identifiers() -> Weight;
+	fn set_author_ipi() -> Weight;
+	fn open_dispute() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn set_authority_policy() -> Weight;
+	fn propose_authority_action() -> Weight;
+	fn approve_authority_proposal() -> Weight;
+	fn cancel_authority_proposal() -> Weight;
+	fn invite_account_access() -> Weight;
+	fn accept_account_access() -> Weight;
+	fn cancel_account_access_invitation() -> Weight;
+	fn prune_expired_account_access() -> Weight;
+	fn delegate_account_access() -> Weight;
+	fn revoke_delegated_access() -> Weight;
+	fn create_release(t: u32, ) -> Weight;
+	fn set_release_tracks(t: u32, ) -> Weight;
+	fn withdraw_release_track() -> Weight;
+	fn detach_entity_nft() -> Weight;
+	fn burn_entity_nft() -> Weight;
+	fn set_resale_royalty() -> Weight;
+	fn list_entity_nft() -> Weight;
+	fn delist_entity_nft() -> Weight;
+	fn buy_entity_nft(p: u32, ) -> Weight;
+	fn issue_royalty_shares() -> Weight;
+	fn register_royalty_share_holder(h: u32, ) -> Weight;
+	fn gift_entity_nft() -> Weight;
+	fn prune_licenses() -> Weight;
+	fn reinstate_entity() -> Weight;
+	fn migrate_v2_authority() -> Weight;
+	fn migrate_v2_author() -> Weight;
+	fn migrate_v2_entity(a: u32, r: u32, ) -> Weight;
+	fn migrate_v2_access() -> Weight;
 }
 
-/// Weights for `pallet_ip_onchain` using the Substrate node and recommended hardware.
+/// Placeholder weights for `pallet_ip_onchain`, not measured on the recommended hardware yet, see the module documentation.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::NextAuthorId` (r:1 w:1)
-	/// Proof: `IPOnchain::NextAuthorId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authors` (r:1 w:1)
-	/// Proof: `IPOnchain::Authors` (`max_values`: None, `max_size`: Some(216), added: 2691, mode: `MaxEncodedLen`)
 	fn create_author() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `207`
-		//  Estimated: `4687`
-		// Minimum execution time: 12_179_000 picoseconds.
 		Weight::from_parts(13_224_000, 4687)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authors` (r:1 w:1)
-	/// Proof: `IPOnchain::Authors` (`max_values`: None, `max_size`: Some(216), added: 2691, mode: `MaxEncodedLen`)
 	fn edit_author() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `291`
-		//  Estimated: `4687`
-		// Minimum execution time: 12_863_000 picoseconds.
 		Weight::from_parts(13_458_000, 4687)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::NextAuthorityId` (r:1 w:1)
-	/// Proof: `IPOnchain::NextAuthorityId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authorities` (r:1 w:1)
-	/// Proof: `IPOnchain::Authorities` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::NftsSupport` (r:1 w:0)
-	/// Proof: `IPOnchain::NftsSupport` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:1 w:1)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	fn create_authority() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `207`
-		//  Estimated: `4687`
-		// Minimum execution time: 19_932_000 picoseconds.
 		Weight::from_parts(21_560_000, 4687)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:1 w:0)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authorities` (r:1 w:1)
-	/// Proof: `IPOnchain::Authorities` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::NftsSupport` (r:1 w:0)
-	/// Proof: `IPOnchain::NftsSupport` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	fn edit_authority() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `354`
-		//  Estimated: `4687`
-		// Minimum execution time: 19_021_000 picoseconds.
 		Weight::from_parts(19_627_000, 4687)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:1 w:0)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authorities` (r:1 w:0)
-	/// Proof: `IPOnchain::Authorities` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Item` (r:1 w:1)
-	/// Proof: `NFTs::Item` (`max_values`: None, `max_size`: Some(865), added: 3340, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Collection` (r:1 w:1)
-	/// Proof: `NFTs::Collection` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::CollectionConfigOf` (r:1 w:0)
-	/// Proof: `NFTs::CollectionConfigOf` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::ItemConfigOf` (r:1 w:1)
-	/// Proof: `NFTs::ItemConfigOf` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::NextEntityId` (r:1 w:1)
-	/// Proof: `IPOnchain::NextEntityId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Entities` (r:1 w:1)
-	/// Proof: `IPOnchain::Entities` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Account` (r:0 w:1)
-	/// Proof: `NFTs::Account` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	fn create_entity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `776`
-		//  Estimated: `4687`
-		// Minimum execution time: 56_260_000 picoseconds.
 		Weight::from_parts(60_453_000, 4687)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Entities` (r:1 w:1)
-	/// Proof: `IPOnchain::Entities` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:1 w:0)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authorities` (r:1 w:0)
-	/// Proof: `IPOnchain::Authorities` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Item` (r:1 w:1)
-	/// Proof: `NFTs::Item` (`max_values`: None, `max_size`: Some(865), added: 3340, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Collection` (r:1 w:1)
-	/// Proof: `NFTs::Collection` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::CollectionConfigOf` (r:1 w:0)
-	/// Proof: `NFTs::CollectionConfigOf` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::ItemConfigOf` (r:1 w:1)
-	/// Proof: `NFTs::ItemConfigOf` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Account` (r:0 w:1)
-	/// Proof: `NFTs::Account` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	fn edit_entity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `840`
-		//  Estimated: `4687`
-		// Minimum execution time: 54_776_000 picoseconds.
 		Weight::from_parts(56_929_000, 4687)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:2 w:1)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	fn create_account_access() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `343`
-		//  Estimated: `6092`
-		// Minimum execution time: 16_684_000 picoseconds.
 		Weight::from_parts(17_732_000, 6092)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:1 w:1)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	fn edit_account_access() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `343`
-		//  Estimated: `4687`
-		// Minimum execution time: 14_877_000 picoseconds.
 		Weight::from_parts(15_916_000, 4687)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: `IPOnchain::NftsSupport` (r:1 w:1)
-	/// Proof: `IPOnchain::NftsSupport` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	fn call_toggle_nfts_support() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `80`
-		//  Estimated: `1486`
-		// Minimum execution time: 4_594_000 picoseconds.
 		Weight::from_parts(4_772_000, 1486)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// The range of component `p` is `[1, 10]`.
+	fn pay_royalties(p: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 4089)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5212).saturating_mul(p.into()))
+	}
+	fn add_license_offer() -> Weight {
+		Weight::from_parts(22_000_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn remove_license_offer() -> Weight {
+		Weight::from_parts(22_000_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `p` is `[1, 10]`.
+	fn purchase_license(p: u32, ) -> Weight {
+		Weight::from_parts(38_000_000, 4368)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5212).saturating_mul(p.into()))
+	}
+	fn remove_author() -> Weight {
+		Weight::from_parts(18_000_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// The range of component `a` is `[1, 100]`.
+	fn remove_authority(a: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 4687)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2551).saturating_mul(a.into()))
+	}
+	/// The range of component `a` is `[0, 10]`.
+	/// The range of component `r` is `[0, 10]`.
+	fn remove_entity(a: u32, r: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 4687)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+	fn remove_account_access() -> Weight {
+		Weight::from_parts(18_000_000, 6092)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn offer_entity_transfer() -> Weight {
+		Weight::from_parts(17_000_000, 4129)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn cancel_entity_transfer() -> Weight {
+		Weight::from_parts(16_000_000, 6092)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn accept_entity_transfer() -> Weight {
+		Weight::from_parts(95_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(29_u64))
+			.saturating_add(T::DbWeight::get().writes(29_u64))
+	}
+	fn claim_author() -> Weight {
+		Weight::from_parts(12_000_000, 3682)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn verify_author() -> Weight {
+		Weight::from_parts(12_000_000, 3682)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_permissionless_mode() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_entity_content() -> Weight {
+		Weight::from_parts(30_000_000, 4517)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn set_entity_identifiers() -> Weight {
+		Weight::from_parts(30_000_000, 4557)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn set_author_ipi() -> Weight {
+		Weight::from_parts(16_000_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn open_dispute() -> Weight {
+		Weight::from_parts(32_000_000, 4633)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(70_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(26_u64))
+	}
+	fn set_authority_policy() -> Weight {
+		Weight::from_parts(18_000_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn propose_authority_action() -> Weight {
+		Weight::from_parts(19_000_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn approve_authority_proposal() -> Weight {
+		Weight::from_parts(27_000_000, 11194)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn cancel_authority_proposal() -> Weight {
+		Weight::from_parts(14_000_000, 4891)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn invite_account_access() -> Weight {
+		Weight::from_parts(30_000_000, 6092)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn accept_account_access() -> Weight {
+		Weight::from_parts(21_000_000, 3542)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn cancel_account_access_invitation() -> Weight {
+		Weight::from_parts(24_000_000, 3557)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn prune_expired_account_access() -> Weight {
+		Weight::from_parts(25_000_000, 3557)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn delegate_account_access() -> Weight {
+		Weight::from_parts(38_000_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn revoke_delegated_access() -> Weight {
+		Weight::from_parts(27_000_000, 4026)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// The range of component `t` is `[1, 100]`.
+	fn create_release(t: u32, ) -> Weight {
+		Weight::from_parts(62_000_000, 4687)
+			.saturating_add(Weight::from_parts(48_000_000, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+			.saturating_add(T::DbWeight::get().writes((13_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 3340).saturating_mul(t.into()))
+	}
+	/// The range of component `t` is `[0, 100]`.
+	fn set_release_tracks(t: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 4687)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 3099).saturating_mul(t.into()))
+	}
+	fn withdraw_release_track() -> Weight {
+		Weight::from_parts(26_000_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn detach_entity_nft() -> Weight {
+		Weight::from_parts(48_000_000, 3833)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	fn burn_entity_nft() -> Weight {
+		Weight::from_parts(62_000_000, 3833)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
+	}
+	fn set_resale_royalty() -> Weight {
+		Weight::from_parts(24_000_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn list_entity_nft() -> Weight {
+		Weight::from_parts(21_000_000, 4330)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn delist_entity_nft() -> Weight {
+		Weight::from_parts(19_000_000, 4330)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `p` is `[1, 10]`.
+	fn buy_entity_nft(p: u32, ) -> Weight {
+		Weight::from_parts(96_000_000, 6680)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5212).saturating_mul(p.into()))
+	}
+	fn issue_royalty_shares() -> Weight {
+		Weight::from_parts(52_000_000, 6723)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// The range of component `h` is `[1, 100]`.
+	fn register_royalty_share_holder(h: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 6723)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(h.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(h.into()))
+	}
+	fn gift_entity_nft() -> Weight {
+		Weight::from_parts(38_000_000, 4330)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn prune_licenses() -> Weight {
+		Weight::from_parts(21_000_000, 3811)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn reinstate_entity() -> Weight {
+		Weight::from_parts(9_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn migrate_v2_authority() -> Weight {
+		Weight::from_parts(11_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn migrate_v2_author() -> Weight {
+		Weight::from_parts(14_000_000, 3687)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// The range of component `a` is `[0, 10]`.
+	/// The range of component `r` is `[0, 10]`.
+	fn migrate_v2_entity(a: u32, r: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 4330)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+	fn migrate_v2_access() -> Weight {
+		Weight::from_parts(13_000_000, 3637)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::NextAuthorId` (r:1 w:1)
-	/// Proof: `IPOnchain::NextAuthorId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authors` (r:1 w:1)
-	/// Proof: `IPOnchain::Authors` (`max_values`: None, `max_size`: Some(216), added: 2691, mode: `MaxEncodedLen`)
 	fn create_author() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `207`
-		//  Estimated: `4687`
-		// Minimum execution time: 12_179_000 picoseconds.
 		Weight::from_parts(13_224_000, 4687)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authors` (r:1 w:1)
-	/// Proof: `IPOnchain::Authors` (`max_values`: None, `max_size`: Some(216), added: 2691, mode: `MaxEncodedLen`)
 	fn edit_author() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `291`
-		//  Estimated: `4687`
-		// Minimum execution time: 12_863_000 picoseconds.
 		Weight::from_parts(13_458_000, 4687)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::NextAuthorityId` (r:1 w:1)
-	/// Proof: `IPOnchain::NextAuthorityId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authorities` (r:1 w:1)
-	/// Proof: `IPOnchain::Authorities` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::NftsSupport` (r:1 w:0)
-	/// Proof: `IPOnchain::NftsSupport` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:1 w:1)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	fn create_authority() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `207`
-		//  Estimated: `4687`
-		// Minimum execution time: 19_932_000 picoseconds.
 		Weight::from_parts(21_560_000, 4687)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:1 w:0)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authorities` (r:1 w:1)
-	/// Proof: `IPOnchain::Authorities` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::NftsSupport` (r:1 w:0)
-	/// Proof: `IPOnchain::NftsSupport` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	fn edit_authority() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `354`
-		//  Estimated: `4687`
-		// Minimum execution time: 19_021_000 picoseconds.
 		Weight::from_parts(19_627_000, 4687)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:1 w:0)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authorities` (r:1 w:0)
-	/// Proof: `IPOnchain::Authorities` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Item` (r:1 w:1)
-	/// Proof: `NFTs::Item` (`max_values`: None, `max_size`: Some(865), added: 3340, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Collection` (r:1 w:1)
-	/// Proof: `NFTs::Collection` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::CollectionConfigOf` (r:1 w:0)
-	/// Proof: `NFTs::CollectionConfigOf` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::ItemConfigOf` (r:1 w:1)
-	/// Proof: `NFTs::ItemConfigOf` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::NextEntityId` (r:1 w:1)
-	/// Proof: `IPOnchain::NextEntityId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Entities` (r:1 w:1)
-	/// Proof: `IPOnchain::Entities` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Account` (r:0 w:1)
-	/// Proof: `NFTs::Account` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	fn create_entity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `776`
-		//  Estimated: `4687`
-		// Minimum execution time: 56_260_000 picoseconds.
 		Weight::from_parts(60_453_000, 4687)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Entities` (r:1 w:1)
-	/// Proof: `IPOnchain::Entities` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:1 w:0)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::Authorities` (r:1 w:0)
-	/// Proof: `IPOnchain::Authorities` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Item` (r:1 w:1)
-	/// Proof: `NFTs::Item` (`max_values`: None, `max_size`: Some(865), added: 3340, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Collection` (r:1 w:1)
-	/// Proof: `NFTs::Collection` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::CollectionConfigOf` (r:1 w:0)
-	/// Proof: `NFTs::CollectionConfigOf` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::ItemConfigOf` (r:1 w:1)
-	/// Proof: `NFTs::ItemConfigOf` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-	/// Storage: `NFTs::Account` (r:0 w:1)
-	/// Proof: `NFTs::Account` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	fn edit_entity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `840`
-		//  Estimated: `4687`
-		// Minimum execution time: 54_776_000 picoseconds.
 		Weight::from_parts(56_929_000, 4687)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:2 w:1)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	fn create_account_access() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `343`
-		//  Estimated: `6092`
-		// Minimum execution time: 16_684_000 picoseconds.
 		Weight::from_parts(17_732_000, 6092)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	/// Storage: `Membership::Members` (r:1 w:0)
-	/// Proof: `Membership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `IPOnchain::AuthoritiesAccess` (r:1 w:1)
-	/// Proof: `IPOnchain::AuthoritiesAccess` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	fn edit_account_access() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `343`
-		//  Estimated: `4687`
-		// Minimum execution time: 14_877_000 picoseconds.
 		Weight::from_parts(15_916_000, 4687)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	/// Storage: `IPOnchain::NftsSupport` (r:1 w:1)
-	/// Proof: `IPOnchain::NftsSupport` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	fn call_toggle_nfts_support() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `80`
-		//  Estimated: `1486`
-		// Minimum execution time: 4_594_000 picoseconds.
 		Weight::from_parts(4_772_000, 1486)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// The range of component `p` is `[1, 10]`.
+	fn pay_royalties(p: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 4089)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5212).saturating_mul(p.into()))
+	}
+	fn add_license_offer() -> Weight {
+		Weight::from_parts(22_000_000, 4687)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn remove_license_offer() -> Weight {
+		Weight::from_parts(22_000_000, 4687)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `p` is `[1, 10]`.
+	fn purchase_license(p: u32, ) -> Weight {
+		Weight::from_parts(38_000_000, 4368)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5212).saturating_mul(p.into()))
+	}
+	fn remove_author() -> Weight {
+		Weight::from_parts(18_000_000, 4687)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// The range of component `a` is `[1, 100]`.
+	fn remove_authority(a: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 4687)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2551).saturating_mul(a.into()))
+	}
+	/// The range of component `a` is `[0, 10]`.
+	/// The range of component `r` is `[0, 10]`.
+	fn remove_entity(a: u32, r: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 4687)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+	fn remove_account_access() -> Weight {
+		Weight::from_parts(18_000_000, 6092)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn offer_entity_transfer() -> Weight {
+		Weight::from_parts(17_000_000, 4129)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn cancel_entity_transfer() -> Weight {
+		Weight::from_parts(16_000_000, 6092)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn accept_entity_transfer() -> Weight {
+		Weight::from_parts(95_000_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(29_u64))
+			.saturating_add(RocksDbWeight::get().writes(29_u64))
+	}
+	fn claim_author() -> Weight {
+		Weight::from_parts(12_000_000, 3682)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn verify_author() -> Weight {
+		Weight::from_parts(12_000_000, 3682)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_permissionless_mode() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_entity_content() -> Weight {
+		Weight::from_parts(30_000_000, 4517)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	fn set_entity_identifiers() -> Weight {
+		Weight::from_parts(30_000_000, 4557)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn set_author_ipi() -> Weight {
+		Weight::from_parts(16_000_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn open_dispute() -> Weight {
+		Weight::from_parts(32_000_000, 4633)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(70_000_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(26_u64))
+	}
+	fn set_authority_policy() -> Weight {
+		Weight::from_parts(18_000_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn propose_authority_action() -> Weight {
+		Weight::from_parts(19_000_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn approve_authority_proposal() -> Weight {
+		Weight::from_parts(27_000_000, 11194)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn cancel_authority_proposal() -> Weight {
+		Weight::from_parts(14_000_000, 4891)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn invite_account_access() -> Weight {
+		Weight::from_parts(30_000_000, 6092)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn accept_account_access() -> Weight {
+		Weight::from_parts(21_000_000, 3542)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn cancel_account_access_invitation() -> Weight {
+		Weight::from_parts(24_000_000, 3557)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn prune_expired_account_access() -> Weight {
+		Weight::from_parts(25_000_000, 3557)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn delegate_account_access() -> Weight {
+		Weight::from_parts(38_000_000, 4687)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn revoke_delegated_access() -> Weight {
+		Weight::from_parts(27_000_000, 4026)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// The range of component `t` is `[1, 100]`.
+	fn create_release(t: u32, ) -> Weight {
+		Weight::from_parts(62_000_000, 4687)
+			.saturating_add(Weight::from_parts(48_000_000, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+			.saturating_add(RocksDbWeight::get().writes((13_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 3340).saturating_mul(t.into()))
+	}
+	/// The range of component `t` is `[0, 100]`.
+	fn set_release_tracks(t: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 4687)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 3099).saturating_mul(t.into()))
+	}
+	fn withdraw_release_track() -> Weight {
+		Weight::from_parts(26_000_000, 4687)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn detach_entity_nft() -> Weight {
+		Weight::from_parts(48_000_000, 3833)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	fn burn_entity_nft() -> Weight {
+		Weight::from_parts(62_000_000, 3833)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+	}
+	fn set_resale_royalty() -> Weight {
+		Weight::from_parts(24_000_000, 4687)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn list_entity_nft() -> Weight {
+		Weight::from_parts(21_000_000, 4330)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn delist_entity_nft() -> Weight {
+		Weight::from_parts(19_000_000, 4330)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `p` is `[1, 10]`.
+	fn buy_entity_nft(p: u32, ) -> Weight {
+		Weight::from_parts(96_000_000, 6680)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5212).saturating_mul(p.into()))
+	}
+	fn issue_royalty_shares() -> Weight {
+		Weight::from_parts(52_000_000, 6723)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// The range of component `h` is `[1, 100]`.
+	fn register_royalty_share_holder(h: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 6723)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(h.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(h.into()))
+	}
+	fn gift_entity_nft() -> Weight {
+		Weight::from_parts(38_000_000, 4330)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn prune_licenses() -> Weight {
+		Weight::from_parts(21_000_000, 3811)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn reinstate_entity() -> Weight {
+		Weight::from_parts(9_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn migrate_v2_authority() -> Weight {
+		Weight::from_parts(11_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn migrate_v2_author() -> Weight {
+		Weight::from_parts(14_000_000, 3687)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// The range of component `a` is `[0, 10]`.
+	/// The range of component `r` is `[0, 10]`.
+	fn migrate_v2_entity(a: u32, r: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 4330)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+	fn migrate_v2_access() -> Weight {
+		Weight::from_parts(13_000_000, 3637)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -549,7 +549,7 @@ impl_runtime_apis! {
         }
     }
 
-    impl pallet_ip_onchain_runtime_api::ApiIpOnchainRuntime<Block, EntityId, AuthorId, AuthorityId, EntityDetails, AuthorDetails, AuthorityDetails> for Runtime {
+    impl pallet_ip_onchain_runtime_api::ApiIpOnchainRuntime<Block, EntityId, AuthorId, AuthorityId, EntityDetails, AuthorDetails, AuthorityDetails, AccountId> for Runtime {
         fn entity(entity_id: EntityId) -> Result<EntityDetails, sp_runtime::DispatchError> {
             IPOnchain::get_entity(entity_id)
         }
@@ -573,6 +573,53 @@ impl_runtime_apis! {
             let res = IPOnchain::get_authorities(from, to)?;
             Ok(res.into())
         }
+
+        fn entities_of_authority(authority_id: AuthorityId) -> Vec<EntityId> {
+            IPOnchain::get_entities_of_authority(authority_id)
+        }
+        fn entities_of_author(author_id: AuthorId) -> Vec<EntityId> {
+            IPOnchain::get_entities_of_author(author_id)
+        }
+        fn account_overview(account_id: AccountId) -> pallet_ip_onchain_runtime_api::AccountOverview<AuthorId, AuthorityId> {
+            IPOnchain::get_account_overview(account_id)
+        }
+
+        fn derivatives(entity_id: EntityId) -> Vec<(EntityId, pallet_ip_onchain_runtime_api::RelationKind)> {
+            IPOnchain::get_derivatives(entity_id)
+        }
+
+        fn is_licensed(account_id: AccountId, entity_id: EntityId, kind: pallet_ip_onchain_runtime_api::LicenseKind) -> bool {
+            IPOnchain::is_licensed(account_id, entity_id, kind)
+        }
+
+        fn metadata_standards() -> Vec<pallet_ip_onchain_runtime_api::MetadataStandard> {
+            IPOnchain::get_metadata_standards()
+        }
+
+        fn entity_by_content_hash(content_hash: Vec<u8>) -> Option<EntityId> {
+            IPOnchain::get_entity_by_content_hash(content_hash)
+        }
+
+        fn entity_by_identifier(identifier: pallet_ip_onchain_runtime_api::IndustryIdentifier) -> Option<EntityId> {
+            IPOnchain::get_entity_by_identifier(identifier)
+        }
+
+        fn author_by_ipi(ipi: pallet_ip_onchain_runtime_api::Ipi) -> Option<AuthorId> {
+            IPOnchain::get_author_by_ipi(ipi)
+        }
+
+        fn entity_revision(entity_id: EntityId, revision: u32) -> Option<(AccountId, EntityDetails)> {
+            IPOnchain::get_entity_revision(entity_id, revision)
+                .map(|revision| (revision.editor, revision.details))
+        }
+
+        fn release(entity_id: EntityId) -> Result<(EntityDetails, Vec<(u32, EntityId, EntityDetails)>), sp_runtime::DispatchError> {
+            IPOnchain::get_release(entity_id)
+        }
+
+        fn entity_nft_holder(entity_id: EntityId) -> Result<Option<AccountId>, sp_runtime::DispatchError> {
+            IPOnchain::get_entity_nft_holder(entity_id)
+        }
     }
 
     #[cfg(feature = "try-runtime")]
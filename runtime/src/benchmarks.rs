@@ -33,6 +33,7 @@ polkadot_sdk::frame_benchmarking::define_benchmarks!(
     [pallet_collator_selection, CollatorSelection]
     [cumulus_pallet_parachain_system, ParachainSystem]
     [cumulus_pallet_xcmp_queue, XcmpQueue]
+    [pallet_migrations, MultiBlockMigrations]
     [pallet_ip_onchain, IPOnchain]
     [pallet_nfts, NFTs]
     [pallet_arweave, Arweave]
@@ -44,8 +44,8 @@ use frame_support::{
         fungible,
         fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
         tokens::{imbalance::ResolveTo, pay::PayAssetFromAccount, UnityAssetBalanceConversion},
-        AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, Imbalance,
-        OnTimestampSet, TransformOrigin, VariantCountOf,
+        ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, EnsureOrigin, EnsureOriginWithArg,
+        Equals, EverythingBut, Imbalance, OnTimestampSet, TransformOrigin, VariantCountOf,
     },
     weights::{ConstantMultiplier, Weight},
     PalletId,
@@ -77,12 +77,12 @@ use super::{
     weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
     AccountId, Assets, Aura, AuthorId, AuthorityId, Balance, Balances, BaseFee, Block, BlockNumber,
     CollatorSelection, CollectionId, ConsensusHook, EVMChainId, EntityId, Hash, ItemId, Membership,
-    MessageQueue, NFTs, Nonce, PalletInfo, ParachainSystem, Runtime, RuntimeCall, RuntimeEvent,
-    RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session, SessionKeys,
-    Signature, SignedExtra, SignedPayload, System, TaskId, Timestamp, UncheckedExtrinsic,
-    WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, DAYS, EXISTENTIAL_DEPOSIT, HOURS,
-    MAXIMUM_BLOCK_WEIGHT, MICRO_UNIT, MILLI_UNIT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, UNIT,
-    VERSION,
+    MessageQueue, MultiBlockMigrations, NFTs, Nonce, PalletInfo, ParachainSystem, Runtime,
+    RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
+    Session, SessionKeys, Signature, SignedExtra, SignedPayload, System, TaskId, Timestamp,
+    UncheckedExtrinsic, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, DAYS,
+    EXISTENTIAL_DEPOSIT, HOURS, MAXIMUM_BLOCK_WEIGHT, MICRO_UNIT, MILLI_UNIT,
+    NORMAL_DISPATCH_RATIO, SLOT_DURATION, UNIT, VERSION,
 };
 use sp_core::TypedGet;
 use xcm_config::{RelayLocation, XcmOriginToTransactDispatchOrigin};
@@ -147,6 +147,27 @@ impl frame_system::Config for Runtime {
     /// The action to take on a Runtime Upgrade
     type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
     type MaxConsumers = frame_support::traits::ConstU32<16>;
+    /// The migrations spanning several blocks, only inherents are included while they run.
+    type MultiBlockMigrator = MultiBlockMigrations;
+}
+
+parameter_types! {
+    pub MbmServiceWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    #[cfg(not(feature = "runtime-benchmarks"))]
+    type Migrations = (pallet_ip_onchain::migrations::v2::MigrateV1ToV2<Runtime>,);
+    // Benchmarks need mocked migrations to guarantee that they succeed.
+    #[cfg(feature = "runtime-benchmarks")]
+    type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+    type CursorMaxLen = ConstU32<65_536>;
+    type IdentifierMaxLen = ConstU32<256>;
+    type MigrationStatusHandler = ();
+    type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+    type MaxServiceWeight = MbmServiceWeight;
+    type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -357,6 +378,52 @@ parameter_types! {
     pub const MaxRelatedEntities: u32 = 10;
      #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
     pub const MaxArrayLen: u32 = 10;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const MaxLineageLookups: u32 = 64;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const MaxLicenseOffers: u32 = 16;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const MaxHolderLicenses: u32 = 16;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const MaxContentHashLength: u32 = 64;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const MaxEntityRevisions: u32 = 32;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const MaxReleaseTracks: u32 = 100;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const MaxAccessDelegates: u32 = 16;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const MaxPolicyThreshold: u32 = 16;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const MaxAuthorityProposals: u32 = 16;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const MaxResaleRoyalty: Perbill = Perbill::from_percent(25);
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const MaxRoyaltyShareHolders: u32 = 100;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const NativePaymentAsset: NativeOrWithId<u32> = NativeOrWithId::Native;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const RoyaltySharesAssetIdStart: AssetIdForTrustBackedAssets = 1 << 31;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const AuthorityDeposit: Balance = 10 * UNIT;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const AuthorDeposit: Balance = UNIT;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const EntityDeposit: Balance = UNIT;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const RevisionDeposit: Balance = 100 * MILLI_UNIT;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const AccessDeposit: Balance = 100 * MILLI_UNIT;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const ProposalDeposit: Balance = UNIT;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const LicenseDeposit: Balance = 100 * MILLI_UNIT;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const RoyaltySharesDeposit: Balance = UNIT;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const ByteDeposit: Balance = 10 * MILLI_UNIT;
+     #[cfg_attr(feature = "std", derive(Clone, serde::Serialize, serde::Deserialize))]
+    pub const DisputeBond: Balance = 10 * UNIT;
 }
 
 impl pallet_ip_onchain::Config for Runtime {
@@ -370,12 +437,40 @@ impl pallet_ip_onchain::Config for Runtime {
     type MaxRoyaltyParts = MaxRoyaltyParts;
     type MaxRelatedEntities = MaxRelatedEntities;
     type MaxArrayLen = MaxArrayLen;
+    type MaxLineageLookups = MaxLineageLookups;
+    type MaxLicenseOffers = MaxLicenseOffers;
+    type MaxHolderLicenses = MaxHolderLicenses;
+    type MaxContentHashLength = MaxContentHashLength;
+    type MaxEntityRevisions = MaxEntityRevisions;
+    type MaxReleaseTracks = MaxReleaseTracks;
+    type MaxAccessDelegates = MaxAccessDelegates;
+    type MaxPolicyThreshold = MaxPolicyThreshold;
+    type MaxAuthorityProposals = MaxAuthorityProposals;
+    type MaxResaleRoyalty = MaxResaleRoyalty;
+    type MaxRoyaltyShareHolders = MaxRoyaltyShareHolders;
     type WhiteListChecker = Membership;
+    type VerifierOrigin = EnsureRoot<AccountId>;
+    type ArbiterOrigin = EnsureRoot<AccountId>;
     type CollectionId = CollectionId;
     type ItemId = ItemId;
     type CollectionConfig = pallet_nfts::CollectionConfig<Balance, BlockNumber, CollectionId>;
     type Nfts = NFTs;
     type Currency = Balances;
+    type Assets = Assets;
+    type Payments = NativeAndAssets;
+    type PaymentAssets = EverythingBut<Equals<NativePaymentAsset>>;
+    type RoyaltySharesAssetIdStart = RoyaltySharesAssetIdStart;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AuthorityDeposit = AuthorityDeposit;
+    type AuthorDeposit = AuthorDeposit;
+    type EntityDeposit = EntityDeposit;
+    type RevisionDeposit = RevisionDeposit;
+    type AccessDeposit = AccessDeposit;
+    type ProposalDeposit = ProposalDeposit;
+    type LicenseDeposit = LicenseDeposit;
+    type RoyaltySharesDeposit = RoyaltySharesDeposit;
+    type ByteDeposit = ByteDeposit;
+    type DisputeBond = DisputeBond;
 
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_ip_onchain::weights::SubstrateWeight<Runtime>;
@@ -629,13 +724,41 @@ parameter_types! {
     pub const UnitBody: BodyId = BodyId::Unit;
 }
 
+/// Lets signed accounts create the assets below the range reserved for the royalty shares of the entities.
+pub struct EnsureSignedBelowRoyaltyShares;
+impl EnsureOriginWithArg<RuntimeOrigin, codec::Compact<AssetIdForTrustBackedAssets>>
+    for EnsureSignedBelowRoyaltyShares
+{
+    type Success = AccountId;
+
+    fn try_origin(
+        o: RuntimeOrigin,
+        asset_id: &codec::Compact<AssetIdForTrustBackedAssets>,
+    ) -> Result<Self::Success, RuntimeOrigin> {
+        if asset_id.0 >= RoyaltySharesAssetIdStart::get() {
+            return Err(o);
+        }
+        EnsureSigned::<AccountId>::try_origin(o)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(
+        asset_id: &codec::Compact<AssetIdForTrustBackedAssets>,
+    ) -> Result<RuntimeOrigin, ()> {
+        if asset_id.0 >= RoyaltySharesAssetIdStart::get() {
+            return Err(());
+        }
+        EnsureSigned::<AccountId>::try_successful_origin()
+    }
+}
+
 impl pallet_assets::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Balance = Balance;
     type AssetId = AssetIdForTrustBackedAssets;
     type AssetIdParameter = codec::Compact<AssetIdForTrustBackedAssets>;
     type Currency = Balances;
-    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type CreateOrigin = EnsureSignedBelowRoyaltyShares;
     type ForceOrigin = EnsureRoot<AccountId>;
     type AssetDeposit = AssetDeposit;
     type MetadataDepositBase = MetadataDepositBase;
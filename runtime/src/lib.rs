@@ -100,7 +100,8 @@ pub type UncheckedExtrinsic =
 pub type CheckedExtrinsic =
     fp_self_contained::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra, H160>;
 
-/// All migrations of the runtime, aside from the ones declared in the pallets.
+/// All single block migrations of the runtime, aside from the ones declared in the pallets.
+/// The multi-block migrations run in `MultiBlockMigrations`.
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
@@ -190,10 +191,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: alloc::borrow::Cow::Borrowed("mubert-runtime"),
     impl_name: alloc::borrow::Cow::Borrowed("mubert-runtime"),
     authoring_version: 1,
-    spec_version: 7,
+    spec_version: 8,
     impl_version: 0,
     apis: apis::RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     system_version: 1,
 };
 
@@ -297,6 +298,8 @@ mod runtime {
     pub type Timestamp = pallet_timestamp;
     #[runtime::pallet_index(3)]
     pub type ParachainInfo = parachain_info;
+    #[runtime::pallet_index(4)]
+    pub type MultiBlockMigrations = pallet_migrations;
 
     // Monetary stuff.
     #[runtime::pallet_index(10)]